@@ -35,7 +35,6 @@ use crate::{
     observed_attesters::Error as ObservedAttestersError,
     BeaconChain, BeaconChainError, BeaconChainTypes,
 };
-use bls::verify_signature_sets;
 use proto_array::Block as ProtoBlock;
 use slog::debug;
 use slot_clock::SlotClock;
@@ -912,10 +911,10 @@ pub fn verify_attestation_signature<T: BeaconChainTypes>(
     let _signature_verification_timer =
         metrics::start_timer(&metrics::ATTESTATION_PROCESSING_SIGNATURE_TIMES);
 
-    if signature_set.verify() {
-        Ok(())
-    } else {
-        Err(Error::InvalidSignature)
+    match chain.verify_bls_signature_batch(vec![signature_set]) {
+        Ok(()) => Ok(()),
+        Err(BeaconChainError::InvalidSignatureBatch) => Err(Error::InvalidSignature),
+        Err(e) => Err(Error::BeaconChainError(e)),
     }
 }
 
@@ -1031,7 +1030,11 @@ pub fn verify_signed_aggregate_signatures<T: BeaconChainTypes>(
         .map_err(BeaconChainError::SignatureSetError)?,
     ];
 
-    Ok(verify_signature_sets(signature_sets.iter()))
+    match chain.verify_bls_signature_batch(signature_sets) {
+        Ok(()) => Ok(true),
+        Err(BeaconChainError::InvalidSignatureBatch) => Ok(false),
+        Err(e) => Err(Error::BeaconChainError(e)),
+    }
 }
 
 /// Assists in readability.
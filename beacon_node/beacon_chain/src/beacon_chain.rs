@@ -9,6 +9,7 @@ use crate::block_verification::{
     IntoFullyVerifiedBlock,
 };
 use crate::chain_config::ChainConfig;
+use crate::checkpoint_state_cache::CheckpointStateCache;
 use crate::errors::{BeaconChainError as Error, BlockProductionError};
 use crate::eth1_chain::{Eth1Chain, Eth1ChainBackend};
 use crate::events::ServerSentEventHandler;
@@ -32,33 +33,47 @@ use crate::validator_pubkey_cache::ValidatorPubkeyCache;
 use crate::BeaconForkChoiceStore;
 use crate::BeaconSnapshot;
 use crate::{metrics, BeaconChainError};
-use eth2::types::{EventKind, SseBlock, SseFinalizedCheckpoint, SseHead};
-use fork_choice::ForkChoice;
+use bls::{verify_signature_sets, SignatureSet};
+use eth2::lighthouse::{
+    ColumnStats, DepositContractData, MissedAttestationDiagnosis, ValidatorPerformanceSummary,
+};
+use eth2::types::{
+    EventKind, SseBlock, SseChainSplit, SseFinalizedCheckpoint, SseForkChoiceEquivocation, SseHead,
+};
+use fork_choice::{ForkChoice, ForkChoiceStore};
 use futures::channel::mpsc::Sender;
 use itertools::process_results;
 use itertools::Itertools;
+use lru::LruCache;
 use operation_pool::{OperationPool, PersistedOperationPool};
 use parking_lot::{Mutex, RwLock};
+use proto_array::core::ProtoArray;
 use slasher::Slasher;
 use slog::{crit, debug, error, info, trace, warn, Logger};
 use slot_clock::SlotClock;
 use state_processing::{
-    common::get_indexed_attestation,
+    common::{get_attesting_indices, get_indexed_attestation},
     per_block_processing,
     per_block_processing::errors::AttestationValidationError,
+    per_block_processing::verify_deposit_merkle_proofs_batch,
     per_slot_processing,
-    state_advance::{complete_state_advance, partial_state_advance},
-    BlockSignatureStrategy, SigVerifiedOp,
+    state_advance::{complete_state_advance, partial_state_advance, Error as StateAdvanceError},
+    BlockProcessingError, BlockSignatureStrategy, SigVerifiedOp,
 };
 use std::borrow::Cow;
-use std::cmp::Ordering;
+use std::cmp::{self, Ordering};
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::collections::VecDeque;
 use std::io::prelude::*;
+use std::panic::{catch_unwind, AssertUnwindSafe};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use store::iter::{BlockRootsIterator, ParentRootBlockIterator, StateRootsIterator};
-use store::{Error as DBError, HotColdDB, KeyValueStore, KeyValueStoreOp, StoreItem, StoreOp};
+use store::{
+    DBColumn, Error as DBError, HotColdDB, KeyValueStore, KeyValueStoreOp, StoreItem, StoreOp,
+};
+use strum::AsRefStr;
 use task_executor::ShutdownReason;
 use types::beacon_state::CloneConfig;
 use types::*;
@@ -80,6 +95,12 @@ pub const ATTESTATION_CACHE_LOCK_TIMEOUT: Duration = Duration::from_secs(1);
 /// validator pubkey cache.
 pub const VALIDATOR_PUBKEY_CACHE_LOCK_TIMEOUT: Duration = Duration::from_secs(1);
 
+/// The maximum number of finalized checkpoints retained in the finality checkpoints history.
+pub const MAX_FINALITY_CHECKPOINTS_HISTORY_LEN: usize = 1_024;
+
+/// The number of post-block states retained by `BeaconChain::get_state_at_block_root`.
+pub const BLOCK_ROOT_STATE_CACHE_SIZE: usize = 4;
+
 // These keys are all zero because they get stored in different columns, see `DBColumn` type.
 pub const BEACON_CHAIN_DB_KEY: Hash256 = Hash256::zero();
 pub const OP_POOL_DB_KEY: Hash256 = Hash256::zero();
@@ -110,11 +131,21 @@ pub enum ChainSegmentResult<T: EthSpec> {
     },
 }
 
+/// The minimum number of signature sets for which `BeaconChain::verify_bls_signature_batch` uses
+/// batch verification. A single set is verified individually.
+///
+/// This is low enough that the three signature sets of a `SignedAggregateAndProof` are batched.
+pub const MIN_BLS_BATCH_VERIFY_SIZE: usize = 2;
+
 /// The accepted clock drift for nodes gossiping blocks and attestations. See:
 ///
 /// https://github.com/ethereum/eth2.0-specs/blob/v0.12.1/specs/phase0/p2p-interface.md#configuration
 pub const MAXIMUM_GOSSIP_CLOCK_DISPARITY: Duration = Duration::from_millis(500);
 
+/// Aggregates covering less than this fraction of their committee are considered inefficient and
+/// are counted in metrics. See `BeaconChain::compute_aggregate_attestation_efficiency`.
+pub const MIN_AGGREGATE_ATTESTATION_EFFICIENCY: f64 = 0.01;
+
 #[derive(Debug, PartialEq)]
 pub enum AttestationProcessingOutcome {
     Processed,
@@ -151,6 +182,20 @@ pub enum AttestationProcessingOutcome {
     Invalid(AttestationValidationError),
 }
 
+/// Whether the attestations of an `AttesterSlashing` vote for blocks on the canonical chain.
+///
+/// See `BeaconChain::verify_attester_slashing_matches_chain`.
+#[derive(Debug, PartialEq, Clone, Copy, AsRefStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum SlashingCanonicity {
+    /// Both attestations vote for canonical blocks.
+    Canonical,
+    /// Exactly one of the attestations votes for a canonical block.
+    OneOnCanonical,
+    /// Neither attestation votes for a canonical block.
+    BothOnForks,
+}
+
 /// Defines how a `BeaconState` should be "skipped" through skip-slots.
 pub enum StateSkipConfig {
     /// Calculate the state root during each skip slot, producing a fully-valid `BeaconState`.
@@ -163,6 +208,21 @@ pub enum StateSkipConfig {
     WithoutStateRoots,
 }
 
+/// A discrepancy between fork choice and the database.
+///
+/// See `BeaconChain::verify_block_tree_consistency`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum InconsistencyReport {
+    /// Fork choice knows of a block which is not in the database.
+    MissingBlock { block_root: Hash256, slot: Slot },
+    /// Fork choice knows of a block whose post-state is not in the hot database.
+    MissingState {
+        block_root: Hash256,
+        slot: Slot,
+        state_root: Hash256,
+    },
+}
+
 #[derive(Debug, PartialEq)]
 pub struct HeadInfo {
     pub slot: Slot,
@@ -176,6 +236,31 @@ pub struct HeadInfo {
     pub proposer_shuffling_decision_root: Hash256,
 }
 
+/// The outcome of running the state transition for a block without importing it.
+///
+/// See `BeaconChain::simulate_block_import`.
+#[derive(Debug)]
+pub struct SimulatedImportResult {
+    /// The root of the post-state, or `None` if the block failed `per_block_processing`.
+    pub state_root: Option<Hash256>,
+    /// The time taken to load the parent state and run the state transition.
+    pub processing_time: Duration,
+    /// The reason the block is invalid, if any.
+    pub validation_error: Option<SimulatedImportError>,
+}
+
+/// The reason a block is invalid according to `BeaconChain::simulate_block_import`.
+#[derive(Debug)]
+pub enum SimulatedImportError {
+    /// The parent state could not be advanced to the slot of the block, e.g., because the block is
+    /// earlier than its parent.
+    StateAdvance(StateAdvanceError),
+    /// The block failed `per_block_processing`.
+    BlockProcessing(BlockProcessingError),
+    /// The root of the post-state does not match the `state_root` of the block.
+    StateRootMismatch { block: Hash256, local: Hash256 },
+}
+
 pub trait BeaconChainTypes: Send + Sync + 'static {
     type HotStore: store::ItemStore<Self::EthSpec>;
     type ColdStore: store::ItemStore<Self::EthSpec>;
@@ -262,6 +347,16 @@ pub struct BeaconChain<T: BeaconChainTypes> {
     pub(crate) shuffling_cache: TimeoutRwLock<ShufflingCache>,
     /// Caches the beacon block proposer shuffling for a given epoch and shuffling key root.
     pub beacon_proposer_cache: Mutex<BeaconProposerCache>,
+    /// Caches the checkpoint states of the current and previous epochs.
+    pub checkpoint_state_cache: Mutex<CheckpointStateCache<T::EthSpec>>,
+    /// The most recent equivocation found by `Self::monitor_fork_choice_equivocation`, used to
+    /// avoid reporting the same equivocation after every fork choice update.
+    pub last_fork_choice_equivocation: Mutex<Option<SseForkChoiceEquivocation>>,
+    /// The current epoch and fork choice justified checkpoint when equivocations were last
+    /// checked for, used to avoid scanning fork choice after every head update.
+    pub last_fork_choice_equivocation_check: Mutex<Option<(Epoch, Checkpoint)>>,
+    /// Caches the most recent results of `Self::get_state_at_block_root`.
+    pub block_root_state_cache: Mutex<LruCache<Hash256, BeaconState<T::EthSpec>>>,
     /// Caches a map of `validator_index -> validator_pubkey`.
     pub(crate) validator_pubkey_cache: TimeoutRwLock<ValidatorPubkeyCache<T>>,
     /// A list of any hard-coded forks that have been disabled.
@@ -277,6 +372,8 @@ pub struct BeaconChain<T: BeaconChainTypes> {
     pub slasher: Option<Arc<Slasher<T::EthSpec>>>,
     /// Provides monitoring of a set of explicitly defined validators.
     pub validator_monitor: RwLock<ValidatorMonitor<T::EthSpec>>,
+    /// The `(epoch, root)` of each checkpoint finalized since startup, oldest first.
+    pub(crate) finality_checkpoints_history: RwLock<VecDeque<(Epoch, Hash256)>>,
 }
 
 type BeaconBlockAndState<T> = (BeaconBlock<T>, BeaconState<T>);
@@ -650,6 +747,65 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         })?
     }
 
+    /// Returns the `(slot, block_root)` of each canonical block in the inclusive range
+    /// `start..=end`, read in one pass from the `block_roots` of the head state.
+    ///
+    /// ## Notes
+    ///
+    /// - Skipped slots are omitted.
+    /// - Slots later than the head are ignored.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if `start` is older than the `SLOTS_PER_HISTORICAL_ROOT` slots covered
+    /// by the head state.
+    pub fn get_all_block_roots_in_slot_range(
+        &self,
+        start: Slot,
+        end: Slot,
+    ) -> Result<Vec<(Slot, Hash256)>, Error> {
+        let (mut roots, start_prev_root_known) = self.with_head(|head| {
+            let state = &head.beacon_state;
+            let end = std::cmp::min(end, state.slot);
+
+            // A slot is skipped when it repeats the root of the slot before it.
+            let mut prev_root = start
+                .as_u64()
+                .checked_sub(1)
+                .and_then(|prev_slot| state.get_block_root(Slot::new(prev_slot)).ok().copied());
+            let start_prev_root_known = start == 0 || prev_root.is_some();
+
+            let mut roots = vec![];
+            for slot in (start.as_u64()..=end.as_u64()).map(Slot::new) {
+                let root = if slot == state.slot {
+                    head.beacon_block_root
+                } else {
+                    *state.get_block_root(slot)?
+                };
+
+                if prev_root != Some(root) {
+                    roots.push((slot, root));
+                }
+                prev_root = Some(root);
+            }
+
+            Ok::<_, Error>((roots, start_prev_root_known))
+        })?;
+
+        // If the slot before `start` has already been overwritten in the circular buffer, fall
+        // back to the database to determine whether `start` is a skipped slot.
+        if !start_prev_root_known
+            && roots.first().map(|(slot, _)| *slot) == Some(start)
+            && self
+                .block_root_at_slot(start, WhenSlotSkipped::None)?
+                .is_none()
+        {
+            roots.remove(0);
+        }
+
+        Ok(roots)
+    }
+
     /// Returns the block at the given root, if any.
     ///
     /// ## Errors
@@ -675,6 +831,37 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         Ok(self.store.get_state(state_root, slot)?)
     }
 
+    /// Returns the post-state of the block with the given `block_root`, which need not be
+    /// canonical.
+    ///
+    /// The most recent `BLOCK_ROOT_STATE_CACHE_SIZE` results are cached.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the block or its state is not in the database.
+    pub fn get_state_at_block_root(
+        &self,
+        block_root: Hash256,
+    ) -> Result<BeaconState<T::EthSpec>, Error> {
+        if let Some(state) = self.block_root_state_cache.lock().get(&block_root) {
+            return Ok(state.clone());
+        }
+
+        let block = self
+            .get_block(&block_root)?
+            .ok_or(Error::MissingBeaconBlock(block_root))?;
+        let state_root = block.state_root();
+        let state = self
+            .get_state(&state_root, Some(block.slot()))?
+            .ok_or(Error::MissingBeaconState(state_root))?;
+
+        self.block_root_state_cache
+            .lock()
+            .put(block_root, state.clone());
+
+        Ok(state)
+    }
+
     /// Returns a `Checkpoint` representing the head block and state. Contains the "best block";
     /// the head of the canonical `BeaconChain`.
     ///
@@ -839,6 +1026,55 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         }
     }
 
+    /// Returns the root of the canonical `BeaconState` at the first slot of `epoch` (the
+    /// checkpoint state), without loading the state.
+    ///
+    /// If a block was produced at the first slot of `epoch` its `state_root` is returned, otherwise
+    /// the root is read from the `state_roots` of the head.
+    ///
+    /// ## Errors
+    ///
+    /// Returns `Error::NoStateForSlot` if there is no canonical state at the start of `epoch`,
+    /// e.g. because it is later than the head.
+    pub fn get_checkpoint_state_root(&self, epoch: Epoch) -> Result<Hash256, Error> {
+        let slot = epoch.start_slot(T::EthSpec::slots_per_epoch());
+
+        if let Some(block) = self.block_at_slot(slot, WhenSlotSkipped::None)? {
+            return Ok(block.state_root());
+        }
+
+        self.state_root_at_slot(slot)?
+            .ok_or(Error::NoStateForSlot(slot))
+    }
+
+    /// Returns the canonical `BeaconState` at the first slot of `epoch` (the checkpoint state).
+    ///
+    /// The checkpoint states of the current and previous epochs are cached in memory to avoid
+    /// repeatedly reading them from the database.
+    ///
+    /// ## Errors
+    ///
+    /// Returns `Error::NoStateForSlot` if there is no canonical state at the start of `epoch`,
+    /// e.g. because it is later than the head.
+    pub fn get_checkpoint_state(&self, epoch: Epoch) -> Result<BeaconState<T::EthSpec>, Error> {
+        let slot = epoch.start_slot(T::EthSpec::slots_per_epoch());
+        let state_root = self.get_checkpoint_state_root(epoch)?;
+
+        if let Some(state) = self.checkpoint_state_cache.lock().get(epoch, state_root) {
+            return Ok(state);
+        }
+
+        let state = self
+            .get_state(&state_root, Some(slot))?
+            .ok_or(Error::MissingBeaconState(state_root))?;
+
+        self.checkpoint_state_cache
+            .lock()
+            .insert(self.epoch()?, epoch, state_root, state.clone());
+
+        Ok(state)
+    }
+
     /// Returns the `BeaconState` the current slot (viz., `self.slot()`).
     ///
     ///  - A reference to the head state (note: this keeps a read lock on the head, try to use
@@ -859,6 +1095,16 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
             .ok_or(Error::CanonicalHeadLockTimeout)
     }
 
+    /// Returns the `(epoch, root)` of the last `count` checkpoints finalized since startup, oldest
+    /// first.
+    ///
+    /// At most `MAX_FINALITY_CHECKPOINTS_HISTORY_LEN` checkpoints are retained.
+    pub fn get_finality_checkpoints_history(&self, count: usize) -> Vec<(Epoch, Hash256)> {
+        let history = self.finality_checkpoints_history.read();
+        let skip = history.len().saturating_sub(count);
+        history.iter().skip(skip).copied().collect()
+    }
+
     /// Returns the validator index (if any) for the given public key.
     ///
     /// ## Notes
@@ -989,6 +1235,157 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         })
     }
 
+    /// Determines at which stage an attestation from `validator_index` for `slot` was lost, if it
+    /// was lost at all.
+    ///
+    /// The canonical blocks in the `SLOTS_PER_EPOCH` slots following `slot` (up to the head) are
+    /// searched for an attestation from the validator. If none is found, the attesters observed on
+    /// gossip are consulted to determine whether the attestation reached this node.
+    ///
+    /// ## Notes
+    ///
+    /// Observed attesters are pruned after a few epochs, so an attestation for an old `slot` which
+    /// was seen but never included will be reported as `NotSeen`.
+    pub fn diagnose_missed_attestation(
+        &self,
+        validator_index: u64,
+        slot: Slot,
+    ) -> Result<MissedAttestationDiagnosis, Error> {
+        let slots_per_epoch = T::EthSpec::slots_per_epoch();
+        let epoch = slot.epoch(slots_per_epoch);
+        let head_info = self.head_info()?;
+
+        let (duties, _) =
+            self.validator_attestation_duties(&[validator_index], epoch, head_info.block_root)?;
+        let duty = match duties.into_iter().next().flatten() {
+            Some(duty) if duty.slot == slot => duty,
+            _ => return Ok(MissedAttestationDiagnosis::NotInCommittee),
+        };
+
+        let last_inclusion_slot = cmp::min(slot + slots_per_epoch, head_info.slot);
+        let mut blocks_checked = 0;
+
+        for inclusion_slot in (slot.as_u64() + 1..=last_inclusion_slot.as_u64()).map(Slot::new) {
+            let block_root = match self.block_root_at_slot(inclusion_slot, WhenSlotSkipped::None)? {
+                Some(block_root) => block_root,
+                None => continue,
+            };
+            let block = self
+                .get_block(&block_root)?
+                .ok_or(Error::MissingBeaconBlock(block_root))?;
+            blocks_checked += 1;
+
+            let included = block.message.body.attestations.iter().any(|attestation| {
+                attestation.data.slot == slot
+                    && attestation.data.index == duty.index
+                    && attestation
+                        .aggregation_bits
+                        .get(duty.committee_position)
+                        .unwrap_or(false)
+            });
+
+            if included {
+                return Ok(MissedAttestationDiagnosis::IncludedInBlock {
+                    block_root,
+                    inclusion_slot,
+                });
+            }
+        }
+
+        let seen = self
+            .observed_attesters
+            .read()
+            .validator_has_been_observed_at_epoch(epoch, validator_index as usize);
+
+        if seen {
+            Ok(MissedAttestationDiagnosis::MissedInclusion { blocks_checked })
+        } else {
+            Ok(MissedAttestationDiagnosis::NotSeen)
+        }
+    }
+
+    /// Returns the indices of all validators with an attestation included in the block with
+    /// `block_root`, sorted by increasing index.
+    ///
+    /// The committees are read from the post-state of the block, which is loaded from the
+    /// database.
+    pub fn get_attesting_validators_for_block(
+        &self,
+        block_root: Hash256,
+    ) -> Result<Vec<u64>, Error> {
+        let block = self
+            .get_block(&block_root)?
+            .ok_or(Error::MissingBeaconBlock(block_root))?;
+        let mut state = self
+            .get_state(&block.state_root(), Some(block.slot()))?
+            .ok_or_else(|| Error::MissingBeaconState(block.state_root()))?;
+        state.build_committee_cache(RelativeEpoch::Previous, &self.spec)?;
+        state.build_committee_cache(RelativeEpoch::Current, &self.spec)?;
+
+        let mut validators = vec![];
+        for attestation in block.message.body.attestations.iter() {
+            let committee =
+                state.get_beacon_committee(attestation.data.slot, attestation.data.index)?;
+            let attesting_indices = get_attesting_indices::<T::EthSpec>(
+                committee.committee,
+                &attestation.aggregation_bits,
+            )?;
+            validators.extend(attesting_indices.into_iter().map(|i| i as u64));
+        }
+
+        validators.sort_unstable();
+        validators.dedup();
+
+        Ok(validators)
+    }
+
+    /// Summarises the performance of `validator_index` from `start_epoch` to `end_epoch`
+    /// (inclusive), using the history held by the validator monitor.
+    ///
+    /// Epochs prior to the activation of the validator are not counted. The validator must be
+    /// monitored, see `ValidatorMonitor::get_performance_summary`.
+    pub fn get_validator_performance_summary(
+        &self,
+        validator_index: u64,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+    ) -> Result<ValidatorPerformanceSummary, Error> {
+        let current_epoch = self.epoch()?;
+        let activation_epoch = self.with_head(|head| {
+            Ok::<_, Error>(
+                head.beacon_state
+                    .validators
+                    .get(validator_index as usize)
+                    .map(|validator| validator.activation_epoch),
+            )
+        })?;
+        let start_epoch = activation_epoch.map_or(start_epoch, |activation_epoch| {
+            cmp::max(start_epoch, activation_epoch)
+        });
+
+        self.validator_monitor
+            .read()
+            .get_performance_summary(validator_index, start_epoch, end_epoch, current_epoch)
+            .ok_or(Error::UnmonitoredValidator(validator_index))
+    }
+
+    /// Returns the number of attestations in the operation pool and the naive aggregation pool
+    /// for each `(slot, committee_index)`.
+    ///
+    /// The pools aggregate attestations where possible, so this counts the distinct aggregates
+    /// held rather than the individual attestations received.
+    pub fn get_pending_attestation_count_by_committee(
+        &self,
+    ) -> HashMap<(Slot, CommitteeIndex), usize> {
+        let mut counts = self.op_pool.num_attestations_by_committee();
+        for attestation in self.naive_aggregation_pool.read().iter() {
+            *counts
+                .entry((attestation.data.slot, attestation.data.index))
+                .or_default() += 1;
+        }
+        counts
+    }
+
     /// Returns an aggregated `Attestation`, if any, that has a matching `attestation.data`.
     ///
     /// The attestation will be obtained from `self.naive_aggregation_pool`.
@@ -1059,6 +1456,60 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         }
     }
 
+    /// Produces the `AttestationData` for every committee at `slot`, keyed by committee index.
+    ///
+    /// This is equivalent to calling `Self::produce_unaggregated_attestation` for each committee,
+    /// however the head state is only advanced once.
+    pub fn get_attestation_data_for_all_committees(
+        &self,
+        slot: Slot,
+    ) -> Result<HashMap<CommitteeIndex, AttestationData>, Error> {
+        let head = self
+            .canonical_head
+            .try_read_for(HEAD_LOCK_TIMEOUT)
+            .ok_or(Error::CanonicalHeadLockTimeout)?;
+
+        // See `Self::produce_unaggregated_attestation` for why this is disallowed.
+        if slot < head.beacon_block.slot() {
+            return Err(Error::AttestingPriorToHead {
+                head_slot: head.beacon_block.slot(),
+                request_slot: slot,
+            });
+        }
+
+        let epoch = slot.epoch(T::EthSpec::slots_per_epoch());
+        let mut state = Cow::Borrowed(&head.beacon_state);
+        if state.current_epoch() < epoch {
+            let mut_state = state.to_mut();
+            partial_state_advance(
+                mut_state,
+                Some(head.beacon_state_root()),
+                epoch.start_slot(T::EthSpec::slots_per_epoch()),
+                &self.spec,
+            )?;
+            mut_state.build_committee_cache(RelativeEpoch::Current, &self.spec)?;
+        }
+
+        // Only the committee index differs between the committees of a slot.
+        let data = self
+            .produce_unaggregated_attestation_for_block(
+                slot,
+                0,
+                head.beacon_block_root,
+                Cow::Borrowed(&*state),
+                head.beacon_state_root(),
+            )?
+            .data;
+
+        Ok((0..state.get_committee_count_at_slot(slot)?)
+            .map(|index| {
+                let mut data = data.clone();
+                data.index = index;
+                (index, data)
+            })
+            .collect())
+    }
+
     /// Produces an "unaggregated" attestation for the given `slot` and `index` that attests to
     /// `beacon_block_root`. The provided `state` should match the `block.state_root` for the
     /// `block` identified by `beacon_block_root`.
@@ -1167,6 +1618,62 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         })
     }
 
+    /// Verifies all of the given signature `sets`, returning `Err(Error::InvalidSignatureBatch)` if
+    /// any of them are invalid or if `sets` is empty.
+    ///
+    /// Batch verification is used when there are at least `MIN_BLS_BATCH_VERIFY_SIZE` sets,
+    /// otherwise each set is verified individually. A panic in the BLS library is caught and
+    /// returned as `Err(Error::SignatureVerificationPanicked)`.
+    pub fn verify_bls_signature_batch(&self, sets: Vec<SignatureSet<'_>>) -> Result<(), Error> {
+        let num_sets = sets.len();
+        metrics::observe(&metrics::BLS_SIGNATURE_BATCH_VERIFY_COUNT, num_sets as f64);
+
+        if sets.is_empty() {
+            return Err(Error::InvalidSignatureBatch);
+        }
+
+        let is_valid = catch_unwind(AssertUnwindSafe(move || {
+            if num_sets >= MIN_BLS_BATCH_VERIFY_SIZE {
+                verify_signature_sets(sets.iter())
+            } else {
+                sets.into_iter().all(|set| set.verify())
+            }
+        }))
+        .map_err(|_| {
+            crit!(
+                self.log,
+                "BLS signature verification panicked";
+                "num_sets" => num_sets,
+            );
+            Error::SignatureVerificationPanicked
+        })?;
+
+        if is_valid {
+            Ok(())
+        } else {
+            Err(Error::InvalidSignatureBatch)
+        }
+    }
+
+    /// Returns the fraction of the committee members which are included in `aggregate`.
+    ///
+    /// The size of the committee is taken to be the length of the aggregation bitfield, so this
+    /// is only meaningful for an aggregate that has been verified with
+    /// `Self::verify_aggregated_attestation_for_gossip`.
+    pub fn compute_aggregate_attestation_efficiency(
+        &self,
+        aggregate: &SignedAggregateAndProof<T::EthSpec>,
+    ) -> f64 {
+        let aggregation_bits = &aggregate.message.aggregate.aggregation_bits;
+        let committee_size = aggregation_bits.len();
+
+        if committee_size == 0 {
+            return 0.0;
+        }
+
+        aggregation_bits.num_set_bits() as f64 / committee_size as f64
+    }
+
     /// Accepts some attestation-type object and attempts to verify it in the context of fork
     /// choice. If it is valid it is applied to `self.fork_choice`.
     ///
@@ -1361,11 +1868,152 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         }
     }
 
+    /// Returns the number of distinct justified checkpoints for `epoch` amongst the blocks known
+    /// to fork choice.
+    ///
+    /// Only one checkpoint may be justified per epoch without 1/3 of validators being slashable,
+    /// so a count greater than one indicates a chain split. In that case a `chain_split_detected`
+    /// event is emitted.
+    pub fn count_justified_checkpoints_in_epoch(&self, epoch: Epoch) -> Result<usize, Error> {
+        let checkpoint_slot = epoch.start_slot(T::EthSpec::slots_per_epoch());
+
+        let justified_roots = {
+            let fork_choice = self.fork_choice.read();
+            let proto_array = fork_choice.proto_array().core_proto_array();
+
+            proto_array
+                .nodes
+                .iter()
+                .filter(|node| node.justified_epoch == epoch)
+                .filter_map(|node| {
+                    justified_checkpoint_root(proto_array, &node.root, checkpoint_slot)
+                })
+                .collect::<HashSet<_>>()
+        };
+
+        let count = justified_roots.len();
+
+        if count > 1 {
+            warn!(
+                self.log,
+                "Multiple justified checkpoints in epoch";
+                "epoch" => epoch,
+                "count" => count,
+            );
+
+            if let Some(event_handler) = self.event_handler.as_ref() {
+                if event_handler.has_chain_split_subscribers() {
+                    let mut justified_roots = justified_roots.into_iter().collect::<Vec<_>>();
+                    justified_roots.sort();
+                    event_handler.register(EventKind::ChainSplitDetected(SseChainSplit {
+                        epoch,
+                        justified_roots,
+                    }));
+                }
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// Scans the blocks known to fork choice for two with the same justified epoch but different
+    /// justified checkpoints, which is only possible if 1/3 of validators are slashable.
+    ///
+    /// The first such pair is returned. The first time a given pair is detected, the
+    /// `fork_choice_equivocation_detected_total` counter is incremented and a
+    /// `fork_choice_equivocation` event is emitted. This runs after fork choice updates which
+    /// change the justified checkpoint, and at least once per epoch.
+    pub fn monitor_fork_choice_equivocation(&self) -> Option<SseForkChoiceEquivocation> {
+        let slots_per_epoch = T::EthSpec::slots_per_epoch();
+
+        let equivocation = {
+            let fork_choice = self.fork_choice.read();
+            let proto_array = fork_choice.proto_array().core_proto_array();
+            let nodes = &proto_array.nodes;
+
+            // The justified checkpoint root of each node, by index. Parents always precede their
+            // children, so a node after the checkpoint slot with the same justified epoch as its
+            // parent reuses the parent's root. Only nodes which change the justified epoch walk back through their ancestors.
+            let mut checkpoint_roots: Vec<Option<Hash256>> = Vec::with_capacity(nodes.len());
+            let mut justified_roots: HashMap<Epoch, Hash256> = HashMap::new();
+            let mut equivocation = None;
+
+            for node in nodes {
+                let epoch = node.justified_epoch;
+                let checkpoint_slot = epoch.start_slot(slots_per_epoch);
+                let root = node
+                    .parent
+                    .filter(|&parent| {
+                        node.slot > checkpoint_slot
+                            && nodes
+                                .get(parent)
+                                .map_or(false, |parent| parent.justified_epoch == epoch)
+                    })
+                    .and_then(|parent| checkpoint_roots.get(parent).copied().flatten())
+                    .or_else(|| {
+                        justified_checkpoint_root(proto_array, &node.root, checkpoint_slot)
+                    });
+                checkpoint_roots.push(root);
+
+                let root = match root {
+                    Some(root) => root,
+                    None => continue,
+                };
+                let other_root = *justified_roots.entry(epoch).or_insert(root);
+                if root != other_root {
+                    let (root_1, root_2) = (cmp::min(root, other_root), cmp::max(root, other_root));
+                    if let Some(divergence_slot) = common_ancestor_slot(proto_array, root_1, root_2)
+                    {
+                        equivocation = Some(SseForkChoiceEquivocation {
+                            epoch,
+                            root_1,
+                            root_2,
+                            divergence_slot,
+                        });
+                        break;
+                    }
+                }
+            }
+
+            equivocation
+        }?;
+
+        let mut last_equivocation = self.last_fork_choice_equivocation.lock();
+        if last_equivocation.as_ref() != Some(&equivocation) {
+            metrics::inc_counter(&metrics::FORK_CHOICE_EQUIVOCATION_DETECTED_TOTAL);
+            warn!(
+                self.log,
+                "Fork choice equivocation detected";
+                "msg" => "two blocks have been justified in the same epoch",
+                "epoch" => equivocation.epoch,
+                "root_1" => ?equivocation.root_1,
+                "root_2" => ?equivocation.root_2,
+                "divergence_slot" => equivocation.divergence_slot,
+            );
+
+            if let Some(event_handler) = self.event_handler.as_ref() {
+                if event_handler.has_fork_choice_equivocation_subscribers() {
+                    event_handler.register(EventKind::ForkChoiceEquivocation(equivocation.clone()));
+                }
+            }
+
+            *last_equivocation = Some(equivocation.clone());
+        }
+
+        Some(equivocation)
+    }
+
     /// Verify a voluntary exit before allowing it to propagate on the gossip network.
     pub fn verify_voluntary_exit_for_gossip(
         &self,
         exit: SignedVoluntaryExit,
     ) -> Result<ObservationOutcome<SignedVoluntaryExit>, Error> {
+        // Exits are permanent, so a second exit for the same validator is always a duplicate.
+        // Check for this before cloning the head state to avoid wasted work on replayed exits.
+        if self.observed_voluntary_exits.lock().is_known(&exit) {
+            return Ok(ObservationOutcome::AlreadyKnown);
+        }
+
         // NOTE: this could be more efficient if it avoided cloning the head state
         let wall_clock_state = self.wall_clock_state()?;
         Ok(self
@@ -1418,11 +2066,83 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         attester_slashing: AttesterSlashing<T::EthSpec>,
     ) -> Result<ObservationOutcome<AttesterSlashing<T::EthSpec>>, Error> {
         let wall_clock_state = self.wall_clock_state()?;
-        Ok(self.observed_attester_slashings.lock().verify_and_observe(
+        let outcome = self.observed_attester_slashings.lock().verify_and_observe(
             attester_slashing,
             &wall_clock_state,
             &self.spec,
-        )?)
+        )?;
+
+        if let ObservationOutcome::New(slashing) = &outcome {
+            let slashing = slashing.as_inner();
+            // A slashing for votes on abandoned forks is still valid, it is only recorded here.
+            match self.verify_attester_slashing_matches_chain(slashing) {
+                Ok(canonicity) => {
+                    metrics::inc_counter_vec(
+                        &metrics::ATTESTER_SLASHING_CANONICITY_TOTAL,
+                        &[canonicity.as_ref()],
+                    );
+                    if canonicity != SlashingCanonicity::Canonical {
+                        debug!(
+                            self.log,
+                            "Attester slashing for non-canonical blocks";
+                            "canonicity" => ?canonicity,
+                            "block_root_1" => ?slashing.attestation_1.data.beacon_block_root,
+                            "block_root_2" => ?slashing.attestation_2.data.beacon_block_root,
+                        );
+                    }
+                }
+                Err(e) => debug!(
+                    self.log,
+                    "Unable to determine attester slashing canonicity";
+                    "error" => ?e
+                ),
+            }
+        }
+
+        Ok(outcome)
+    }
+
+    /// Determines whether the attestations in `slashing` vote for blocks on the canonical chain.
+    ///
+    /// A block is canonical if it is the head block or one of its ancestors. Blocks which are known
+    /// to fork choice are checked with `ProtoArrayForkChoice::is_descendant`, whilst blocks prior to
+    /// the finalized checkpoint are compared against the canonical block root at the slot of the
+    /// attestation.
+    pub fn verify_attester_slashing_matches_chain(
+        &self,
+        slashing: &AttesterSlashing<T::EthSpec>,
+    ) -> Result<SlashingCanonicity, Error> {
+        let head_block_root = self.head_info()?.block_root;
+
+        let is_canonical = |data: &AttestationData| -> Result<bool, Error> {
+            let block_root = data.beacon_block_root;
+            let known_to_fork_choice = {
+                let fork_choice = self.fork_choice.read();
+                let proto_array = fork_choice.proto_array();
+                if proto_array.contains_block(&block_root) {
+                    Some(proto_array.is_descendant(block_root, head_block_root))
+                } else {
+                    None
+                }
+            };
+
+            if let Some(is_canonical) = known_to_fork_choice {
+                Ok(is_canonical)
+            } else {
+                Ok(self.block_root_at_slot(data.slot, WhenSlotSkipped::Prev)? == Some(block_root))
+            }
+        };
+
+        let canonicity = match (
+            is_canonical(&slashing.attestation_1.data)?,
+            is_canonical(&slashing.attestation_2.data)?,
+        ) {
+            (true, true) => SlashingCanonicity::Canonical,
+            (false, false) => SlashingCanonicity::BothOnForks,
+            _ => SlashingCanonicity::OneOnCanonical,
+        };
+
+        Ok(canonicity)
     }
 
     /// Accept some attester slashing and queue it for inclusion in an appropriate block.
@@ -1437,6 +2157,317 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         Ok(())
     }
 
+    /// Scan the attestations in `block` and the op pool for double votes by the validators which
+    /// attested in `block`, i.e., validators that have attested to two different `AttestationData`
+    /// with the same target epoch.
+    ///
+    /// An `AttesterSlashing` is constructed for each pair of conflicting attestations. Slashings
+    /// which have not been seen before are verified, imported into the op pool and returned.
+    ///
+    /// The `BeaconChain` has no access to the network, so it is up to the caller to publish the
+    /// returned slashings on gossip.
+    pub fn monitor_attester_slashing_opportunity(
+        &self,
+        block: &SignedBeaconBlock<T::EthSpec>,
+    ) -> Vec<AttesterSlashing<T::EthSpec>> {
+        self.find_op_pool_attester_slashings(block)
+            .unwrap_or_else(|e| {
+                debug!(
+                    self.log,
+                    "Unable to scan op pool for slashings";
+                    "error" => ?e,
+                );
+                vec![]
+            })
+            .into_iter()
+            .filter_map(
+                |slashing| match self.verify_attester_slashing_for_gossip(slashing) {
+                    Ok(ObservationOutcome::New(verified)) => {
+                        let slashing = verified.as_inner().clone();
+                        if let Err(e) = self.import_attester_slashing(verified) {
+                            error!(
+                                self.log,
+                                "Unable to import op pool slashing";
+                                "error" => ?e,
+                            );
+                            return None;
+                        }
+                        Some(slashing)
+                    }
+                    Ok(ObservationOutcome::AlreadyKnown) => None,
+                    Err(e) => {
+                        debug!(
+                            self.log,
+                            "Invalid op pool slashing";
+                            "error" => ?e,
+                        );
+                        None
+                    }
+                },
+            )
+            .collect()
+    }
+
+    /// Returns an `AttesterSlashing` for each pair of attestations in `block` or the op pool which
+    /// contain a double vote by a validator which attested in `block`.
+    ///
+    /// Only attestations from the previous and current epochs of the head state are considered.
+    fn find_op_pool_attester_slashings(
+        &self,
+        block: &SignedBeaconBlock<T::EthSpec>,
+    ) -> Result<Vec<AttesterSlashing<T::EthSpec>>, Error> {
+        if block.message.body.attestations.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let pool_attestations = self.op_pool.get_all_attestations();
+
+        let (block_attestations, pool_attestations) = {
+            let head = self
+                .canonical_head
+                .try_read_for(HEAD_LOCK_TIMEOUT)
+                .ok_or(Error::CanonicalHeadLockTimeout)?;
+            let state = &head.beacon_state;
+            let get_indexed = |attestation: &Attestation<T::EthSpec>| {
+                let committee = state
+                    .get_beacon_committee(attestation.data.slot, attestation.data.index)
+                    .ok()?;
+                get_indexed_attestation(committee.committee, attestation).ok()
+            };
+
+            let block_attestations = block
+                .message
+                .body
+                .attestations
+                .iter()
+                .filter_map(get_indexed)
+                .collect::<Vec<_>>();
+            if block_attestations.is_empty() {
+                return Ok(vec![]);
+            }
+
+            let pool_attestations = pool_attestations
+                .iter()
+                .filter_map(get_indexed)
+                .collect::<Vec<_>>();
+
+            (block_attestations, pool_attestations)
+        };
+
+        let block_attesters = block_attestations
+            .iter()
+            .flat_map(|indexed_attestation| indexed_attestation.attesting_indices.iter().copied())
+            .collect::<HashSet<_>>();
+        let indexed_attestations = block_attestations
+            .into_iter()
+            .chain(pool_attestations)
+            .collect::<Vec<_>>();
+
+        // Maps each `(target_epoch, validator_index)` to the first attestation it was seen in.
+        let mut first_votes = HashMap::new();
+        let mut conflicting_pairs = HashSet::new();
+
+        for (i, indexed_attestation) in indexed_attestations.iter().enumerate() {
+            let target_epoch = indexed_attestation.data.target.epoch;
+
+            for &validator_index in indexed_attestation
+                .attesting_indices
+                .iter()
+                .filter(|index| block_attesters.contains(index))
+            {
+                let first = *first_votes
+                    .entry((target_epoch, validator_index))
+                    .or_insert(i);
+
+                if indexed_attestations[first].data != indexed_attestation.data {
+                    conflicting_pairs.insert((first, i));
+                }
+            }
+        }
+
+        Ok(conflicting_pairs
+            .into_iter()
+            .map(|(first, second)| AttesterSlashing {
+                attestation_1: indexed_attestations[first].clone(),
+                attestation_2: indexed_attestations[second].clone(),
+            })
+            .collect())
+    }
+
+    /// Verify the merkle proofs of `deposits` against the `eth1_data.deposit_root` of the head
+    /// state, in parallel.
+    ///
+    /// The deposits must be contiguous, starting from the `eth1_deposit_index` of the head state.
+    /// Returns one entry per deposit, which is `true` if its proof is valid.
+    pub fn verify_deposit_merkle_proofs_batch(
+        &self,
+        deposits: &[Deposit],
+    ) -> Result<Vec<bool>, Error> {
+        let _timer = metrics::start_timer(&metrics::DEPOSIT_PROOFS_BATCH_VERIFICATION_TIMES);
+
+        self.with_head(|head| {
+            Ok(verify_deposit_merkle_proofs_batch(
+                &head.beacon_state,
+                deposits,
+                &self.spec,
+            ))
+        })
+    }
+
+    /// Returns the deposit with `deposit_index` from the eth1 deposit cache, along with a merkle
+    /// proof against the `deposit_root` of all cached deposits.
+    pub fn get_deposit_contract_call_data(
+        &self,
+        deposit_index: u64,
+    ) -> Result<DepositContractData, Error> {
+        self.eth1_chain
+            .as_ref()
+            .ok_or(Error::NoEth1ChainConnection)?
+            .deposit_contract_data(deposit_index)?
+            .ok_or(Error::UnknownDeposit(deposit_index))
+    }
+
+    /// Returns the `(validator_index, exit_epoch)` of each voluntary exit included in the
+    /// canonical blocks of `epoch`, in block order.
+    pub fn get_on_chain_exits_for_epoch(&self, epoch: Epoch) -> Result<Vec<(u64, Epoch)>, Error> {
+        Ok(self
+            .canonical_blocks_in_epoch(epoch)?
+            .iter()
+            .flat_map(|block| block.message.body.voluntary_exits.iter())
+            .map(|exit| (exit.message.validator_index, exit.message.epoch))
+            .collect())
+    }
+
+    /// Returns the proposer slashings included in the canonical blocks of `epoch`, in block
+    /// order.
+    pub fn get_proposer_slashings_for_epoch(
+        &self,
+        epoch: Epoch,
+    ) -> Result<Vec<ProposerSlashing>, Error> {
+        Ok(self
+            .canonical_blocks_in_epoch(epoch)?
+            .into_iter()
+            .flat_map(|block| Vec::from(block.message.body.proposer_slashings))
+            .collect())
+    }
+
+    /// Returns the canonical blocks in `epoch`, in slot order.
+    ///
+    /// Blocks are read from the hot or cold database. Slots later than the current wall-clock slot
+    /// are ignored.
+    fn canonical_blocks_in_epoch(
+        &self,
+        epoch: Epoch,
+    ) -> Result<Vec<SignedBeaconBlock<T::EthSpec>>, Error> {
+        epoch
+            .slot_iter(T::EthSpec::slots_per_epoch())
+            .filter_map(|slot| {
+                self.block_root_at_slot(slot, WhenSlotSkipped::None)
+                    .transpose()
+            })
+            .map(|block_root| {
+                let block_root = block_root?;
+                self.get_block(&block_root)?
+                    .ok_or(Error::MissingBeaconBlock(block_root))
+            })
+            .collect()
+    }
+
+    /// Returns the sum of the effective balances of all validators active in `epoch`.
+    ///
+    /// If the head state has a committee cache for `epoch` the total is computed from its cached
+    /// active indices without cloning the head. Otherwise the state at the start of `epoch` is
+    /// loaded (from the hot or cold database, or by skipping slots for a future epoch).
+    pub fn get_total_active_balance_at_epoch(&self, epoch: Epoch) -> Result<u64, Error> {
+        let cached_total = self.with_head(|head| {
+            let state = &head.beacon_state;
+            let total = RelativeEpoch::from_epoch(state.current_epoch(), epoch)
+                .ok()
+                .and_then(|relative_epoch| {
+                    state
+                        .get_cached_active_validator_indices(relative_epoch)
+                        .ok()
+                })
+                .map(|indices| state.get_total_balance(indices, &self.spec))
+                .transpose()?;
+            Ok::<_, Error>(total)
+        })?;
+
+        if let Some(total) = cached_total {
+            return Ok(total);
+        }
+
+        let state = self.state_at_slot(
+            epoch.start_slot(T::EthSpec::slots_per_epoch()),
+            StateSkipConfig::WithoutStateRoots,
+        )?;
+        let active_indices = state.get_active_validator_indices(epoch, &self.spec)?;
+        Ok(state.get_total_balance(&active_indices, &self.spec)?)
+    }
+
+    /// Run the state transition for `block` on top of its parent state, without writing anything
+    /// to the database or updating fork choice.
+    ///
+    /// Signatures are verified in the same way as during import. An invalid block is not an error;
+    /// the reason it failed is returned in the `SimulatedImportResult`. An error is returned only
+    /// if the parent block or state is unavailable.
+    pub fn simulate_block_import(
+        &self,
+        block: &SignedBeaconBlock<T::EthSpec>,
+    ) -> Result<SimulatedImportResult, Error> {
+        let start = Instant::now();
+
+        let parent_root = block.parent_root();
+        let parent_block = self
+            .get_block(&parent_root)?
+            .ok_or(Error::MissingBeaconBlock(parent_root))?;
+        let parent_state_root = parent_block.state_root();
+        let mut state = self
+            .get_state(&parent_state_root, Some(parent_block.slot()))?
+            .ok_or(Error::MissingBeaconState(parent_state_root))?;
+
+        if let Err(e) = complete_state_advance(
+            &mut state,
+            Some(parent_state_root),
+            block.slot(),
+            &self.spec,
+        ) {
+            return Ok(SimulatedImportResult {
+                state_root: None,
+                processing_time: start.elapsed(),
+                validation_error: Some(SimulatedImportError::StateAdvance(e)),
+            });
+        }
+        state.build_committee_cache(RelativeEpoch::Previous, &self.spec)?;
+        state.build_committee_cache(RelativeEpoch::Current, &self.spec)?;
+
+        let (state_root, validation_error) = match per_block_processing(
+            &mut state,
+            block,
+            Some(block.canonical_root()),
+            BlockSignatureStrategy::VerifyBulk,
+            &self.spec,
+        ) {
+            Ok(()) => {
+                let local = state.update_tree_hash_cache()?;
+                let mismatch = (local != block.state_root()).then(|| {
+                    SimulatedImportError::StateRootMismatch {
+                        block: block.state_root(),
+                        local,
+                    }
+                });
+                (Some(local), mismatch)
+            }
+            Err(e) => (None, Some(SimulatedImportError::BlockProcessing(e))),
+        };
+
+        Ok(SimulatedImportResult {
+            state_root,
+            processing_time: start.elapsed(),
+            validation_error,
+        })
+    }
+
     /// Attempt to verify and import a chain of blocks to `self`.
     ///
     /// The provided blocks _must_ each reference the previous block via `block.parent_root` (i.e.,
@@ -2204,7 +3235,7 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
     /// Execute the fork choice algorithm and enthrone the result as the canonical head.
     pub fn fork_choice(&self) -> Result<(), Error> {
         metrics::inc_counter(&metrics::FORK_CHOICE_REQUESTS);
-        let _timer = metrics::start_timer(&metrics::FORK_CHOICE_TIMES);
+        let timer = metrics::start_timer(&metrics::FORK_CHOICE_TIMES);
 
         let result = self.fork_choice_internal();
 
@@ -2212,9 +3243,33 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
             metrics::inc_counter(&metrics::FORK_CHOICE_ERRORS);
         }
 
+        metrics::stop_timer(timer);
+
+        self.maybe_monitor_fork_choice_equivocation();
+
         result
     }
 
+    /// Runs `Self::monitor_fork_choice_equivocation` if the justified checkpoint of fork choice or
+    /// the current epoch has changed since it last ran.
+    fn maybe_monitor_fork_choice_equivocation(&self) {
+        let current_epoch = match self.epoch() {
+            Ok(epoch) => epoch,
+            Err(_) => return,
+        };
+        let justified_checkpoint = *self.fork_choice.read().fc_store().justified_checkpoint();
+
+        {
+            let mut last_check = self.last_fork_choice_equivocation_check.lock();
+            if *last_check == Some((current_epoch, justified_checkpoint)) {
+                return;
+            }
+            *last_check = Some((current_epoch, justified_checkpoint));
+        }
+
+        self.monitor_fork_choice_equivocation();
+    }
+
     fn fork_choice_internal(&self) -> Result<(), Error> {
         // Determine the root of the block that is the head of the chain.
         let beacon_block_root = self.fork_choice.write().get_head(self.slot()?)?;
@@ -2511,6 +3566,68 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         Ok(())
     }
 
+    /// Returns the number of keys in each column of the hot database and, if `include_sizes` is
+    /// `true`, their total size.
+    ///
+    /// Every key in the database is read, so this may take some time on a large database. Reading
+    /// the sizes also reads every value, including the full states stored in the hot database.
+    pub fn list_hot_db_columns(&self, include_sizes: bool) -> Result<Vec<ColumnStats>, Error> {
+        DBColumn::ALL
+            .iter()
+            .map(|column| -> Result<_, Error> {
+                let size = self.store.hot_db.column_size(*column, include_sizes)?;
+                Ok(ColumnStats {
+                    column_name: format!("{:?}", column),
+                    key_count: size.key_count,
+                    approximate_size_bytes: size.size_bytes,
+                })
+            })
+            .collect()
+    }
+
+    /// Cross-references the blocks known to fork choice against the database, returning a report
+    /// for each block or state which fork choice expects to exist but which is missing from the
+    /// database.
+    ///
+    /// States prior to the split slot may have been pruned or moved to the freezer database, so
+    /// they are not checked. Only blocks descending from the finalized root are checked, since
+    /// abandoned forks may have been pruned from the database by the migrator before they are
+    /// pruned from fork choice.
+    pub fn verify_block_tree_consistency(&self) -> Result<Vec<InconsistencyReport>, Error> {
+        let nodes = {
+            let fork_choice = self.fork_choice.read();
+            fork_choice
+                .proto_array()
+                .core_proto_array()
+                .nodes
+                .iter()
+                .filter(|node| fork_choice.is_descendant_of_finalized(node.root))
+                .map(|node| (node.root, node.slot, node.state_root))
+                .collect::<Vec<_>>()
+        };
+        let split_slot = self.store.get_split_slot();
+
+        let mut reports = vec![];
+        for (block_root, slot, state_root) in nodes {
+            if !self
+                .store
+                .item_exists::<SignedBeaconBlock<T::EthSpec>>(&block_root)?
+            {
+                reports.push(InconsistencyReport::MissingBlock { block_root, slot });
+            }
+
+            if slot >= split_slot && self.store.load_hot_state_summary(&state_root)?.is_none() {
+                reports.push(InconsistencyReport::MissingState {
+                    block_root,
+                    slot,
+                    state_root,
+                });
+            }
+        }
+
+        Ok(reports)
+    }
+
     /// Called by the timer on every slot.
     ///
     /// Performs slot-based pruning.
@@ -2554,6 +3671,17 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
 
         self.op_pool.prune_all(head_state, self.epoch()?);
 
+        {
+            let mut history = self.finality_checkpoints_history.write();
+            if history.len() >= MAX_FINALITY_CHECKPOINTS_HISTORY_LEN {
+                history.pop_front();
+            }
+            history.push_back((
+                new_finalized_checkpoint.epoch,
+                new_finalized_checkpoint.root,
+            ));
+        }
+
         self.store_migrator.process_finalization(
             new_finalized_state_root.into(),
             new_finalized_checkpoint,
@@ -2978,3 +4106,36 @@ impl<T: EthSpec> ChainSegmentResult<T> {
         }
     }
 }
+
+/// Returns the justified checkpoint root of `block_root` given the start slot of its justified
+/// epoch, i.e. its ancestor at `checkpoint_slot` (or the first block prior, if that slot was
+/// skipped).
+///
+/// Returns `None` if the checkpoint is prior to the oldest block in `proto_array`.
+fn justified_checkpoint_root(
+    proto_array: &ProtoArray,
+    block_root: &Hash256,
+    checkpoint_slot: Slot,
+) -> Option<Hash256> {
+    proto_array
+        .iter_block_roots(block_root)
+        .find(|(_, slot)| *slot <= checkpoint_slot)
+        .map(|(root, _)| root)
+}
+
+/// Returns the slot of the most recent block which is an ancestor of both `root_1` and `root_2`.
+fn common_ancestor_slot(
+    proto_array: &ProtoArray,
+    root_1: Hash256,
+    root_2: Hash256,
+) -> Option<Slot> {
+    let ancestors_1 = proto_array
+        .iter_block_roots(&root_1)
+        .map(|(root, _)| root)
+        .collect::<HashSet<_>>();
+
+    proto_array
+        .iter_block_roots(&root_2)
+        .find(|(root, _)| ancestors_1.contains(root))
+        .map(|(_, slot)| slot)
+}
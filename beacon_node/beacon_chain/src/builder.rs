@@ -1,4 +1,6 @@
-use crate::beacon_chain::{BEACON_CHAIN_DB_KEY, ETH1_CACHE_DB_KEY, OP_POOL_DB_KEY};
+use crate::beacon_chain::{
+    BEACON_CHAIN_DB_KEY, BLOCK_ROOT_STATE_CACHE_SIZE, ETH1_CACHE_DB_KEY, OP_POOL_DB_KEY,
+};
 use crate::eth1_chain::{CachingEth1Backend, SszEth1};
 use crate::head_tracker::HeadTracker;
 use crate::migrate::{BackgroundMigrator, MigratorConfig};
@@ -16,11 +18,13 @@ use crate::{
 use eth1::Config as Eth1Config;
 use fork_choice::ForkChoice;
 use futures::channel::mpsc::Sender;
+use lru::LruCache;
 use operation_pool::{OperationPool, PersistedOperationPool};
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use slasher::Slasher;
-use slog::{crit, info, Logger};
+use slog::{crit, info, warn, Logger};
 use slot_clock::{SlotClock, TestingSlotClock};
+use std::collections::VecDeque;
 use std::marker::PhantomData;
 use std::sync::Arc;
 use std::time::Duration;
@@ -531,6 +535,10 @@ where
             )),
             shuffling_cache: TimeoutRwLock::new(ShufflingCache::new()),
             beacon_proposer_cache: <_>::default(),
+            checkpoint_state_cache: <_>::default(),
+            last_fork_choice_equivocation: <_>::default(),
+            last_fork_choice_equivocation_check: <_>::default(),
+            block_root_state_cache: Mutex::new(LruCache::new(BLOCK_ROOT_STATE_CACHE_SIZE)),
             validator_pubkey_cache: TimeoutRwLock::new(validator_pubkey_cache),
             disabled_forks: self.disabled_forks,
             shutdown_sender: self
@@ -540,6 +548,7 @@ where
             graffiti: self.graffiti,
             slasher: self.slasher.clone(),
             validator_monitor: RwLock::new(validator_monitor),
+            finality_checkpoints_history: RwLock::new(VecDeque::new()),
         };
 
         let head = beacon_chain
@@ -567,6 +576,26 @@ where
             }
         }
 
+        if beacon_chain.config.verify_tree_consistency {
+            let reports = beacon_chain
+                .verify_block_tree_consistency()
+                .map_err(|e| format!("Failed to verify block tree consistency: {:?}", e))?;
+
+            for report in &reports {
+                warn!(
+                    log,
+                    "Database is missing data known to fork choice";
+                    "report" => ?report,
+                );
+            }
+
+            info!(
+                log,
+                "Verified block tree consistency";
+                "inconsistencies" => reports.len(),
+            );
+        }
+
         info!(
             log,
             "Beacon chain initialized";
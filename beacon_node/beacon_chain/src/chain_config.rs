@@ -1,6 +1,11 @@
 use serde_derive::{Deserialize, Serialize};
 use types::Checkpoint;
 
+/// The default number of slots a peer's head may be ahead of the local slot clock.
+pub const DEFAULT_FUTURE_SLOT_TOLERANCE: u64 = 1;
+/// The largest permitted `future_slot_tolerance`.
+pub const MAX_FUTURE_SLOT_TOLERANCE: u64 = 4;
+
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
 pub struct ChainConfig {
     /// Maximum number of slots to skip when importing a consensus message (e.g., block,
@@ -12,6 +17,12 @@ pub struct ChainConfig {
     ///
     /// If `None`, there is no weak subjectivity verification.
     pub weak_subjectivity_checkpoint: Option<Checkpoint>,
+    /// The number of slots ahead of the local slot clock that a peer's head or an RPC block may
+    /// be before it is attributed to a clock or genesis time mismatch.
+    pub future_slot_tolerance: u64,
+    /// Check that every block known to fork choice, and its state, is present in the database
+    /// during start-up.
+    pub verify_tree_consistency: bool,
 }
 
 impl Default for ChainConfig {
@@ -19,6 +30,8 @@ impl Default for ChainConfig {
         Self {
             import_max_skip_slots: None,
             weak_subjectivity_checkpoint: None,
+            future_slot_tolerance: DEFAULT_FUTURE_SLOT_TOLERANCE,
+            verify_tree_consistency: false,
         }
     }
 }
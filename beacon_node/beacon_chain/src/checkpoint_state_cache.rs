@@ -0,0 +1,77 @@
+//! The `CheckpointStateCache` stores the canonical states at the start slots of recent epochs.
+//!
+//! This cache is keyed by `(epoch, state_root)` where `state_root` is the root of the canonical
+//! state at `start_slot(epoch)`. Keying by the state root ensures a re-org which changes the
+//! checkpoint state does not result in a stale state being served.
+//!
+//! Only the checkpoint states of the current and previous epochs are retained, older states are
+//! pruned as the epoch advances.
+
+use types::{BeaconState, Epoch, EthSpec, Hash256};
+
+/// The maximum number of checkpoint states that may be cached.
+const CACHE_SIZE: usize = 2;
+
+/// A cache of recent checkpoint states.
+///
+/// See the module-level documentation for more information.
+pub struct CheckpointStateCache<E: EthSpec> {
+    states: Vec<(Epoch, Hash256, BeaconState<E>)>,
+}
+
+impl<E: EthSpec> Default for CheckpointStateCache<E> {
+    fn default() -> Self {
+        Self {
+            states: Vec::with_capacity(CACHE_SIZE),
+        }
+    }
+}
+
+impl<E: EthSpec> CheckpointStateCache<E> {
+    /// Returns a clone of the checkpoint state for `epoch` with `state_root`, if it is cached.
+    pub fn get(&self, epoch: Epoch, state_root: Hash256) -> Option<BeaconState<E>> {
+        self.states
+            .iter()
+            .find(|(cached_epoch, cached_root, _)| {
+                *cached_epoch == epoch && *cached_root == state_root
+            })
+            .map(|(_, _, state)| state.clone())
+    }
+
+    /// Caches the checkpoint `state` for `epoch` if `epoch` is the current or previous epoch,
+    /// dropping any states older than the previous epoch.
+    pub fn insert(
+        &mut self,
+        current_epoch: Epoch,
+        epoch: Epoch,
+        state_root: Hash256,
+        state: BeaconState<E>,
+    ) {
+        self.prune(current_epoch);
+
+        if epoch + 1 < current_epoch || epoch > current_epoch {
+            return;
+        }
+
+        // Replace any state for the same epoch, it must have been re-orged out.
+        self.states
+            .retain(|(cached_epoch, _, _)| *cached_epoch != epoch);
+        self.states.push((epoch, state_root, state));
+    }
+
+    /// Drops all states from epochs prior to the previous epoch.
+    pub fn prune(&mut self, current_epoch: Epoch) {
+        self.states
+            .retain(|(cached_epoch, _, _)| *cached_epoch + 1 >= current_epoch);
+    }
+
+    /// Returns the number of cached states.
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Returns `true` if no states are cached.
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+}
@@ -82,6 +82,10 @@ pub enum BeaconChainError {
     ValidatorPubkeyCacheIncomplete(usize),
     SignatureSetError(SignatureSetError),
     BlockSignatureVerifierError(state_processing::block_signature_verifier::Error),
+    /// One or more signatures passed to `BeaconChain::verify_bls_signature_batch` are invalid.
+    InvalidSignatureBatch,
+    /// The BLS library panicked whilst verifying signatures.
+    SignatureVerificationPanicked,
     DuplicateValidatorPublicKey,
     ValidatorPubkeyCacheFileError(String),
     ValidatorIndexUnknown(usize),
@@ -117,6 +121,12 @@ pub enum BeaconChainError {
         request_slot: Slot,
         slot: Slot,
     },
+    Eth1ChainError(Eth1ChainError),
+    /// The `BeaconChain` was explicitly configured _without_ a connection to eth1.
+    NoEth1ChainConnection,
+    /// The deposit with the given index is not in the eth1 deposit cache.
+    UnknownDeposit(u64),
+    UnmonitoredValidator(u64),
 }
 
 easy_from_to!(SlotProcessingError, BeaconChainError);
@@ -135,6 +145,7 @@ easy_from_to!(PruningError, BeaconChainError);
 easy_from_to!(ArithError, BeaconChainError);
 easy_from_to!(ForkChoiceStoreError, BeaconChainError);
 easy_from_to!(StateAdvanceError, BeaconChainError);
+easy_from_to!(Eth1ChainError, BeaconChainError);
 
 #[derive(Debug)]
 pub enum BlockProductionError {
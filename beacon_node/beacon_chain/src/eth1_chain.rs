@@ -1,6 +1,6 @@
 use crate::metrics;
 use eth1::{Config as Eth1Config, Eth1Block, Service as HttpService};
-use eth2::lighthouse::Eth1SyncStatusData;
+use eth2::lighthouse::{DepositContractData, Eth1SyncStatusData};
 use eth2_hashing::hash;
 use slog::{debug, error, trace, Logger};
 use ssz::{Decode, Encode};
@@ -52,6 +52,9 @@ pub enum Error {
     UnknownPreviousEth1BlockHash,
     /// An arithmetic error occurred.
     ArithError(safe_arith::ArithError),
+    /// Deposit processing is disabled, but the state requires deposits to be included in the
+    /// next block.
+    DepositProcessingDisabled { pending_deposits: u64 },
 }
 
 impl From<safe_arith::ArithError> for Error {
@@ -273,6 +276,21 @@ where
         )
     }
 
+    /// Returns the deposit with the given `deposit_index` and a merkle proof against the root of all
+    /// known deposits, if the deposit is known.
+    ///
+    /// Always returns `None` when using the dummy backend.
+    pub fn deposit_contract_data(
+        &self,
+        deposit_index: u64,
+    ) -> Result<Option<DepositContractData>, Error> {
+        if self.use_dummy_backend {
+            Ok(None)
+        } else {
+            self.backend.deposit_contract_data(deposit_index)
+        }
+    }
+
     /// Instantiate `Eth1Chain` from a persisted `SszEth1`.
     ///
     /// The `Eth1Chain` will have the same caches as the persisted `SszEth1`.
@@ -325,6 +343,13 @@ pub trait Eth1ChainBackend<T: EthSpec>: Sized + Send + Sync {
         spec: &ChainSpec,
     ) -> Result<Vec<Deposit>, Error>;
 
+    /// Returns the deposit with `deposit_index`, with a merkle proof against the root of all the
+    /// deposits known to the backend. Returns `None` if the deposit is unknown.
+    fn deposit_contract_data(
+        &self,
+        deposit_index: u64,
+    ) -> Result<Option<DepositContractData>, Error>;
+
     /// Returns the latest block stored in the cache. Used to obtain an idea of how up-to-date the
     /// beacon node eth1 cache is.
     fn latest_cached_block(&self) -> Option<Eth1Block>;
@@ -379,6 +404,11 @@ impl<T: EthSpec> Eth1ChainBackend<T> for DummyEth1ChainBackend<T> {
         Ok(vec![])
     }
 
+    /// The dummy back-end never has deposits.
+    fn deposit_contract_data(&self, _: u64) -> Result<Option<DepositContractData>, Error> {
+        Ok(None)
+    }
+
     fn latest_cached_block(&self) -> Option<Eth1Block> {
         None
     }
@@ -434,10 +464,26 @@ impl<T: EthSpec> CachingEth1Backend<T> {
     }
 
     /// Starts the routine which connects to the external eth1 node and updates the caches.
+    ///
+    /// Does nothing if deposit processing is disabled.
     pub fn start(&self, handle: TaskExecutor) {
+        if self.deposit_processing_disabled() {
+            debug!(
+                self.log,
+                "Not starting eth1 cache updates";
+                "reason" => "deposit processing is disabled"
+            );
+            return;
+        }
+
         HttpService::auto_update(self.core.clone(), handle);
     }
 
+    /// Returns `true` if the eth1 node should not be polled and no new deposits processed.
+    fn deposit_processing_disabled(&self) -> bool {
+        self.core.config().disable_deposit_processing
+    }
+
     /// Instantiates `self` from an existing service.
     pub fn from_service(service: HttpService) -> Self {
         Self {
@@ -450,6 +496,11 @@ impl<T: EthSpec> CachingEth1Backend<T> {
 
 impl<T: EthSpec> Eth1ChainBackend<T> for CachingEth1Backend<T> {
     fn eth1_data(&self, state: &BeaconState<T>, spec: &ChainSpec) -> Result<Eth1Data, Error> {
+        // Without an eth1 node, the best we can do is vote for the last known deposit root.
+        if self.deposit_processing_disabled() {
+            return Ok(state.eth1_data.clone());
+        }
+
         let period = T::SlotsPerEth1VotingPeriod::to_u64();
         let voting_period_start_slot = (state.slot / period) * period;
         let voting_period_start_seconds = slot_start_seconds::<T>(
@@ -522,6 +573,20 @@ impl<T: EthSpec> Eth1ChainBackend<T> for CachingEth1Backend<T> {
         eth1_data_vote: &Eth1Data,
         _spec: &ChainSpec,
     ) -> Result<Vec<Deposit>, Error> {
+        // A block which omits deposits required by the state is invalid, so refuse to produce
+        // one rather than returning an empty list.
+        if self.deposit_processing_disabled() {
+            let pending_deposits = state
+                .eth1_data
+                .deposit_count
+                .saturating_sub(state.eth1_deposit_index);
+            return if pending_deposits == 0 {
+                Ok(vec![])
+            } else {
+                Err(Error::DepositProcessingDisabled { pending_deposits })
+            };
+        }
+
         let deposit_index = state.eth1_deposit_index;
         let deposit_count = if let Some(new_eth1_data) = get_new_eth1_data(state, eth1_data_vote)? {
             new_eth1_data.deposit_count
@@ -547,6 +612,39 @@ impl<T: EthSpec> Eth1ChainBackend<T> for CachingEth1Backend<T> {
         }
     }
 
+    fn deposit_contract_data(
+        &self,
+        deposit_index: u64,
+    ) -> Result<Option<DepositContractData>, Error> {
+        let deposits = self.core.deposits().read();
+        let eth1_block = match deposits.cache.get(deposit_index as usize) {
+            Some(log) => log.block_number,
+            None => return Ok(None),
+        };
+        let deposit_count = deposits.cache.len() as u64;
+
+        let (deposit_root, mut proven) = deposits
+            .cache
+            .get_deposits(
+                deposit_index,
+                deposit_index + 1,
+                deposit_count,
+                DEPOSIT_TREE_DEPTH,
+            )
+            .map_err(|e| Error::BackendError(format!("Failed to get deposit: {:?}", e)))?;
+        let deposit = proven
+            .pop()
+            .ok_or_else(|| Error::BackendError("Failed to get deposit".to_string()))?;
+
+        Ok(Some(DepositContractData {
+            deposit_data: deposit.data,
+            proof: deposit.proof.to_vec(),
+            eth1_block,
+            deposit_count,
+            deposit_root,
+        }))
+    }
+
     fn latest_cached_block(&self) -> Option<Eth1Block> {
         self.core.latest_cached_block()
     }
@@ -723,6 +821,7 @@ mod test {
     mod eth1_chain_json_backend {
         use super::*;
         use eth1::DepositLog;
+        use tree_hash::TreeHash;
         use types::{
             test_utils::{generate_deterministic_keypair, TestingDepositBuilder},
             EthSpec, MainnetEthSpec,
@@ -788,6 +887,53 @@ mod test {
             );
         }
 
+        #[test]
+        fn deposit_processing_disabled() {
+            let spec = &E::default_spec();
+
+            let eth1_config = Eth1Config {
+                disable_deposit_processing: true,
+                ..Eth1Config::default()
+            };
+            let eth1_chain: Eth1Chain<_, E> = Eth1Chain::new(CachingEth1Backend::new(
+                eth1_config,
+                null_logger().unwrap(),
+                MainnetEthSpec::default_spec(),
+            ));
+
+            // A mature network where all deposits have been included.
+            let mut state: BeaconState<E> = BeaconState::new(0, get_eth1_data(0), &spec);
+            state.eth1_deposit_index = 42;
+            state.eth1_data.deposit_count = 42;
+
+            assert_eq!(
+                eth1_chain
+                    .eth1_data_for_block_production(&state, spec)
+                    .expect("should produce eth1 data"),
+                state.eth1_data,
+                "should vote for the last known eth1 data"
+            );
+            assert!(
+                eth1_chain
+                    .deposits_for_block_inclusion(&state, &state.eth1_data, spec)
+                    .expect("should succeed if no deposits are required")
+                    .is_empty(),
+                "should not include any deposits"
+            );
+
+            state.eth1_data.deposit_count = 43;
+
+            assert!(
+                matches!(
+                    eth1_chain.deposits_for_block_inclusion(&state, &state.eth1_data, spec),
+                    Err(Error::DepositProcessingDisabled {
+                        pending_deposits: 1
+                    })
+                ),
+                "should fail if deposits are required"
+            );
+        }
+
         #[test]
         fn deposits_with_cache() {
             let spec = &E::default_spec();
@@ -871,6 +1017,62 @@ mod test {
             })
         }
 
+        #[test]
+        fn deposit_contract_data() {
+            let spec = &E::default_spec();
+
+            let eth1_chain = get_eth1_chain();
+            let deposit_count = 5;
+
+            assert_eq!(
+                eth1_chain.deposit_contract_data(0).unwrap(),
+                None,
+                "should not find a deposit in an empty cache"
+            );
+
+            let deposits: Vec<_> = (0..deposit_count)
+                .map(|i| get_deposit_log(i, spec))
+                .inspect(|log| {
+                    eth1_chain
+                        .backend
+                        .core
+                        .deposits()
+                        .write()
+                        .cache
+                        .insert_log(log.clone())
+                        .expect("should insert log");
+                })
+                .collect();
+
+            for log in &deposits {
+                let data = eth1_chain
+                    .deposit_contract_data(log.index)
+                    .unwrap()
+                    .expect("should find deposit");
+
+                assert_eq!(data.deposit_data, log.deposit_data);
+                assert_eq!(data.eth1_block, log.block_number);
+                assert_eq!(data.deposit_count, deposit_count);
+                assert!(
+                    merkle_proof::verify_merkle_proof(
+                        data.deposit_data.tree_hash_root(),
+                        &data.proof,
+                        DEPOSIT_TREE_DEPTH + 1,
+                        log.index as usize,
+                        data.deposit_root,
+                    ),
+                    "proof for deposit {} should be valid",
+                    log.index
+                );
+            }
+
+            assert_eq!(
+                eth1_chain.deposit_contract_data(deposit_count).unwrap(),
+                None,
+                "should not find an unknown deposit"
+            );
+        }
+
         #[test]
         fn eth1_data_empty_cache() {
             let spec = &E::default_spec();
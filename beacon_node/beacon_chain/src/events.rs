@@ -1,4 +1,6 @@
-pub use eth2::types::{EventKind, SseBlock, SseFinalizedCheckpoint, SseHead};
+pub use eth2::types::{
+    EventKind, SseBlock, SseChainSplit, SseFinalizedCheckpoint, SseForkChoiceEquivocation, SseHead,
+};
 use slog::{trace, Logger};
 use tokio::sync::broadcast;
 use tokio::sync::broadcast::{error::SendError, Receiver, Sender};
@@ -12,6 +14,8 @@ pub struct ServerSentEventHandler<T: EthSpec> {
     finalized_tx: Sender<EventKind<T>>,
     head_tx: Sender<EventKind<T>>,
     exit_tx: Sender<EventKind<T>>,
+    chain_split_tx: Sender<EventKind<T>>,
+    fork_choice_equivocation_tx: Sender<EventKind<T>>,
     log: Logger,
 }
 
@@ -22,6 +26,8 @@ impl<T: EthSpec> ServerSentEventHandler<T> {
         let (finalized_tx, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
         let (head_tx, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
         let (exit_tx, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        let (chain_split_tx, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        let (fork_choice_equivocation_tx, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
 
         Self {
             attestation_tx,
@@ -29,6 +35,8 @@ impl<T: EthSpec> ServerSentEventHandler<T> {
             finalized_tx,
             head_tx,
             exit_tx,
+            chain_split_tx,
+            fork_choice_equivocation_tx,
             log,
         }
     }
@@ -39,6 +47,8 @@ impl<T: EthSpec> ServerSentEventHandler<T> {
         let (finalized_tx, _) = broadcast::channel(capacity);
         let (head_tx, _) = broadcast::channel(capacity);
         let (exit_tx, _) = broadcast::channel(capacity);
+        let (chain_split_tx, _) = broadcast::channel(capacity);
+        let (fork_choice_equivocation_tx, _) = broadcast::channel(capacity);
 
         Self {
             attestation_tx,
@@ -46,6 +56,8 @@ impl<T: EthSpec> ServerSentEventHandler<T> {
             finalized_tx,
             head_tx,
             exit_tx,
+            chain_split_tx,
+            fork_choice_equivocation_tx,
             log,
         }
     }
@@ -65,6 +77,10 @@ impl<T: EthSpec> ServerSentEventHandler<T> {
                 .map(|count| trace!(self.log, "Registering server-sent head event"; "receiver_count" => count)),
             EventKind::VoluntaryExit(exit) => self.exit_tx.send(EventKind::VoluntaryExit(exit))
                 .map(|count| trace!(self.log, "Registering server-sent voluntary exit event"; "receiver_count" => count)),
+            EventKind::ChainSplitDetected(split) => self.chain_split_tx.send(EventKind::ChainSplitDetected(split))
+                .map(|count| trace!(self.log, "Registering server-sent chain split event"; "receiver_count" => count)),
+            EventKind::ForkChoiceEquivocation(equivocation) => self.fork_choice_equivocation_tx.send(EventKind::ForkChoiceEquivocation(equivocation))
+                .map(|count| trace!(self.log, "Registering server-sent fork choice equivocation event"; "receiver_count" => count)),
         };
         if let Err(SendError(event)) = result {
             trace!(self.log, "No receivers registered to listen for event"; "event" => ?event);
@@ -91,6 +107,14 @@ impl<T: EthSpec> ServerSentEventHandler<T> {
         self.exit_tx.subscribe()
     }
 
+    pub fn subscribe_chain_split(&self) -> Receiver<EventKind<T>> {
+        self.chain_split_tx.subscribe()
+    }
+
+    pub fn subscribe_fork_choice_equivocation(&self) -> Receiver<EventKind<T>> {
+        self.fork_choice_equivocation_tx.subscribe()
+    }
+
     pub fn has_attestation_subscribers(&self) -> bool {
         self.attestation_tx.receiver_count() > 0
     }
@@ -110,4 +134,12 @@ impl<T: EthSpec> ServerSentEventHandler<T> {
     pub fn has_exit_subscribers(&self) -> bool {
         self.exit_tx.receiver_count() > 0
     }
+
+    pub fn has_chain_split_subscribers(&self) -> bool {
+        self.chain_split_tx.receiver_count() > 0
+    }
+
+    pub fn has_fork_choice_equivocation_subscribers(&self) -> bool {
+        self.fork_choice_equivocation_tx.receiver_count() > 0
+    }
 }
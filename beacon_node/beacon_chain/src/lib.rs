@@ -7,6 +7,7 @@ mod beacon_snapshot;
 mod block_verification;
 pub mod builder;
 pub mod chain_config;
+mod checkpoint_state_cache;
 mod errors;
 pub mod eth1_chain;
 pub mod events;
@@ -31,7 +32,9 @@ mod validator_pubkey_cache;
 
 pub use self::beacon_chain::{
     AttestationProcessingOutcome, BeaconChain, BeaconChainTypes, BeaconStore, ChainSegmentResult,
-    ForkChoiceError, StateSkipConfig, WhenSlotSkipped, MAXIMUM_GOSSIP_CLOCK_DISPARITY,
+    ForkChoiceError, InconsistencyReport, SimulatedImportError, SimulatedImportResult,
+    SlashingCanonicity, StateSkipConfig, WhenSlotSkipped, MAXIMUM_GOSSIP_CLOCK_DISPARITY,
+    MIN_AGGREGATE_ATTESTATION_EFFICIENCY, MIN_BLS_BATCH_VERIFY_SIZE,
 };
 pub use self::beacon_snapshot::BeaconSnapshot;
 pub use self::chain_config::ChainConfig;
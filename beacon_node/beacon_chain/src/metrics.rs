@@ -101,6 +101,14 @@ lazy_static! {
         "Number of attestations in a block"
     );
 
+    /*
+     * Deposit Verification
+     */
+    pub static ref DEPOSIT_PROOFS_BATCH_VERIFICATION_TIMES: Result<Histogram> = try_create_histogram(
+        "beacon_deposit_proofs_batch_verification_seconds",
+        "Time taken to verify a batch of deposit merkle proofs"
+    );
+
     /*
      * Unaggregated Attestation Verification
      */
@@ -197,6 +205,14 @@ lazy_static! {
         "Time spent on the signature verification of attestation processing"
     );
 
+    /*
+     * BLS Signature Verification
+     */
+    pub static ref BLS_SIGNATURE_BATCH_VERIFY_COUNT: Result<Histogram> = try_create_histogram(
+        "bls_signature_batch_verify_count",
+        "Count of signature sets passed to each call of BeaconChain::verify_bls_signature_batch"
+    );
+
     /*
      * Shuffling cache
      */
@@ -235,6 +251,10 @@ lazy_static! {
         "beacon_fork_choice_errors_total",
         "Count of occasions where fork choice has returned an error when trying to find a head"
     );
+    pub static ref FORK_CHOICE_EQUIVOCATION_DETECTED_TOTAL: Result<IntCounter> = try_create_int_counter(
+        "fork_choice_equivocation_detected_total",
+        "Count of distinct occasions where fork choice contained two justified checkpoints for one epoch"
+    );
     pub static ref FORK_CHOICE_CHANGED_HEAD: Result<IntCounter> = try_create_int_counter(
         "beacon_fork_choice_changed_head_total",
         "Count of occasions fork choice has found a new head"
@@ -325,6 +345,11 @@ lazy_static! {
         try_create_int_gauge("beacon_op_pool_proposer_slashings_total", "Count of proposer slashings in the op pool");
     pub static ref OP_POOL_NUM_VOLUNTARY_EXITS: Result<IntGauge> =
         try_create_int_gauge("beacon_op_pool_voluntary_exits_total", "Count of voluntary exits in the op pool");
+    pub static ref ATTESTER_SLASHING_CANONICITY_TOTAL: Result<IntCounterVec> = try_create_int_counter_vec(
+        "beacon_attester_slashing_canonicity_total",
+        "Count of new attester slashings by whether their attestations vote for canonical blocks",
+        &["canonicity"]
+    );
 
     /*
      * Participation Metrics
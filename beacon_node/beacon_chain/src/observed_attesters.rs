@@ -227,6 +227,20 @@ impl<T: Item, E: EthSpec> AutoPruningContainer<T, E> {
         Ok(exists)
     }
 
+    /// Returns `true` if `validator_index` has been observed producing an attestation with a target
+    /// of `epoch`.
+    ///
+    /// Returns `false` for epochs which have been pruned, rather than an error.
+    pub fn validator_has_been_observed_at_epoch(
+        &self,
+        epoch: Epoch,
+        validator_index: usize,
+    ) -> bool {
+        self.items
+            .get(&epoch)
+            .map_or(false, |item| item.contains(validator_index))
+    }
+
     /// Returns the number of validators that have been observed at the given `epoch`. Returns
     /// `None` if `self` does not have a cache for that epoch.
     pub fn observed_validator_count(&self, epoch: Epoch) -> Option<usize> {
@@ -324,6 +338,10 @@ mod tests {
                             Ok(true),
                             "should indicate a known attestation is known"
                         );
+                        assert!(
+                            store.validator_has_been_observed_at_epoch(epoch, i),
+                            "should indicate a known attester is known for the epoch"
+                        );
                         assert_eq!(
                             store.observe_validator(a, i),
                             Ok(true),
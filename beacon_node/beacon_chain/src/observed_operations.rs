@@ -76,34 +76,42 @@ impl<E: EthSpec> ObservableOperation<E> for AttesterSlashing<E> {
 }
 
 impl<T: ObservableOperation<E>, E: EthSpec> ObservedOperations<T, E> {
+    /// Returns `true` if every validator involved in `op` has already been observed in a prior
+    /// operation, in which case `op` would be `AlreadyKnown`.
+    ///
+    /// This check is cheap and does not require a state, so it can be used to skip loading a state
+    /// for duplicate operations.
+    pub fn is_known(&self, op: &T) -> bool {
+        op.observed_validators()
+            .iter()
+            .all(|index| self.observed_validator_indices.contains(index))
+    }
+
     pub fn verify_and_observe(
         &mut self,
         op: T,
         head_state: &BeaconState<E>,
         spec: &ChainSpec,
     ) -> Result<ObservationOutcome<T>, T::Error> {
-        let observed_validator_indices = &mut self.observed_validator_indices;
-        let new_validator_indices = op.observed_validators();
-
         // If all of the new validator indices have been previously observed, short-circuit
         // the validation. This implements the uniqueness check part of the spec, which for attester
         // slashings reads:
         //
         // At least one index in the intersection of the attesting indices of each attestation has
         // not yet been seen in any prior attester_slashing.
-        if new_validator_indices
-            .iter()
-            .all(|index| observed_validator_indices.contains(index))
-        {
+        if self.is_known(&op) {
             return Ok(ObservationOutcome::AlreadyKnown);
         }
 
+        let new_validator_indices = op.observed_validators();
+
         // Validate the op using operation-specific logic (`verify_attester_slashing`, etc).
         let verified_op = op.validate(head_state, spec)?;
 
         // Add the relevant indices to the set of known indices to prevent processing of duplicates
         // in the future.
-        observed_validator_indices.extend(new_validator_indices);
+        self.observed_validator_indices
+            .extend(new_validator_indices);
 
         Ok(ObservationOutcome::New(verified_op))
     }
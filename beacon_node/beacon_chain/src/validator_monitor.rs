@@ -3,6 +3,7 @@
 //! This component should not affect consensus.
 
 use crate::metrics;
+use eth2::lighthouse::ValidatorPerformanceSummary;
 use parking_lot::RwLock;
 use slog::{crit, error, info, warn, Logger};
 use slot_clock::SlotClock;
@@ -86,6 +87,11 @@ impl EpochSummary {
         }
     }
 
+    pub fn register_block(&mut self, delay: Duration) {
+        self.blocks += 1;
+        Self::update_if_lt(&mut self.block_min_delay, delay);
+    }
+
     pub fn register_unaggregated_attestation(&mut self, delay: Duration) {
         self.attestations += 1;
         Self::update_if_lt(&mut self.attestation_min_delay, delay);
@@ -423,13 +429,6 @@ impl<T: EthSpec> ValidatorMonitor<T> {
         }
     }
 
-    fn get_validator_id(&self, validator_index: u64) -> Option<&str> {
-        self.indices
-            .get(&validator_index)
-            .and_then(|pubkey| self.validators.get(pubkey))
-            .map(|validator| validator.id.as_str())
-    }
-
     fn get_validator(&self, validator_index: u64) -> Option<&MonitoredValidator> {
         self.indices
             .get(&validator_index)
@@ -441,6 +440,67 @@ impl<T: EthSpec> ValidatorMonitor<T> {
         self.validators.len()
     }
 
+    /// Summarises the performance of `validator_index` across the epochs from `start_epoch` to
+    /// `end_epoch` (inclusive).
+    ///
+    /// Only the last `HISTORIC_EPOCHS` epochs up to `current_epoch` are retained, so the range is
+    /// clamped to those epochs. An epoch in the range without a summary is counted as an epoch in
+    /// which no attestation was included. Returns `None` if the validator is not monitored.
+    pub fn get_performance_summary(
+        &self,
+        validator_index: u64,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+        current_epoch: Epoch,
+    ) -> Option<ValidatorPerformanceSummary> {
+        let validator = self.get_validator(validator_index)?;
+        let summaries = validator.summaries.read();
+
+        let start_epoch = std::cmp::max(
+            start_epoch,
+            (current_epoch + 1).saturating_sub(HISTORIC_EPOCHS as u64),
+        );
+        let end_epoch = std::cmp::min(end_epoch, current_epoch);
+        let epochs = if end_epoch >= start_epoch {
+            (end_epoch - start_epoch).as_u64() + 1
+        } else {
+            0
+        };
+
+        let mut blocks_observed = 0;
+        let mut epochs_included = 0;
+        let mut total_inclusion_distance = 0;
+
+        for (_, summary) in summaries
+            .iter()
+            .filter(|(epoch, _)| **epoch >= start_epoch && **epoch <= end_epoch)
+        {
+            blocks_observed += summary.blocks;
+            if let Some(distance) = summary.attestation_min_block_inclusion_distance {
+                epochs_included += 1;
+                total_inclusion_distance += distance.as_u64();
+            }
+        }
+
+        let attestation_inclusion_rate = if epochs == 0 {
+            0.0
+        } else {
+            epochs_included as f64 / epochs as f64
+        };
+        let average_inclusion_distance = if epochs_included == 0 {
+            None
+        } else {
+            Some(total_inclusion_distance as f64 / epochs_included as f64)
+        };
+
+        Some(ValidatorPerformanceSummary {
+            epochs,
+            blocks_observed,
+            attestation_inclusion_rate,
+            average_inclusion_distance,
+        })
+    }
+
     /// If `self.auto_register == true`, add the `validator_index` to `self.monitored_validators`.
     /// Otherwise, do nothing.
     pub fn auto_register_local_validator(&mut self, validator_index: u64) {
@@ -495,7 +555,8 @@ impl<T: EthSpec> ValidatorMonitor<T> {
         block_root: Hash256,
         slot_clock: &S,
     ) {
-        if let Some(id) = self.get_validator_id(block.proposer_index) {
+        if let Some(validator) = self.get_validator(block.proposer_index) {
+            let id = &validator.id;
             let delay = get_block_delay_ms(seen_timestamp, block, slot_clock);
 
             metrics::inc_counter_vec(&metrics::VALIDATOR_MONITOR_BEACON_BLOCK_TOTAL, &[src, id]);
@@ -514,6 +575,10 @@ impl<T: EthSpec> ValidatorMonitor<T> {
                 "src" => src,
                 "validator" => %id,
             );
+
+            validator.with_epoch_summary(block.slot.epoch(T::slots_per_epoch()), |summary| {
+                summary.register_block(delay)
+            });
         }
     }
 
@@ -6,7 +6,7 @@ extern crate lazy_static;
 use beacon_chain::{
     attestation_verification::Error as AttnError,
     test_utils::{AttestationStrategy, BeaconChainHarness, BlockStrategy, EphemeralHarnessType},
-    BeaconChain, BeaconChainTypes, WhenSlotSkipped,
+    BeaconChain, BeaconChainTypes, WhenSlotSkipped, MIN_AGGREGATE_ATTESTATION_EFFICIENCY,
 };
 use int_to_bytes::int_to_bytes32;
 use state_processing::{
@@ -183,6 +183,72 @@ fn get_non_aggregator<T: BeaconChainTypes>(
         .expect("should find non-aggregator for committee")
 }
 
+#[test]
+#[allow(clippy::float_cmp)]
+fn aggregate_attestation_efficiency() {
+    let harness = get_harness(VALIDATOR_COUNT);
+
+    harness.extend_chain(
+        MainnetEthSpec::slots_per_epoch() as usize,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+    harness.advance_slot();
+
+    let chain = &harness.chain;
+    let (valid_attestation, _, _, _, _) = get_valid_unaggregated_attestation(chain);
+    let (aggregate, _, _) = get_valid_aggregated_attestation(chain, valid_attestation);
+    let committee_len = aggregate.message.aggregate.aggregation_bits.len();
+
+    // An aggregate built from a single attestation covers one member of the committee.
+    assert_eq!(
+        chain.compute_aggregate_attestation_efficiency(&aggregate),
+        1.0 / committee_len as f64
+    );
+
+    let mut full_aggregate = aggregate.clone();
+    for i in 0..committee_len {
+        full_aggregate
+            .message
+            .aggregate
+            .aggregation_bits
+            .set(i, true)
+            .unwrap();
+    }
+    assert_eq!(
+        chain.compute_aggregate_attestation_efficiency(&full_aggregate),
+        1.0
+    );
+
+    // A single member of a large committee falls below the efficiency threshold.
+    let large_committee_len = 128;
+    let mut sparse_aggregate = aggregate;
+    sparse_aggregate.message.aggregate.aggregation_bits =
+        BitList::with_capacity(large_committee_len).unwrap();
+    sparse_aggregate
+        .message
+        .aggregate
+        .aggregation_bits
+        .set(0, true)
+        .unwrap();
+    let efficiency = chain.compute_aggregate_attestation_efficiency(&sparse_aggregate);
+    assert_eq!(efficiency, 1.0 / large_committee_len as f64);
+    assert!(efficiency < MIN_AGGREGATE_ATTESTATION_EFFICIENCY);
+
+    // One member of a 64 validator committee is tolerated.
+    sparse_aggregate.message.aggregate.aggregation_bits = BitList::with_capacity(64).unwrap();
+    sparse_aggregate
+        .message
+        .aggregate
+        .aggregation_bits
+        .set(0, true)
+        .unwrap();
+    assert!(
+        chain.compute_aggregate_attestation_efficiency(&sparse_aggregate)
+            >= MIN_AGGREGATE_ATTESTATION_EFFICIENCY
+    );
+}
+
 /// Tests verification of `SignedAggregateAndProof` from the gossip network.
 #[test]
 fn aggregated_gossip_verification() {
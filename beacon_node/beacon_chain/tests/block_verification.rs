@@ -5,7 +5,7 @@ extern crate lazy_static;
 
 use beacon_chain::{
     test_utils::{AttestationStrategy, BeaconChainHarness, BlockStrategy, EphemeralHarnessType},
-    BeaconSnapshot, BlockError,
+    BeaconSnapshot, BlockError, SimulatedImportError,
 };
 use slasher::{Config as SlasherConfig, Slasher};
 use std::sync::Arc;
@@ -829,3 +829,78 @@ fn verify_block_for_gossip_slashing_detection() {
     drop(slasher);
     slasher_dir.close().unwrap();
 }
+
+#[test]
+fn simulate_block_import_matches_real_import() {
+    let harness = get_harness(VALIDATOR_COUNT);
+    harness.extend_chain(
+        3,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+
+    let slot = harness.get_current_slot() + 1;
+    let (block, _) = harness.make_block(harness.get_current_state(), slot);
+
+    let mut invalid_block = block.clone();
+    invalid_block.signature = junk_signature();
+    let invalid_result = harness
+        .chain
+        .simulate_block_import(&invalid_block)
+        .expect("should simulate invalid block");
+    assert!(invalid_result.validation_error.is_some());
+    assert_eq!(invalid_result.state_root, None);
+
+    let result = harness
+        .chain
+        .simulate_block_import(&block)
+        .expect("should simulate valid block");
+    assert!(result.validation_error.is_none());
+
+    // Simulation must not import the block.
+    let block_root = block.canonical_root();
+    assert!(harness.chain.get_block(&block_root).unwrap().is_none());
+
+    // A correctly signed block with the wrong state root is invalid.
+    let mut wrong_state_root = block.message.clone();
+    wrong_state_root.state_root = Hash256::repeat_byte(42);
+    let wrong_state_root = wrong_state_root.sign(
+        &generate_deterministic_keypair(block.message.proposer_index as usize).sk,
+        &harness.chain.head_info().unwrap().fork,
+        harness.chain.genesis_validators_root,
+        &harness.chain.spec,
+    );
+    let mismatch_result = harness
+        .chain
+        .simulate_block_import(&wrong_state_root)
+        .expect("should simulate block with wrong state root");
+    assert!(matches!(
+        mismatch_result.validation_error,
+        Some(SimulatedImportError::StateRootMismatch { block, local })
+            if block == Hash256::repeat_byte(42) && Some(local) == result.state_root
+    ));
+
+    harness
+        .process_block(slot, block.clone())
+        .expect("should import block");
+    assert_eq!(
+        result.state_root,
+        Some(harness.chain.head_info().unwrap().state_root)
+    );
+
+    // A block which is earlier than its parent is invalid, not an error.
+    let mut earlier_block = block.message.clone();
+    earlier_block.parent_root = block.canonical_root();
+    earlier_block.slot = slot - 1;
+    let earlier_result = harness
+        .chain
+        .simulate_block_import(&SignedBeaconBlock {
+            message: earlier_block,
+            signature: junk_signature(),
+        })
+        .expect("should simulate block earlier than its parent");
+    assert!(matches!(
+        earlier_result.validation_error,
+        Some(SimulatedImportError::StateAdvance(_))
+    ));
+}
@@ -271,3 +271,211 @@ fn attester_slashing() {
         ObservationOutcome::AlreadyKnown
     ));
 }
+
+#[test]
+fn attester_slashing_from_op_pool_double_vote() {
+    let db_path = tempdir().unwrap();
+    let store = get_store(&db_path);
+    let harness = get_harness(store.clone(), VALIDATOR_COUNT);
+    let spec = &harness.chain.spec;
+
+    harness.extend_chain(
+        (E::slots_per_epoch() * 2) as usize,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+
+    let head = harness.chain.head().unwrap();
+    let state = &head.beacon_state;
+    let slot = head.beacon_block.slot();
+    let validator_index = state.get_beacon_committees_at_slot(slot).unwrap()[0].committee[0];
+
+    // Attest to both the head block and its parent at the same slot, which is a double vote.
+    let make_attestation = |block_root: Hash256| {
+        harness
+            .make_unaggregated_attestations(
+                &[validator_index],
+                state,
+                head.beacon_state_root(),
+                block_root.into(),
+                slot,
+            )
+            .into_iter()
+            .flatten()
+            .map(|(attestation, _)| attestation)
+            .next()
+            .unwrap()
+    };
+    let attestation_1 = make_attestation(head.beacon_block_root);
+    let attestation_2 = make_attestation(head.beacon_block.parent_root());
+    assert_eq!(
+        attestation_1.data.target.epoch,
+        attestation_2.data.target.epoch
+    );
+
+    harness
+        .chain
+        .op_pool
+        .insert_attestation(
+            attestation_2,
+            &state.fork,
+            state.genesis_validators_root,
+            spec,
+        )
+        .unwrap();
+
+    // The op pool alone does not contain a double vote by an attester of the head block.
+    assert_eq!(
+        harness
+            .chain
+            .monitor_attester_slashing_opportunity(&head.beacon_block),
+        vec![]
+    );
+    assert_eq!(harness.chain.op_pool.num_attester_slashings(), 0);
+
+    // A block including the other vote conflicts with the op pool.
+    let mut block = head.beacon_block.clone();
+    block.message.body.attestations.push(attestation_1).unwrap();
+
+    let slashings = harness.chain.monitor_attester_slashing_opportunity(&block);
+    assert_eq!(slashings.len(), 1);
+    let slashing = &slashings[0];
+    assert_ne!(slashing.attestation_1.data, slashing.attestation_2.data);
+    assert_eq!(
+        slashing.attestation_1.attesting_indices.to_vec(),
+        vec![validator_index as u64]
+    );
+    assert_eq!(harness.chain.op_pool.num_attester_slashings(), 1);
+
+    // The same double vote should not be reported twice.
+    assert_eq!(
+        harness.chain.monitor_attester_slashing_opportunity(&block),
+        vec![]
+    );
+    assert_eq!(harness.chain.op_pool.num_attester_slashings(), 1);
+}
+
+#[test]
+fn on_chain_exits_for_epoch() {
+    let db_path = tempdir().unwrap();
+    let store = get_store(&db_path);
+    let harness = get_harness(store.clone(), VALIDATOR_COUNT);
+    let spec = &harness.chain.spec.clone();
+
+    harness.extend_chain(
+        (E::slots_per_epoch() * (spec.shard_committee_period + 1)) as usize,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+
+    let head_info = harness.chain.head_info().unwrap();
+    let validator_index = VALIDATOR_COUNT - 1;
+    let exit_epoch = Epoch::new(spec.shard_committee_period);
+    let exit = TestingVoluntaryExitBuilder::new(exit_epoch, validator_index as u64).build(
+        &KEYPAIRS[validator_index].sk,
+        &head_info.fork,
+        head_info.genesis_validators_root,
+        spec,
+    );
+
+    let verified_exit = match harness
+        .chain
+        .verify_voluntary_exit_for_gossip(exit)
+        .unwrap()
+    {
+        ObservationOutcome::New(verified_exit) => verified_exit,
+        ObservationOutcome::AlreadyKnown => panic!("exit should be new"),
+    };
+    harness.chain.import_voluntary_exit(verified_exit);
+
+    // The next block should include the exit from the op pool.
+    harness.extend_chain(
+        1,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+
+    let block_epoch = harness
+        .chain
+        .head_info()
+        .unwrap()
+        .slot
+        .epoch(E::slots_per_epoch());
+    assert_eq!(
+        harness
+            .chain
+            .get_on_chain_exits_for_epoch(block_epoch)
+            .unwrap(),
+        vec![(validator_index as u64, exit_epoch)]
+    );
+    assert_eq!(
+        harness
+            .chain
+            .get_on_chain_exits_for_epoch(block_epoch - 1)
+            .unwrap(),
+        vec![]
+    );
+}
+
+#[test]
+fn proposer_slashings_for_epoch() {
+    let db_path = tempdir().unwrap();
+    let store = get_store(&db_path);
+    let harness = get_harness(store.clone(), VALIDATOR_COUNT);
+    let spec = &harness.chain.spec.clone();
+
+    harness.extend_chain(
+        E::slots_per_epoch() as usize,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+
+    let head_info = harness.chain.head_info().unwrap();
+    let validator_index = VALIDATOR_COUNT - 1;
+    let slashing = TestingProposerSlashingBuilder::double_vote::<E>(
+        ProposerSlashingTestTask::Valid,
+        validator_index as u64,
+        &KEYPAIRS[validator_index].sk,
+        &head_info.fork,
+        head_info.genesis_validators_root,
+        spec,
+    );
+
+    let verified_slashing = match harness
+        .chain
+        .verify_proposer_slashing_for_gossip(slashing.clone())
+        .unwrap()
+    {
+        ObservationOutcome::New(verified_slashing) => verified_slashing,
+        ObservationOutcome::AlreadyKnown => panic!("slashing should be new"),
+    };
+    harness.chain.import_proposer_slashing(verified_slashing);
+
+    // The next block should include the slashing from the op pool.
+    harness.extend_chain(
+        1,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+
+    let block_epoch = harness
+        .chain
+        .head_info()
+        .unwrap()
+        .slot
+        .epoch(E::slots_per_epoch());
+    assert_eq!(
+        harness
+            .chain
+            .get_proposer_slashings_for_epoch(block_epoch)
+            .unwrap(),
+        vec![slashing]
+    );
+    assert_eq!(
+        harness
+            .chain
+            .get_proposer_slashings_for_epoch(block_epoch - 1)
+            .unwrap(),
+        vec![]
+    );
+}
@@ -4,7 +4,7 @@ use beacon_chain::attestation_verification::Error as AttnError;
 use beacon_chain::test_utils::{
     test_logger, AttestationStrategy, BeaconChainHarness, BlockStrategy, DiskHarnessType,
 };
-use beacon_chain::BeaconSnapshot;
+use beacon_chain::{BeaconSnapshot, StateSkipConfig};
 use lazy_static::lazy_static;
 use maplit::hashset;
 use rand::Rng;
@@ -853,6 +853,13 @@ fn prunes_abandoned_fork_between_two_finalized_checkpoints() {
     }
 
     assert!(!rig.chain.knows_head(&stray_head));
+
+    // Fork choice may still hold the pruned fork, but it must not be reported as inconsistent.
+    assert_eq!(
+        rig.chain.verify_block_tree_consistency().unwrap(),
+        vec![],
+        "pruned fork should not be reported as inconsistent"
+    );
 }
 
 #[test]
@@ -1720,6 +1727,56 @@ fn garbage_collect_temp_states_from_failed_block() {
     assert_eq!(store.iter_temporary_state_roots().count(), 0);
 }
 
+#[test]
+fn total_active_balance_at_hot_and_cold_epochs() {
+    let num_blocks_produced = E::slots_per_epoch() * 6;
+    let db_path = tempdir().unwrap();
+    let store = get_store(&db_path);
+    let harness = get_harness(store.clone(), LOW_VALIDATOR_COUNT);
+
+    harness.extend_chain(
+        num_blocks_produced as usize,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+
+    let cold_epoch = Epoch::new(1);
+    let hot_epoch = harness
+        .chain
+        .head_info()
+        .unwrap()
+        .slot
+        .epoch(E::slots_per_epoch());
+    assert!(
+        cold_epoch.start_slot(E::slots_per_epoch()) < store.get_split_slot(),
+        "precondition: epoch should be in the cold database"
+    );
+
+    for &epoch in &[cold_epoch, hot_epoch] {
+        let state = harness
+            .chain
+            .state_at_slot(
+                epoch.start_slot(E::slots_per_epoch()),
+                StateSkipConfig::WithStateRoots,
+            )
+            .unwrap();
+        let expected: u64 = state
+            .validators
+            .iter()
+            .filter(|validator| validator.is_active_at(epoch))
+            .map(|validator| validator.effective_balance)
+            .sum();
+
+        assert_eq!(
+            harness
+                .chain
+                .get_total_active_balance_at_epoch(epoch)
+                .unwrap(),
+            expected
+        );
+    }
+}
+
 /// Check that the head state's slot matches `expected_slot`.
 fn check_slot(harness: &TestHarness, expected_slot: u64) {
     let state = &harness.chain.head().expect("should get head").beacon_state;
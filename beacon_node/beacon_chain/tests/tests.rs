@@ -5,18 +5,27 @@ extern crate lazy_static;
 
 use beacon_chain::{
     attestation_verification::Error as AttnError,
+    events::{EventKind, SseChainSplit, SseForkChoiceEquivocation},
     test_utils::{
         AttestationStrategy, BeaconChainHarness, BlockStrategy, EphemeralHarnessType,
         OP_POOL_DB_KEY,
     },
-    WhenSlotSkipped,
+    BeaconChainError, InconsistencyReport, SlashingCanonicity, StateSkipConfig, WhenSlotSkipped,
+    MIN_BLS_BATCH_VERIFY_SIZE,
 };
+use bls::SignatureSet;
+use eth2::lighthouse::MissedAttestationDiagnosis;
 use operation_pool::PersistedOperationPool;
 use state_processing::{
     per_slot_processing, per_slot_processing::Error as SlotProcessingError, EpochProcessingError,
 };
-use store::config::StoreConfig;
-use types::{BeaconStateError, EthSpec, Hash256, Keypair, MinimalEthSpec, RelativeEpoch, Slot};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use store::{config::StoreConfig, DBColumn, KeyValueStore};
+use types::{
+    Attestation, BeaconStateError, Epoch, EthSpec, Hash256, Keypair, MinimalEthSpec, RelativeEpoch,
+    Signature, Slot,
+};
 
 // Should ideally be divisible by 3.
 pub const VALIDATOR_COUNT: usize = 24;
@@ -188,6 +197,73 @@ fn chooses_fork() {
     );
 }
 
+#[test]
+fn attester_slashing_canonicity() {
+    let harness = get_harness(VALIDATOR_COUNT);
+
+    let two_thirds = (VALIDATOR_COUNT / 3) * 2;
+    let delay = MinimalEthSpec::default_spec().min_attestation_inclusion_delay as usize;
+
+    let honest_validators: Vec<usize> = (0..two_thirds).collect();
+    let faulty_validators: Vec<usize> = (two_thirds..VALIDATOR_COUNT).collect();
+
+    harness.extend_chain(
+        delay + 1,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+
+    let (honest_head, faulty_head) = harness.generate_two_forks_by_skipping_a_block(
+        &honest_validators,
+        &faulty_validators,
+        delay + 1,
+        delay + 2,
+    );
+    assert_eq!(harness.chain.head_info().unwrap().block_root, honest_head);
+
+    // Returns the block with `root` and its parent, both of which are on the same fork.
+    let block_and_parent = |root: Hash256| {
+        let block = harness.chain.get_block(&root).unwrap().unwrap();
+        let parent = harness
+            .chain
+            .get_block(&block.parent_root())
+            .unwrap()
+            .unwrap();
+        ((root, block.slot()), (block.parent_root(), parent.slot()))
+    };
+    let (honest_1, honest_2) = block_and_parent(honest_head);
+    let (faulty_1, faulty_2) = block_and_parent(faulty_head);
+
+    let slashing_for = |(root_1, slot_1): (Hash256, Slot), (root_2, slot_2): (Hash256, Slot)| {
+        let mut slashing = harness.make_attester_slashing(vec![0]);
+        slashing.attestation_1.data.beacon_block_root = root_1;
+        slashing.attestation_1.data.slot = slot_1;
+        slashing.attestation_2.data.beacon_block_root = root_2;
+        slashing.attestation_2.data.slot = slot_2;
+        harness
+            .chain
+            .verify_attester_slashing_matches_chain(&slashing)
+            .unwrap()
+    };
+
+    assert_eq!(
+        slashing_for(honest_1, honest_2),
+        SlashingCanonicity::Canonical
+    );
+    assert_eq!(
+        slashing_for(honest_1, faulty_1),
+        SlashingCanonicity::OneOnCanonical
+    );
+    assert_eq!(
+        slashing_for(faulty_2, honest_2),
+        SlashingCanonicity::OneOnCanonical
+    );
+    assert_eq!(
+        slashing_for(faulty_1, faulty_2),
+        SlashingCanonicity::BothOnForks
+    );
+}
+
 #[test]
 fn finalizes_with_full_participation() {
     let num_blocks_produced = MinimalEthSpec::slots_per_epoch() * 5;
@@ -754,3 +830,941 @@ fn block_roots_skip_slot_behaviour() {
         "WhenSlotSkipped::Prev should return None on a future slot"
     );
 }
+
+#[test]
+fn count_justified_checkpoints_on_split_chain() {
+    let harness = get_harness(VALIDATOR_COUNT);
+    let slots_per_epoch = MinimalEthSpec::slots_per_epoch();
+    let all_validators = harness.get_all_validators();
+
+    // Build a common chain which ends part-way through epoch 2.
+    harness.extend_chain(
+        (slots_per_epoch * 2 + slots_per_epoch / 2) as usize,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+
+    let split_epoch = Epoch::new(3);
+    assert_eq!(
+        harness
+            .chain
+            .count_justified_checkpoints_in_epoch(split_epoch)
+            .unwrap(),
+        0,
+        "nothing should be justified in the split epoch yet"
+    );
+
+    let mut chain_splits = harness
+        .chain
+        .event_handler
+        .as_ref()
+        .expect("harness should have an event handler")
+        .subscribe_chain_split();
+
+    // Build two forks from the head, each with every validator attesting. Attestations are
+    // inserted directly into the op pool since gossip verification would reject validators voting
+    // on both forks.
+    let fork_start = harness.get_current_slot() + 1;
+    let mut forks = vec![harness.get_current_state(), harness.get_current_state()];
+    for slot in (fork_start.as_u64()..slots_per_epoch * 6).map(Slot::new) {
+        for state in forks.iter_mut() {
+            let (block, new_state) = harness.make_block(state.clone(), slot);
+            let block_root = harness.process_block(slot, block.clone()).unwrap();
+
+            let attestations = harness.make_unaggregated_attestations(
+                &all_validators,
+                &new_state,
+                block.message.state_root,
+                block_root,
+                slot,
+            );
+            for (attestation, _) in attestations.into_iter().flatten() {
+                harness
+                    .chain
+                    .op_pool
+                    .insert_attestation(
+                        attestation,
+                        &new_state.fork,
+                        new_state.genesis_validators_root,
+                        &harness.spec,
+                    )
+                    .unwrap();
+            }
+
+            *state = new_state;
+        }
+    }
+
+    assert!(
+        forks
+            .iter()
+            .all(|state| state.current_justified_checkpoint.epoch > split_epoch),
+        "precondition: both forks should justify beyond the split epoch"
+    );
+    assert_ne!(
+        forks[0].get_block_root_at_epoch(split_epoch).unwrap(),
+        forks[1].get_block_root_at_epoch(split_epoch).unwrap(),
+        "precondition: the forks should have different checkpoints in the split epoch"
+    );
+
+    // Checkpoints prior to the split are shared by both forks.
+    assert_eq!(
+        harness
+            .chain
+            .count_justified_checkpoints_in_epoch(split_epoch - 1)
+            .unwrap(),
+        1
+    );
+    assert!(
+        chain_splits.try_recv().is_err(),
+        "no chain split should be announced prior to the split"
+    );
+
+    assert_eq!(
+        harness
+            .chain
+            .count_justified_checkpoints_in_epoch(split_epoch)
+            .unwrap(),
+        2
+    );
+
+    let mut expected_roots = forks
+        .iter()
+        .map(|state| *state.get_block_root_at_epoch(split_epoch).unwrap())
+        .collect::<Vec<_>>();
+    expected_roots.sort();
+    assert_eq!(
+        chain_splits.try_recv().unwrap(),
+        EventKind::ChainSplitDetected(SseChainSplit {
+            epoch: split_epoch,
+            justified_roots: expected_roots,
+        })
+    );
+}
+
+#[test]
+fn fork_choice_equivocation() {
+    let harness = get_harness(VALIDATOR_COUNT);
+    let slots_per_epoch = MinimalEthSpec::slots_per_epoch();
+    let all_validators = harness.get_all_validators();
+
+    harness.extend_chain(
+        (slots_per_epoch * 2 + slots_per_epoch / 2) as usize,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+
+    assert_eq!(
+        harness.chain.monitor_fork_choice_equivocation(),
+        None,
+        "a single chain cannot equivocate"
+    );
+
+    let mut equivocations = harness
+        .chain
+        .event_handler
+        .as_ref()
+        .expect("harness should have an event handler")
+        .subscribe_fork_choice_equivocation();
+
+    // Build two forks from the head which justify different checkpoints in the same epoch, as in
+    // `count_justified_checkpoints_on_split_chain`.
+    let divergence_slot = harness.chain.head_info().unwrap().slot;
+    let mut forks = vec![harness.get_current_state(), harness.get_current_state()];
+    for slot in (divergence_slot.as_u64() + 1..slots_per_epoch * 6).map(Slot::new) {
+        for state in forks.iter_mut() {
+            let (block, new_state) = harness.make_block(state.clone(), slot);
+            let block_root = harness.process_block(slot, block.clone()).unwrap();
+
+            let attestations = harness.make_unaggregated_attestations(
+                &all_validators,
+                &new_state,
+                block.message.state_root,
+                block_root,
+                slot,
+            );
+            for (attestation, _) in attestations.into_iter().flatten() {
+                harness
+                    .chain
+                    .op_pool
+                    .insert_attestation(
+                        attestation,
+                        &new_state.fork,
+                        new_state.genesis_validators_root,
+                        &harness.spec,
+                    )
+                    .unwrap();
+            }
+
+            *state = new_state;
+        }
+    }
+
+    let split_epoch = Epoch::new(3);
+    let mut expected_roots = forks
+        .iter()
+        .map(|state| *state.get_block_root_at_epoch(split_epoch).unwrap())
+        .collect::<Vec<_>>();
+    expected_roots.sort();
+    assert_ne!(
+        expected_roots[0], expected_roots[1],
+        "precondition: the forks should have different checkpoints in the split epoch"
+    );
+
+    let expected = SseForkChoiceEquivocation {
+        epoch: split_epoch,
+        root_1: expected_roots[0],
+        root_2: expected_roots[1],
+        divergence_slot,
+    };
+    assert_eq!(
+        harness.chain.monitor_fork_choice_equivocation(),
+        Some(expected.clone())
+    );
+
+    // The equivocation is announced once, despite fork choice running after every block.
+    assert_eq!(
+        equivocations.try_recv().unwrap(),
+        EventKind::ForkChoiceEquivocation(expected)
+    );
+    assert!(
+        equivocations.try_recv().is_err(),
+        "the same equivocation should not be announced twice"
+    );
+}
+
+#[test]
+fn finality_checkpoints_history() {
+    let harness = get_harness(VALIDATOR_COUNT);
+
+    // Build the chain one slot at a time, recording each new finalized checkpoint.
+    let mut finalized_checkpoints = vec![];
+    for _ in 0..MinimalEthSpec::slots_per_epoch() * 6 {
+        harness.extend_chain(
+            1,
+            BlockStrategy::OnCanonicalHead,
+            AttestationStrategy::AllValidators,
+        );
+
+        let finalized = harness.chain.head_info().unwrap().finalized_checkpoint;
+        if finalized.epoch > 0 && finalized_checkpoints.last() != Some(&finalized) {
+            finalized_checkpoints.push(finalized);
+        }
+    }
+
+    assert!(
+        finalized_checkpoints.len() >= 3,
+        "the chain should have finalized several times"
+    );
+
+    let expected = finalized_checkpoints
+        .iter()
+        .map(|checkpoint| (checkpoint.epoch, checkpoint.root))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        harness
+            .chain
+            .get_finality_checkpoints_history(expected.len()),
+        expected,
+        "all finalized checkpoints should be in the history"
+    );
+    assert_eq!(
+        harness
+            .chain
+            .get_finality_checkpoints_history(usize::max_value()),
+        expected,
+        "there should be no other checkpoints in the history"
+    );
+    assert_eq!(
+        harness.chain.get_finality_checkpoints_history(1),
+        expected[expected.len() - 1..].to_vec(),
+        "a smaller count should return the most recent checkpoints"
+    );
+}
+
+#[test]
+fn block_roots_in_slot_range() {
+    let harness = get_harness(VALIDATOR_COUNT);
+
+    let slots_per_historical_root = harness.chain.head().unwrap().beacon_state.block_roots.len();
+    let chain_length = slots_per_historical_root as u64 + 16;
+
+    // A run of skipped slots which straddles the wrap-around of the `block_roots` array.
+    let wrap_slot = slots_per_historical_root as u64;
+    let skipped_slots = (wrap_slot - 3..wrap_slot + 3).collect::<Vec<_>>();
+
+    // A skipped slot at the oldest end of the head state's `block_roots`, whose previous root has
+    // already been overwritten.
+    let oldest_slot = chain_length - slots_per_historical_root as u64;
+
+    for i in 1..=chain_length {
+        if i > 1 {
+            harness.advance_slot();
+        }
+
+        let slot = harness.chain.slot().unwrap().as_u64();
+
+        if !skipped_slots.contains(&slot) && slot != oldest_slot {
+            harness.extend_chain(
+                1,
+                BlockStrategy::OnCanonicalHead,
+                AttestationStrategy::AllValidators,
+            );
+        }
+    }
+
+    let start = Slot::new(wrap_slot - 8);
+    let end = Slot::new(chain_length);
+
+    let expected = (start.as_u64()..=end.as_u64())
+        .map(Slot::new)
+        .filter_map(|slot| {
+            harness
+                .chain
+                .block_root_at_slot(slot, WhenSlotSkipped::None)
+                .unwrap()
+                .map(|root| (slot, root))
+        })
+        .collect::<Vec<_>>();
+
+    let roots = harness
+        .chain
+        .get_all_block_roots_in_slot_range(start, end)
+        .unwrap();
+
+    assert_eq!(roots, expected);
+    assert_eq!(
+        roots.len() as u64,
+        (end - start).as_u64() + 1 - skipped_slots.len() as u64,
+        "only the skipped slots should be omitted"
+    );
+    assert!(
+        roots
+            .iter()
+            .all(|(slot, _)| !skipped_slots.contains(&slot.as_u64())),
+        "skipped slots should not be returned"
+    );
+
+    assert_eq!(
+        harness
+            .chain
+            .get_all_block_roots_in_slot_range(end, end + 10)
+            .unwrap(),
+        vec![(end, harness.chain.head_info().unwrap().block_root)],
+        "slots after the head should be ignored"
+    );
+
+    let oldest_roots = harness
+        .chain
+        .get_all_block_roots_in_slot_range(Slot::new(oldest_slot), end)
+        .unwrap();
+    assert_eq!(
+        oldest_roots.first().map(|(slot, _)| *slot),
+        Some(Slot::new(oldest_slot + 1)),
+        "a skipped slot at the oldest end of the block roots array should be omitted"
+    );
+
+    assert!(
+        harness
+            .chain
+            .get_all_block_roots_in_slot_range(Slot::new(1), end)
+            .is_err(),
+        "slots which have left the block roots array should error"
+    );
+}
+
+#[test]
+fn checkpoint_state_cache() {
+    let harness = get_harness(VALIDATOR_COUNT);
+
+    harness.extend_chain(
+        MinimalEthSpec::slots_per_epoch() as usize * 5,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+
+    let chain = &harness.chain;
+    let current_epoch = chain.epoch().unwrap();
+    let expected_state_root = |epoch: Epoch| {
+        chain
+            .state_root_at_slot(epoch.start_slot(MinimalEthSpec::slots_per_epoch()))
+            .unwrap()
+            .unwrap()
+    };
+
+    assert!(chain.checkpoint_state_cache.lock().is_empty());
+
+    // The first read of the previous epoch's checkpoint state populates the cache, the second is
+    // served from it.
+    let previous_epoch = current_epoch - 1;
+    let uncached = chain.get_checkpoint_state(previous_epoch).unwrap();
+    assert_eq!(chain.checkpoint_state_cache.lock().len(), 1);
+    let cached = chain.get_checkpoint_state(previous_epoch).unwrap();
+    assert_eq!(chain.checkpoint_state_cache.lock().len(), 1);
+
+    assert_eq!(uncached, cached);
+    assert_eq!(
+        cached.slot,
+        previous_epoch.start_slot(MinimalEthSpec::slots_per_epoch())
+    );
+    assert_eq!(cached.canonical_root(), expected_state_root(previous_epoch));
+
+    // Older checkpoint states are loaded but not cached.
+    let old_epoch = current_epoch - 3;
+    let old_state = chain.get_checkpoint_state(old_epoch).unwrap();
+    assert_eq!(old_state.canonical_root(), expected_state_root(old_epoch));
+    assert_eq!(chain.checkpoint_state_cache.lock().len(), 1);
+
+    // Once the epoch advances, the stale state is pruned from the cache.
+    harness.extend_chain(
+        MinimalEthSpec::slots_per_epoch() as usize * 2,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+    let new_previous_epoch = chain.epoch().unwrap() - 1;
+    assert!(new_previous_epoch > current_epoch);
+
+    let state = chain.get_checkpoint_state(new_previous_epoch).unwrap();
+    assert_eq!(
+        state.canonical_root(),
+        expected_state_root(new_previous_epoch)
+    );
+    assert_eq!(
+        chain.checkpoint_state_cache.lock().len(),
+        1,
+        "the checkpoint state from the earlier epoch should be pruned"
+    );
+}
+
+#[test]
+fn get_checkpoint_state_root() {
+    let harness = get_harness(VALIDATOR_COUNT);
+    let slots_per_epoch = MinimalEthSpec::slots_per_epoch();
+
+    // Skip the first slot of epoch 2.
+    let skipped_slot = Epoch::new(2).start_slot(slots_per_epoch);
+    let slots = (1..=slots_per_epoch * 3)
+        .map(Slot::new)
+        .filter(|slot| *slot != skipped_slot)
+        .collect::<Vec<_>>();
+    let (state, state_root) = harness.get_current_state_and_root();
+    harness.add_attested_blocks_at_slots(state, state_root, &slots, &harness.get_all_validators());
+
+    let chain = &harness.chain;
+
+    for epoch in (0..=3).map(Epoch::new) {
+        let expected = chain
+            .state_at_slot(
+                epoch.start_slot(slots_per_epoch),
+                StateSkipConfig::WithStateRoots,
+            )
+            .unwrap()
+            .canonical_root();
+        assert_eq!(
+            chain.get_checkpoint_state_root(epoch).unwrap(),
+            expected,
+            "epoch {}",
+            epoch
+        );
+    }
+
+    // The checkpoint state of the skipped slot is not the post-state of the previous block.
+    let previous_block = chain
+        .block_at_slot(skipped_slot - 1, WhenSlotSkipped::None)
+        .unwrap()
+        .unwrap();
+    assert_ne!(
+        chain.get_checkpoint_state_root(Epoch::new(2)).unwrap(),
+        previous_block.state_root()
+    );
+
+    assert!(matches!(
+        chain.get_checkpoint_state_root(Epoch::new(100)),
+        Err(BeaconChainError::NoStateForSlot(_))
+    ));
+}
+
+#[test]
+fn verify_block_tree_consistency() {
+    let harness = get_harness(VALIDATOR_COUNT);
+
+    harness.extend_chain(
+        MinimalEthSpec::slots_per_epoch() as usize,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+
+    let chain = &harness.chain;
+    assert_eq!(chain.verify_block_tree_consistency().unwrap(), vec![]);
+
+    // Remove a block and the post-state of a different block from the database.
+    let block = chain
+        .block_at_slot(Slot::new(2), WhenSlotSkipped::None)
+        .unwrap()
+        .unwrap();
+    let block_root = block.canonical_root();
+    chain
+        .store
+        .hot_db
+        .key_delete(DBColumn::BeaconBlock.into(), block_root.as_bytes())
+        .unwrap();
+
+    let other_block = chain
+        .block_at_slot(Slot::new(4), WhenSlotSkipped::None)
+        .unwrap()
+        .unwrap();
+    let other_block_root = other_block.canonical_root();
+    let state_root = other_block.state_root();
+    chain
+        .store
+        .hot_db
+        .key_delete(DBColumn::BeaconStateSummary.into(), state_root.as_bytes())
+        .unwrap();
+
+    assert_eq!(
+        chain.verify_block_tree_consistency().unwrap(),
+        vec![
+            InconsistencyReport::MissingBlock {
+                block_root,
+                slot: Slot::new(2),
+            },
+            InconsistencyReport::MissingState {
+                block_root: other_block_root,
+                slot: Slot::new(4),
+                state_root,
+            },
+        ]
+    );
+}
+
+#[test]
+fn get_attestation_data_for_all_committees() {
+    let harness = get_harness(VALIDATOR_COUNT);
+
+    harness.extend_chain(
+        MinimalEthSpec::slots_per_epoch() as usize,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+
+    let chain = &harness.chain;
+    let head_slot = chain.head_info().unwrap().slot;
+
+    // Include a slot in the next epoch, which requires the head state to be advanced.
+    for slot in vec![
+        head_slot,
+        head_slot + 1,
+        head_slot + MinimalEthSpec::slots_per_epoch(),
+    ] {
+        let data = chain.get_attestation_data_for_all_committees(slot).unwrap();
+        assert!(!data.is_empty());
+
+        for (index, data) in data {
+            assert_eq!(
+                data,
+                chain
+                    .produce_unaggregated_attestation(slot, index)
+                    .unwrap()
+                    .data
+            );
+        }
+    }
+
+    assert!(matches!(
+        chain.get_attestation_data_for_all_committees(head_slot - 1),
+        Err(BeaconChainError::AttestingPriorToHead { .. })
+    ));
+}
+
+#[test]
+fn get_attesting_validators_for_block() {
+    let harness = get_harness(VALIDATOR_COUNT);
+
+    harness.extend_chain(
+        MinimalEthSpec::slots_per_epoch() as usize * 2,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+
+    let chain = &harness.chain;
+    let mut head = chain.head().unwrap();
+    head.beacon_state
+        .build_committee_cache(RelativeEpoch::Previous, &harness.spec)
+        .unwrap();
+
+    // Every validator attested at the previous slot, and those attestations are included in the
+    // head block.
+    let mut expected = head
+        .beacon_state
+        .get_beacon_committees_at_slot(head.beacon_block.slot() - 1)
+        .unwrap()
+        .into_iter()
+        .flat_map(|committee| committee.committee.to_vec())
+        .map(|i| i as u64)
+        .collect::<Vec<_>>();
+    expected.sort_unstable();
+    assert!(!expected.is_empty());
+
+    assert_eq!(
+        chain
+            .get_attesting_validators_for_block(head.beacon_block_root)
+            .unwrap(),
+        expected
+    );
+
+    assert!(matches!(
+        chain.get_attesting_validators_for_block(Hash256::repeat_byte(42)),
+        Err(BeaconChainError::MissingBeaconBlock(_))
+    ));
+}
+
+#[test]
+fn list_hot_db_columns() {
+    let harness = get_harness(VALIDATOR_COUNT);
+    let num_blocks = MinimalEthSpec::slots_per_epoch() as usize;
+
+    harness.extend_chain(
+        num_blocks,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+
+    let columns = harness.chain.list_hot_db_columns(true).unwrap();
+    assert_eq!(columns.len(), DBColumn::ALL.len());
+
+    let column = |name: &str| {
+        columns
+            .iter()
+            .find(|column| column.column_name == name)
+            .unwrap_or_else(|| panic!("should list {} column", name))
+    };
+
+    for name in &["BeaconBlock", "BeaconStateSummary"] {
+        assert!(column(name).key_count >= num_blocks as u64, "{}", name);
+        assert!(
+            column(name).approximate_size_bytes.unwrap() > column(name).key_count,
+            "{}",
+            name
+        );
+    }
+    assert_eq!(column("DhtEnrs").key_count, 0);
+    assert_eq!(column("DhtEnrs").approximate_size_bytes, Some(0));
+
+    // Without sizes, only the keys are counted.
+    let key_counts = harness.chain.list_hot_db_columns(false).unwrap();
+    for (without_sizes, with_sizes) in key_counts.iter().zip(columns.iter()) {
+        assert_eq!(without_sizes.column_name, with_sizes.column_name);
+        assert_eq!(without_sizes.key_count, with_sizes.key_count);
+        assert_eq!(without_sizes.approximate_size_bytes, None);
+    }
+}
+
+#[test]
+fn get_state_at_block_root() {
+    let harness = get_harness(VALIDATOR_COUNT);
+
+    harness.extend_chain(
+        MinimalEthSpec::slots_per_epoch() as usize * 2,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+
+    let chain = &harness.chain;
+    let head = chain.head_info().unwrap();
+
+    // Build a block at the head slot which skips the slot prior, so it is not canonical.
+    let fork_parent_state = chain
+        .state_at_slot(head.slot - 2, StateSkipConfig::WithStateRoots)
+        .unwrap();
+    let (fork_block, fork_state) = harness.make_block(fork_parent_state, head.slot);
+    let fork_block_root: Hash256 = harness
+        .process_block(head.slot, fork_block.clone())
+        .unwrap()
+        .into();
+    assert_ne!(
+        fork_block_root, head.block_root,
+        "precondition: forked block"
+    );
+    assert_eq!(
+        chain.head_info().unwrap().block_root,
+        head.block_root,
+        "precondition: fork should not be canonical"
+    );
+
+    assert!(chain.block_root_state_cache.lock().is_empty());
+
+    let state = chain.get_state_at_block_root(fork_block_root).unwrap();
+    assert_eq!(state.slot, head.slot);
+    assert_eq!(state.canonical_root(), fork_block.message.state_root);
+    assert_eq!(state.canonical_root(), fork_state.canonical_root());
+    assert_eq!(chain.block_root_state_cache.lock().len(), 1);
+
+    // The canonical head block has a different post-state.
+    let head_state = chain.get_state_at_block_root(head.block_root).unwrap();
+    assert_eq!(head_state.canonical_root(), head.state_root);
+    assert_eq!(chain.block_root_state_cache.lock().len(), 2);
+
+    // Repeated queries are served from the cache.
+    assert_eq!(
+        chain.get_state_at_block_root(fork_block_root).unwrap(),
+        state
+    );
+    assert_eq!(chain.block_root_state_cache.lock().len(), 2);
+
+    // Unknown blocks are an error.
+    assert!(matches!(
+        chain.get_state_at_block_root(Hash256::repeat_byte(42)),
+        Err(BeaconChainError::MissingBeaconBlock(_))
+    ));
+}
+
+#[test]
+fn diagnose_missed_attestation() {
+    let harness = get_harness(VALIDATOR_COUNT);
+    let slots_per_epoch = MinimalEthSpec::slots_per_epoch();
+
+    // Validator 0 never attests.
+    harness.extend_chain(
+        slots_per_epoch as usize * 3,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::SomeValidators((1..VALIDATOR_COUNT).collect()),
+    );
+
+    let chain = &harness.chain;
+    let head = chain.head_info().unwrap();
+    let epoch = Epoch::new(1);
+    let (duties, _) = chain
+        .validator_attestation_duties(&[0, 1], epoch, head.block_root)
+        .unwrap();
+    let absent_duty = duties[0].unwrap();
+    let present_duty = duties[1].unwrap();
+
+    assert_eq!(
+        chain
+            .diagnose_missed_attestation(0, absent_duty.slot)
+            .unwrap(),
+        MissedAttestationDiagnosis::NotSeen
+    );
+
+    match chain
+        .diagnose_missed_attestation(1, present_duty.slot)
+        .unwrap()
+    {
+        MissedAttestationDiagnosis::IncludedInBlock {
+            block_root,
+            inclusion_slot,
+        } => {
+            assert!(inclusion_slot > present_duty.slot);
+            assert!(inclusion_slot <= present_duty.slot + slots_per_epoch);
+            assert_eq!(
+                chain
+                    .block_root_at_slot(inclusion_slot, WhenSlotSkipped::None)
+                    .unwrap(),
+                Some(block_root)
+            );
+        }
+        other => panic!("expected attestation to be included, got {:?}", other),
+    }
+
+    let other_slot = epoch
+        .slot_iter(slots_per_epoch)
+        .find(|slot| *slot != present_duty.slot)
+        .unwrap();
+    assert_eq!(
+        chain.diagnose_missed_attestation(1, other_slot).unwrap(),
+        MissedAttestationDiagnosis::NotInCommittee
+    );
+}
+
+#[test]
+fn pending_attestation_count_by_committee() {
+    let harness = get_harness(VALIDATOR_COUNT);
+
+    harness.extend_chain(
+        2,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::SomeValidators(vec![]),
+    );
+
+    let chain = &harness.chain;
+    assert!(chain
+        .get_pending_attestation_count_by_committee()
+        .is_empty());
+
+    let head = chain.head().unwrap();
+    let state = &head.beacon_state;
+    let insert = |attestation: Attestation<MinimalEthSpec>| {
+        chain
+            .op_pool
+            .insert_attestation(
+                attestation,
+                &state.fork,
+                state.genesis_validators_root,
+                &chain.spec,
+            )
+            .unwrap()
+    };
+
+    let mut expected = HashMap::new();
+    for &slot in &[Slot::new(1), Slot::new(2)] {
+        let committee_attestations = harness.make_unaggregated_attestations(
+            &harness.get_all_validators(),
+            state,
+            state.canonical_root(),
+            head.beacon_block_root.into(),
+            slot,
+        );
+
+        for attestations in committee_attestations {
+            let first = attestations[0].0.clone();
+            insert(first.clone());
+            let mut count = 1;
+
+            // An aggregate which overlaps the first attestation is held separately.
+            if slot == 1 {
+                let mut aggregate = first.clone();
+                aggregate.aggregate(&attestations[1].0);
+                insert(aggregate);
+                count += 1;
+            }
+
+            expected.insert((slot, first.data.index), count);
+        }
+    }
+
+    assert_eq!(chain.get_pending_attestation_count_by_committee(), expected);
+}
+
+#[test]
+fn validator_performance_summary() {
+    let harness = get_harness(VALIDATOR_COUNT);
+    let chain = &harness.chain;
+
+    assert!(matches!(
+        chain.get_validator_performance_summary(0, Epoch::new(0), Epoch::new(0)),
+        Err(BeaconChainError::UnmonitoredValidator(0))
+    ));
+
+    chain
+        .validator_monitor
+        .write()
+        .auto_register_local_validator(0);
+
+    harness.extend_chain(
+        MinimalEthSpec::slots_per_epoch() as usize * 3,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+
+    let summary = chain
+        .get_validator_performance_summary(0, Epoch::new(1), Epoch::new(2))
+        .unwrap();
+    assert_eq!(summary.epochs, 2);
+    assert_eq!(summary.attestation_inclusion_rate, 1.0);
+    assert_eq!(summary.average_inclusion_distance, Some(0.0));
+
+    // Epochs after the current epoch are not counted.
+    let summary = chain
+        .get_validator_performance_summary(0, Epoch::new(100), Epoch::new(200))
+        .unwrap();
+    assert_eq!(summary.epochs, 0);
+    assert_eq!(summary.average_inclusion_distance, None);
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn validator_performance_summary_with_skipped_epoch() {
+    let harness = get_harness(VALIDATOR_COUNT);
+    let chain = &harness.chain;
+    let slots_per_epoch = MinimalEthSpec::slots_per_epoch() as usize;
+
+    chain
+        .validator_monitor
+        .write()
+        .auto_register_local_validator(0);
+
+    // Validator 0 attests in epochs 0 and 1, skips epoch 2 and attests in epochs 3 and 4.
+    harness.extend_chain(
+        slots_per_epoch * 2 - 1,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+    harness.advance_slot();
+    harness.extend_chain(
+        slots_per_epoch,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::SomeValidators((1..VALIDATOR_COUNT).collect()),
+    );
+    harness.advance_slot();
+    harness.extend_chain(
+        slots_per_epoch * 2,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+    assert_eq!(chain.epoch().unwrap(), Epoch::new(4));
+
+    // The skipped epoch counts towards the inclusion rate.
+    let summary = chain
+        .get_validator_performance_summary(0, Epoch::new(2), Epoch::new(3))
+        .unwrap();
+    assert_eq!(summary.epochs, 2);
+    assert_eq!(summary.attestation_inclusion_rate, 0.5);
+
+    // Epoch 0 is outside of the epochs retained by the validator monitor.
+    let summary = chain
+        .get_validator_performance_summary(0, Epoch::new(0), Epoch::new(3))
+        .unwrap();
+    assert_eq!(summary.epochs, 3);
+    assert_eq!(summary.attestation_inclusion_rate, 2.0 / 3.0);
+}
+
+#[test]
+fn verify_bls_signature_batch() {
+    let harness = get_harness(VALIDATOR_COUNT);
+    let chain = &harness.chain;
+
+    let message = Hash256::repeat_byte(42);
+    let other_message = Hash256::repeat_byte(43);
+    let num_sets = MIN_BLS_BATCH_VERIFY_SIZE + 1;
+
+    let valid_signatures = KEYPAIRS[..num_sets]
+        .iter()
+        .map(|keypair| keypair.sk.sign(message))
+        .collect::<Vec<_>>();
+    let mut invalid_signatures = valid_signatures.clone();
+    invalid_signatures[0] = KEYPAIRS[0].sk.sign(other_message);
+
+    let signature_sets = |signatures: &[Signature], n: usize| {
+        signatures[..n]
+            .iter()
+            .zip(KEYPAIRS.iter())
+            .map(|(signature, keypair)| {
+                SignatureSet::single_pubkey(signature, Cow::Borrowed(&keypair.pk), message)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // A single set is verified individually.
+    assert!(chain
+        .verify_bls_signature_batch(signature_sets(&valid_signatures, 1))
+        .is_ok());
+    assert!(matches!(
+        chain.verify_bls_signature_batch(signature_sets(&invalid_signatures, 1)),
+        Err(BeaconChainError::InvalidSignatureBatch)
+    ));
+
+    // Larger sets are batch verified.
+    assert!(chain
+        .verify_bls_signature_batch(signature_sets(&valid_signatures, num_sets))
+        .is_ok());
+    assert!(matches!(
+        chain.verify_bls_signature_batch(signature_sets(&invalid_signatures, num_sets)),
+        Err(BeaconChainError::InvalidSignatureBatch)
+    ));
+
+    assert!(matches!(
+        chain.verify_bls_signature_batch(vec![]),
+        Err(BeaconChainError::InvalidSignatureBatch)
+    ));
+}
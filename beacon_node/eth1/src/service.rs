@@ -365,6 +365,9 @@ pub struct DepositCacheUpdateOutcome {
 pub struct Config {
     /// An Eth1 node (e.g., Geth) running a HTTP JSON-RPC endpoint.
     pub endpoints: Vec<SensitiveUrl>,
+    /// The priority of each of the `endpoints`, by index. Endpoints with a lower priority are
+    /// tried first and endpoints without a priority have priority 0.
+    pub endpoint_priorities: Vec<u64>,
     /// The address the `BlockCache` and `DepositCache` should assume is the canonical deposit contract.
     pub deposit_contract_address: String,
     /// The eth1 network id where the deposit contract is deployed (Goerli/Mainnet).
@@ -398,6 +401,9 @@ pub struct Config {
     pub max_blocks_per_update: Option<usize>,
     /// If set to true, the eth1 caches are wiped clean when the eth1 service starts.
     pub purge_cache: bool,
+    /// If set to true, the eth1 node is never polled and no new deposits are processed. Blocks
+    /// will repeat the `Eth1Data` of their parent state.
+    pub disable_deposit_processing: bool,
 }
 
 impl Config {
@@ -423,6 +429,26 @@ impl Config {
 
         self.block_cache_truncation = Some(length as usize);
     }
+
+    /// Returns the `endpoints` in the order they should be tried, i.e., sorted by ascending
+    /// priority. Endpoints with the same priority are tried in the order they were given.
+    pub fn prioritized_endpoints(&self) -> Vec<SensitiveUrl> {
+        let mut endpoints = self
+            .endpoints
+            .iter()
+            .enumerate()
+            .map(|(i, endpoint)| {
+                let priority = self.endpoint_priorities.get(i).copied().unwrap_or(0);
+                (priority, endpoint.clone())
+            })
+            .collect::<Vec<_>>();
+        // Stable sort, equal priorities keep their original order.
+        endpoints.sort_by_key(|(priority, _)| *priority);
+        endpoints
+            .into_iter()
+            .map(|(_, endpoint)| endpoint)
+            .collect()
+    }
 }
 
 impl Default for Config {
@@ -430,6 +456,7 @@ impl Default for Config {
         Self {
             endpoints: vec![SensitiveUrl::parse(DEFAULT_ETH1_ENDPOINT)
                 .expect("The default Eth1 endpoint must always be a valid URL.")],
+            endpoint_priorities: vec![],
             deposit_contract_address: "0x0000000000000000000000000000000000000000".into(),
             network_id: DEFAULT_NETWORK_ID,
             chain_id: DEFAULT_CHAIN_ID,
@@ -443,6 +470,7 @@ impl Default for Config {
             max_log_requests_per_update: Some(5_000),
             max_blocks_per_update: Some(8_192),
             purge_cache: false,
+            disable_deposit_processing: false,
         }
     }
 }
@@ -641,7 +669,7 @@ impl Service {
 
     /// Builds a new `EndpointsCache` with empty states.
     pub fn init_endpoints(&self) -> Arc<EndpointsCache> {
-        let endpoints = self.config().endpoints.clone();
+        let endpoints = self.config().prioritized_endpoints();
         let config_network_id = self.config().network_id.clone();
         let config_chain_id = self.config().chain_id.clone();
         let new_cache = Arc::new(EndpointsCache {
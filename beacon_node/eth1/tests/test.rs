@@ -922,6 +922,81 @@ mod fallbacks {
         .await;
     }
 
+    #[tokio::test]
+    async fn test_fallback_respects_endpoint_priority() {
+        async {
+            let log = null_logger();
+            let endpoint2 = new_ganache_instance()
+                .await
+                .expect("should start eth1 environment");
+            let deposit_contract = &endpoint2.deposit_contract;
+
+            let initial_block_number = get_block_number(&endpoint2.web3()).await;
+
+            let new_blocks = 4;
+
+            for _ in 0..new_blocks {
+                endpoint2
+                    .ganache
+                    .evm_mine()
+                    .await
+                    .expect("should mine block");
+            }
+
+            let endpoint1 = endpoint2
+                .ganache
+                .fork()
+                .expect("should start eth1 environment");
+
+            //mine additional blocks on top of the original endpoint
+            for _ in 0..new_blocks {
+                endpoint2
+                    .ganache
+                    .evm_mine()
+                    .await
+                    .expect("should mine block");
+            }
+
+            //endpoint1 is listed last but has the lowest priority number
+            let service = Service::new(
+                Config {
+                    endpoints: vec![
+                        SensitiveUrl::parse(endpoint2.endpoint().as_str()).unwrap(),
+                        SensitiveUrl::parse(endpoint1.endpoint().as_str()).unwrap(),
+                    ],
+                    endpoint_priorities: vec![1, 0],
+                    deposit_contract_address: deposit_contract.address(),
+                    lowest_cached_block_number: initial_block_number,
+                    follow_distance: 0,
+                    ..Config::default()
+                },
+                log.clone(),
+                MainnetEthSpec::default_spec(),
+            );
+
+            let endpoint1_block_number = get_block_number(&endpoint1.web3).await;
+            //the first call will only query endpoint1
+            service.update().await.expect("should update deposit cache");
+            assert_eq!(
+                service.deposits().read().last_processed_block.unwrap(),
+                endpoint1_block_number
+            );
+
+            drop(endpoint1);
+
+            let endpoint2_block_number = get_block_number(&endpoint2.web3()).await;
+            assert!(endpoint1_block_number < endpoint2_block_number);
+            //endpoint1 is offline => query will import blocks from endpoint2
+            service.update().await.expect("should update deposit cache");
+            assert_eq!(
+                service.deposits().read().last_processed_block.unwrap(),
+                endpoint2_block_number
+            );
+        }
+        .compat()
+        .await;
+    }
+
     #[tokio::test]
     async fn test_fallback_when_wrong_network_id() {
         async {
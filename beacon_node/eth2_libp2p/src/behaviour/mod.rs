@@ -219,7 +219,12 @@ impl<TSpec: EthSpec> Behaviour<TSpec> {
             .expect("Valid score params and thresholds");
 
         Ok(Behaviour {
-            eth2_rpc: RPC::new(log.clone()),
+            eth2_rpc: RPC::new(
+                net_conf.max_concurrent_rpc_requests_per_peer,
+                net_conf.rpc_range_request_limit,
+                net_conf.rpc_range_burst,
+                log.clone(),
+            ),
             gossipsub,
             identify,
             peer_manager: PeerManager::new(local_key, net_conf, network_globals.clone(), log)
@@ -1,3 +1,4 @@
+use crate::rpc::MAX_REQUEST_BLOCKS;
 use crate::types::GossipKind;
 use crate::{Enr, PeerIdSerialized};
 use directory::{
@@ -21,6 +22,21 @@ pub const GOSSIP_MAX_SIZE: usize = 1_048_576;
 // const MESSAGE_DOMAIN_INVALID_SNAPPY: [u8; 4] = [0, 0, 0, 0];
 const MESSAGE_DOMAIN_VALID_SNAPPY: [u8; 4] = [1, 0, 0, 0];
 pub const MESH_N_LOW: usize = 6;
+/// The default target number of peers in each gossipsub topic mesh.
+pub const DEFAULT_MESH_N: usize = 8;
+/// The default upper bound on the number of peers in each gossipsub topic mesh.
+pub const DEFAULT_MESH_N_HIGH: usize = 12;
+/// The default interval between gossipsub heartbeats, which perform mesh maintenance.
+pub const DEFAULT_GOSSIP_HEARTBEAT_INTERVAL: Duration = Duration::from_millis(700);
+/// By default, messages we publish are sent to all peers subscribed to the topic, not just the
+/// mesh.
+pub const DEFAULT_FLOOD_PUBLISH: bool = true;
+
+/// The default maximum number of bytes buffered for each yamux substream.
+pub const DEFAULT_YAMUX_MAX_STREAM_BUFFER_SIZE: usize = 16 * 1024 * 1024;
+/// The smallest yamux receive window permitted by the yamux specification, which is also its
+/// default.
+pub const MIN_YAMUX_WINDOW_SIZE: u32 = 256 * 1024;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(default)]
@@ -93,6 +109,35 @@ pub struct Config {
 
     /// List of extra topics to initially subscribe to as strings.
     pub topics: Vec<GossipKind>,
+
+    /// The maximum number of concurrent inbound RPC requests a single peer may have open. Any
+    /// request beyond this limit is rejected with a `ResourceUnavailable` error.
+    pub max_concurrent_rpc_requests_per_peer: usize,
+
+    /// The number of blocks a single peer may request via `BlocksByRange` in each 10 second
+    /// window. Requests beyond this rate are rejected with a rate-limited error.
+    pub rpc_range_request_limit: u64,
+
+    /// The maximum number of blocks a single peer may request via `BlocksByRange` in an
+    /// instantaneous burst before being rate limited.
+    pub rpc_range_burst: u64,
+
+    /// The interval in seconds between PINGs sent to outbound peers. Inbound peers are PING'd on
+    /// a slightly longer interval to avoid simultaneous pings.
+    pub ping_interval: u64,
+
+    /// When the beacon processor's work queues are congested, only admit new work which is
+    /// estimated to take less than this many milliseconds to process. Admission control is
+    /// disabled when `None`.
+    pub beacon_processor_admission_cost_threshold_ms: Option<u64>,
+
+    /// The maximum number of bytes buffered for each yamux substream. Bounds the memory used by
+    /// many concurrent RPC streams.
+    pub yamux_max_stream_buffer_size: usize,
+
+    /// The yamux flow-control receive window of each substream, in bytes. Must be at least
+    /// `MIN_YAMUX_WINDOW_SIZE`.
+    pub yamux_window_size: u32,
 }
 
 impl Default for Config {
@@ -132,10 +177,11 @@ impl Default for Config {
         // parameter.
         let gs_config = GossipsubConfigBuilder::default()
             .max_transmit_size(GOSSIP_MAX_SIZE)
-            .heartbeat_interval(Duration::from_millis(700))
-            .mesh_n(8)
+            .heartbeat_interval(DEFAULT_GOSSIP_HEARTBEAT_INTERVAL)
+            .mesh_n(DEFAULT_MESH_N)
             .mesh_n_low(MESH_N_LOW)
-            .mesh_n_high(12)
+            .mesh_n_high(DEFAULT_MESH_N_HIGH)
+            .flood_publish(DEFAULT_FLOOD_PUBLISH)
             .gossip_lazy(6)
             .fanout_ttl(Duration::from_secs(60))
             .history_length(6)
@@ -189,6 +235,46 @@ impl Default for Config {
             subscribe_all_subnets: false,
             import_all_attestations: false,
             topics: Vec::new(),
+            max_concurrent_rpc_requests_per_peer: 4,
+            rpc_range_request_limit: MAX_REQUEST_BLOCKS,
+            rpc_range_burst: MAX_REQUEST_BLOCKS,
+            ping_interval: 30,
+            beacon_processor_admission_cost_threshold_ms: None,
+            yamux_max_stream_buffer_size: DEFAULT_YAMUX_MAX_STREAM_BUFFER_SIZE,
+            yamux_window_size: MIN_YAMUX_WINDOW_SIZE,
         }
     }
 }
+
+impl Config {
+    /// Rebuilds `self.gs_config` with a new heartbeat interval and mesh size parameters, leaving
+    /// all other gossipsub parameters unchanged.
+    ///
+    /// Returns an error if the mesh sizes are inconsistent, e.g. `mesh_n_low > mesh_n`.
+    pub fn set_gossipsub_mesh_params(
+        &mut self,
+        heartbeat_interval: Duration,
+        mesh_n_low: usize,
+        mesh_n: usize,
+        mesh_n_high: usize,
+    ) -> Result<(), String> {
+        self.gs_config = GossipsubConfigBuilder::from(self.gs_config.clone())
+            .heartbeat_interval(heartbeat_interval)
+            .mesh_n_low(mesh_n_low)
+            .mesh_n(mesh_n)
+            .mesh_n_high(mesh_n_high)
+            .build()
+            .map_err(|e| format!("Invalid gossipsub configuration: {}", e))?;
+        Ok(())
+    }
+
+    /// Rebuilds `self.gs_config` to publish our own messages either to all peers subscribed to a
+    /// topic (`flood_publish == true`) or only to the topic mesh.
+    pub fn set_gossipsub_flood_publish(&mut self, flood_publish: bool) -> Result<(), String> {
+        self.gs_config = GossipsubConfigBuilder::from(self.gs_config.clone())
+            .flood_publish(flood_publish)
+            .build()
+            .map_err(|e| format!("Invalid gossipsub configuration: {}", e))?;
+        Ok(())
+    }
+}
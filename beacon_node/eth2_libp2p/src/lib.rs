@@ -62,7 +62,7 @@ impl<'de> Deserialize<'de> for PeerIdSerialized {
 
 pub use crate::types::{error, Enr, GossipTopic, NetworkGlobals, PubsubMessage, SubnetDiscovery};
 pub use behaviour::{BehaviourEvent, Gossipsub, PeerRequestId, Request, Response};
-pub use config::Config as NetworkConfig;
+pub use config::{Config as NetworkConfig, MIN_YAMUX_WINDOW_SIZE};
 pub use discovery::{CombinedKeyExt, EnrExt, Eth2Enr};
 pub use discv5;
 pub use libp2p::bandwidth::BandwidthSinks;
@@ -55,6 +55,11 @@ lazy_static! {
         "RPC errors per client",
         &["client", "rpc_error", "direction"]
     );
+    pub static ref RPC_REQUEST_TIMEOUTS: Result<IntCounterVec> = try_create_int_counter_vec(
+        "beacon_rpc_request_timeout_total",
+        "Count of outbound RPC requests which were not answered before the response timeout",
+        &["type"]
+    );
     pub static ref PEER_ACTION_EVENTS_PER_CLIENT: Result<IntCounterVec> =
         try_create_int_counter_vec(
             "libp2p_peer_actions_per_client",
@@ -67,6 +72,16 @@ lazy_static! {
             "Gossipsub messages that we did not accept, per client",
             &["client", "validation_result"]
         );
+    pub static ref RPC_REQUEST_CONCURRENCY_REJECTED: Result<IntCounterVec> =
+        try_create_int_counter_vec(
+            "rpc_request_concurrency_rejected_total",
+            "Inbound RPC requests rejected for exceeding the per-peer concurrency limit",
+            &["protocol"]
+        );
+    pub static ref NETWORK_PING_TIMEOUT_TOTAL: Result<IntCounter> = try_create_int_counter(
+        "network_ping_timeout_total",
+        "Count of outbound PING requests that timed out waiting for a PONG"
+    );
 }
 
 pub fn scrape_discovery_metrics() {
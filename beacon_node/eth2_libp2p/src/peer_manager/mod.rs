@@ -39,13 +39,10 @@ use std::collections::HashMap;
 
 /// The time in seconds between re-status's peers.
 const STATUS_INTERVAL: u64 = 300;
-/// The time in seconds between PING events. We do not send a ping if the other peer has PING'd us
-/// within this time frame (Seconds)
-/// This is asymmetric to avoid simultaneous pings.
-/// The interval for outbound connections.
-const PING_INTERVAL_OUTBOUND: u64 = 15;
-/// The interval for inbound connections.
-const PING_INTERVAL_INBOUND: u64 = 20;
+/// The additional time in seconds inbound connections wait between PING events, on top of the
+/// configured `ping_interval` used for outbound connections. We do not send a ping if the other
+/// peer has PING'd us within this time frame. This is asymmetric to avoid simultaneous pings.
+const PING_INTERVAL_INBOUND_OFFSET: u64 = 5;
 
 /// The heartbeat performs regular updates such as updating reputations and performing discovery
 /// requests. This defines the interval in seconds.
@@ -75,6 +72,8 @@ pub struct PeerManager<TSpec: EthSpec> {
     outbound_ping_peers: HashSetDelay<PeerId>,
     /// A collection of peers awaiting to be Status'd.
     status_peers: HashSetDelay<PeerId>,
+    /// The number of consecutive PINGs each peer has failed to respond to.
+    missed_pings: HashMap<PeerId, u32>,
     /// The target number of peers we would like to connect to.
     target_peers: usize,
     /// The maximum number of peers we allow (exceptions for subnet peers)
@@ -122,9 +121,12 @@ impl<TSpec: EthSpec> PeerManager<TSpec> {
         Ok(PeerManager {
             network_globals,
             events: SmallVec::new(),
-            inbound_ping_peers: HashSetDelay::new(Duration::from_secs(PING_INTERVAL_INBOUND)),
-            outbound_ping_peers: HashSetDelay::new(Duration::from_secs(PING_INTERVAL_OUTBOUND)),
+            inbound_ping_peers: HashSetDelay::new(Duration::from_secs(
+                config.ping_interval + PING_INTERVAL_INBOUND_OFFSET,
+            )),
+            outbound_ping_peers: HashSetDelay::new(Duration::from_secs(config.ping_interval)),
             status_peers: HashSetDelay::new(Duration::from_secs(STATUS_INTERVAL)),
+            missed_pings: HashMap::new(),
             target_peers: config.target_peers,
             max_peers: (config.target_peers as f32 * (1.0 + PEER_EXCESS_FACTOR)).ceil() as usize,
             discovery,
@@ -320,6 +322,7 @@ impl<TSpec: EthSpec> PeerManager<TSpec> {
         self.inbound_ping_peers.remove(peer_id);
         self.outbound_ping_peers.remove(peer_id);
         self.status_peers.remove(peer_id);
+        self.missed_pings.remove(peer_id);
     }
 
     /// A dial attempt has failed.
@@ -473,14 +476,23 @@ impl<TSpec: EthSpec> PeerManager<TSpec> {
                     warn!(self.log, "Timed out to a peer's request. Likely too many resources, reduce peer count");
                     return;
                 }
-                ConnectionDirection::Outgoing => match protocol {
-                    Protocol::Ping => PeerAction::LowToleranceError,
-                    Protocol::BlocksByRange => PeerAction::MidToleranceError,
-                    Protocol::BlocksByRoot => PeerAction::MidToleranceError,
-                    Protocol::Goodbye => return,
-                    Protocol::MetaData => return,
-                    Protocol::Status => return,
-                },
+                ConnectionDirection::Outgoing => {
+                    metrics::inc_counter_vec(
+                        &metrics::RPC_REQUEST_TIMEOUTS,
+                        &[&protocol.to_string()],
+                    );
+                    match protocol {
+                        Protocol::Ping => {
+                            self.handle_ping_timeout(peer_id);
+                            return;
+                        }
+                        Protocol::BlocksByRange => PeerAction::MidToleranceError,
+                        Protocol::BlocksByRoot => PeerAction::MidToleranceError,
+                        Protocol::Goodbye => return,
+                        Protocol::MetaData => return,
+                        Protocol::Status => return,
+                    }
+                }
             },
             RPCError::NegotiationTimeout => PeerAction::LowToleranceError,
         };
@@ -488,6 +500,33 @@ impl<TSpec: EthSpec> PeerManager<TSpec> {
         self.report_peer(peer_id, peer_action, ReportSource::RPC);
     }
 
+    /// An outbound PING to a peer has timed out.
+    ///
+    /// The peer is penalised once for every PING it has consecutively failed to answer, so a peer
+    /// that repeatedly misses PINGs is disconnected quickly while a single miss is tolerated. The
+    /// penalties stop once the peer is to be disconnected, so missed PINGs alone never get a peer
+    /// banned.
+    fn handle_ping_timeout(&mut self, peer_id: &PeerId) {
+        metrics::inc_counter(&metrics::NETWORK_PING_TIMEOUT_TOTAL);
+
+        let missed = self.missed_pings.entry(*peer_id).or_default();
+        *missed = missed.saturating_add(1);
+        let missed = *missed;
+
+        debug!(self.log, "Peer failed to respond to a ping"; "peer_id" => %peer_id, "consecutive_missed" => missed);
+        for _ in 0..missed {
+            if self
+                .network_globals
+                .peers
+                .read()
+                .is_banned_or_disconnected(peer_id)
+            {
+                break;
+            }
+            self.report_peer(peer_id, PeerAction::LowToleranceError, ReportSource::RPC);
+        }
+    }
+
     /// A ping request has been received.
     // NOTE: The behaviour responds with a PONG automatically
     pub fn ping_request(&mut self, peer_id: &PeerId, seq: u64) {
@@ -530,6 +569,7 @@ impl<TSpec: EthSpec> PeerManager<TSpec> {
     pub fn pong_response(&mut self, peer_id: &PeerId, seq: u64) {
         if let Some(peer_info) = self.network_globals.peers.read().peer_info(peer_id) {
             // received a pong
+            self.missed_pings.remove(peer_id);
 
             // if the sequence number is unknown send update the meta data of the peer.
             if let Some(meta_data) = &peer_info.meta_data {
@@ -1094,11 +1134,18 @@ mod tests {
     }
 
     async fn build_peer_manager(target: usize) -> PeerManager<E> {
+        build_peer_manager_with_config(NetworkConfig {
+            target_peers: target,
+            ..Default::default()
+        })
+        .await
+    }
+
+    async fn build_peer_manager_with_config(config: NetworkConfig) -> PeerManager<E> {
         let keypair = libp2p::identity::Keypair::generate_secp256k1();
         let config = NetworkConfig {
             discovery_port: unused_port(),
-            target_peers: target,
-            ..Default::default()
+            ..config
         };
         let enr_key: CombinedKey = CombinedKey::from_libp2p(&keypair).unwrap();
         let enr: Enr = build_enr::<E>(&enr_key, &config, EnrForkId::default()).unwrap();
@@ -1352,4 +1399,80 @@ mod tests {
         // the number of connected peers updates and we will not remove too many peers.
         assert_eq!(peer_manager.network_globals.connected_or_dialing_peers(), 3);
     }
+
+    #[tokio::test]
+    async fn test_peer_manager_uses_configured_ping_interval() {
+        let ping_interval = 42;
+        let mut peer_manager = build_peer_manager_with_config(NetworkConfig {
+            target_peers: 3,
+            ping_interval,
+            ..Default::default()
+        })
+        .await;
+
+        let inbound_peer = PeerId::random();
+        let outbound_peer = PeerId::random();
+        let before = Instant::now();
+        peer_manager.connect_ingoing(&inbound_peer, "/ip4/0.0.0.0".parse().unwrap());
+        peer_manager.connect_outgoing(&outbound_peer, "/ip4/0.0.0.0".parse().unwrap());
+        let after = Instant::now();
+
+        let outbound_deadline = *peer_manager
+            .outbound_ping_peers
+            .get(&outbound_peer)
+            .unwrap();
+        assert!(outbound_deadline >= before + Duration::from_secs(ping_interval));
+        assert!(outbound_deadline <= after + Duration::from_secs(ping_interval));
+
+        let inbound_interval = Duration::from_secs(ping_interval + PING_INTERVAL_INBOUND_OFFSET);
+        let inbound_deadline = *peer_manager.inbound_ping_peers.get(&inbound_peer).unwrap();
+        assert!(inbound_deadline >= before + inbound_interval);
+        assert!(inbound_deadline <= after + inbound_interval);
+    }
+
+    #[tokio::test]
+    async fn test_peer_manager_penalises_consecutive_ping_timeouts() {
+        let mut peer_manager = build_peer_manager(3).await;
+
+        let peer = PeerId::random();
+        peer_manager.connect_outgoing(&peer, "/ip4/0.0.0.0".parse().unwrap());
+
+        let ping_timeout = |peer_manager: &mut PeerManager<E>| {
+            let before = peer_manager.network_globals.peers.read().score(&peer);
+            peer_manager.handle_rpc_error(
+                &peer,
+                Protocol::Ping,
+                &RPCError::StreamTimeout,
+                ConnectionDirection::Outgoing,
+            );
+            before - peer_manager.network_globals.peers.read().score(&peer)
+        };
+
+        // A single miss is tolerated.
+        assert!(ping_timeout(&mut peer_manager) > 0.0);
+        assert!(!peer_manager
+            .network_globals
+            .peers
+            .read()
+            .is_banned_or_disconnected(&peer));
+
+        // The second consecutive miss disconnects the peer.
+        assert!(ping_timeout(&mut peer_manager) > 0.0);
+        assert!(peer_manager
+            .network_globals
+            .peers
+            .read()
+            .is_banned_or_disconnected(&peer));
+        assert_eq!(peer_manager.missed_pings.get(&peer), Some(&2));
+
+        // Further misses are not penalised, so missed PINGs alone never ban a peer.
+        for _ in 0..5 {
+            assert!(ping_timeout(&mut peer_manager) < 1.0);
+        }
+        assert!(!peer_manager.network_globals.peers.read().is_banned(&peer));
+
+        // A PONG resets the count of consecutive misses.
+        peer_manager.pong_response(&peer, 0);
+        assert!(peer_manager.missed_pings.get(&peer).is_none());
+    }
 }
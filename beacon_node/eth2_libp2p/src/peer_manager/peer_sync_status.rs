@@ -62,6 +62,16 @@ impl PeerSyncStatus {
         matches!(self, PeerSyncStatus::Behind { .. })
     }
 
+    /// Returns the sync information from the peer's latest STATUS, if known.
+    pub fn info(&self) -> Option<&SyncInfo> {
+        match self {
+            PeerSyncStatus::Synced { info }
+            | PeerSyncStatus::Advanced { info }
+            | PeerSyncStatus::Behind { info } => Some(info),
+            PeerSyncStatus::IrrelevantPeer | PeerSyncStatus::Unknown => None,
+        }
+    }
+
     pub fn update(&mut self, new_state: PeerSyncStatus) -> bool {
         if *self == new_state {
             *self = new_state;
@@ -10,7 +10,7 @@ use slog::{crit, debug, error, trace, warn};
 use std::collections::HashMap;
 use std::net::{IpAddr, SocketAddr};
 use std::time::Instant;
-use types::{EthSpec, SubnetId};
+use types::{Checkpoint, EthSpec, Hash256, Slot, SubnetId};
 
 /// Max number of disconnected nodes to remember.
 const MAX_DC_PEERS: usize = 500;
@@ -266,6 +266,42 @@ impl<TSpec: EthSpec> PeerDB<TSpec> {
             .map(|(peer_id, _)| peer_id)
     }
 
+    /// Gives the `peer_id` of all connected peers whose latest STATUS reports `checkpoint` as
+    /// finalized.
+    pub fn peers_with_finalized_checkpoint(
+        &self,
+        checkpoint: Checkpoint,
+    ) -> impl Iterator<Item = &PeerId> {
+        self.peers
+            .iter()
+            .filter(move |(_, info)| {
+                info.is_connected()
+                    && info.sync_status.info().map_or(false, |sync_info| {
+                        sync_info.finalized_epoch == checkpoint.epoch
+                            && sync_info.finalized_root == checkpoint.root
+                    })
+            })
+            .map(|(peer_id, _)| peer_id)
+    }
+
+    /// Gives the `peer_id` of all connected peers whose latest STATUS reports the block at
+    /// `head_root` and `head_slot` as their head.
+    pub fn peers_at_head(
+        &self,
+        head_slot: Slot,
+        head_root: Hash256,
+    ) -> impl Iterator<Item = &PeerId> {
+        self.peers
+            .iter()
+            .filter(move |(_, info)| {
+                info.is_connected()
+                    && info.sync_status.info().map_or(false, |sync_info| {
+                        sync_info.head_slot == head_slot && sync_info.head_root == head_root
+                    })
+            })
+            .map(|(peer_id, _)| peer_id)
+    }
+
     /// Gives an iterator of all peers on a given subnet.
     pub fn good_peers_on_subnet(&self, subnet_id: SubnetId) -> impl Iterator<Item = &PeerId> {
         self.peers
@@ -633,10 +669,11 @@ impl<TSpec: EthSpec> PeerDB<TSpec> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::SyncInfo;
     use libp2p::core::Multiaddr;
     use slog::{o, Drain};
     use std::net::{Ipv4Addr, Ipv6Addr};
-    use types::MinimalEthSpec;
+    use types::{Epoch, Hash256, MinimalEthSpec, Slot};
 
     type M = MinimalEthSpec;
 
@@ -1089,4 +1126,84 @@ mod tests {
             Score::max_score().score()
         );
     }
+
+    #[test]
+    fn test_peers_with_finalized_checkpoint() {
+        let mut pdb = get_db();
+        let checkpoint = Checkpoint {
+            epoch: Epoch::new(4),
+            root: Hash256::repeat_byte(1),
+        };
+        let sync_status = |finalized_root| PeerSyncStatus::Synced {
+            info: SyncInfo {
+                head_slot: Slot::new(160),
+                head_root: Hash256::repeat_byte(2),
+                finalized_epoch: checkpoint.epoch,
+                finalized_root,
+            },
+        };
+
+        let matching_peer = PeerId::random();
+        let other_root_peer = PeerId::random();
+        let disconnected_peer = PeerId::random();
+        let unknown_status_peer = PeerId::random();
+        for peer in &[
+            matching_peer,
+            other_root_peer,
+            disconnected_peer,
+            unknown_status_peer,
+        ] {
+            pdb.connect_ingoing(peer, "/ip4/0.0.0.0".parse().unwrap(), None);
+        }
+        pdb.peer_info_mut(&matching_peer).unwrap().sync_status = sync_status(checkpoint.root);
+        pdb.peer_info_mut(&other_root_peer).unwrap().sync_status =
+            sync_status(Hash256::repeat_byte(3));
+        pdb.peer_info_mut(&disconnected_peer).unwrap().sync_status = sync_status(checkpoint.root);
+        pdb.notify_disconnect(&disconnected_peer);
+
+        let peers: Vec<_> = pdb.peers_with_finalized_checkpoint(checkpoint).collect();
+        assert_eq!(peers, vec![&matching_peer]);
+    }
+
+    #[test]
+    fn test_peers_at_head() {
+        let mut pdb = get_db();
+        let head_slot = Slot::new(160);
+        let head_root = Hash256::repeat_byte(2);
+        let sync_status = |head_slot, head_root| PeerSyncStatus::Synced {
+            info: SyncInfo {
+                head_slot,
+                head_root,
+                finalized_epoch: Epoch::new(3),
+                finalized_root: Hash256::repeat_byte(1),
+            },
+        };
+
+        let matching_peer = PeerId::random();
+        let other_root_peer = PeerId::random();
+        let other_slot_peer = PeerId::random();
+        let disconnected_peer = PeerId::random();
+        let unknown_status_peer = PeerId::random();
+        for peer in &[
+            matching_peer,
+            other_root_peer,
+            other_slot_peer,
+            disconnected_peer,
+            unknown_status_peer,
+        ] {
+            pdb.connect_ingoing(peer, "/ip4/0.0.0.0".parse().unwrap(), None);
+        }
+        pdb.peer_info_mut(&matching_peer).unwrap().sync_status = sync_status(head_slot, head_root);
+        pdb.peer_info_mut(&other_root_peer).unwrap().sync_status =
+            sync_status(head_slot, Hash256::repeat_byte(3));
+        pdb.peer_info_mut(&other_slot_peer).unwrap().sync_status =
+            sync_status(head_slot + 1, head_root);
+        pdb.peer_info_mut(&disconnected_peer).unwrap().sync_status =
+            sync_status(head_slot, head_root);
+        pdb.notify_disconnect(&disconnected_peer);
+
+        let peers: Vec<_> = pdb.peers_at_head(head_slot, head_root).collect();
+        assert_eq!(peers, vec![&matching_peer]);
+        assert_eq!(pdb.connected_peer_ids().count(), 4);
+    }
 }
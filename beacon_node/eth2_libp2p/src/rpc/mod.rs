@@ -14,11 +14,14 @@ use libp2p::swarm::{
 use libp2p::{Multiaddr, PeerId};
 use rate_limiter::{RPCRateLimiter as RateLimiter, RPCRateLimiterBuilder, RateLimitedErr};
 use slog::{crit, debug, o};
+use std::collections::{HashMap, HashSet};
 use std::marker::PhantomData;
 use std::task::{Context, Poll};
 use std::time::Duration;
 use types::EthSpec;
 
+use crate::metrics;
+
 pub(crate) use handler::HandlerErr;
 pub(crate) use methods::{MetaData, Ping, RPCCodedResponse, RPCResponse};
 pub(crate) use protocol::{RPCProtocol, RPCRequest};
@@ -36,6 +39,49 @@ pub mod methods;
 mod protocol;
 mod rate_limiter;
 
+/// The window over which the `BlocksByRange` request limit of a peer is measured.
+pub const RANGE_REQUEST_LIMIT_WINDOW: Duration = Duration::from_secs(10);
+
+/// The smallest `BlocksByRange` burst which may be configured.
+///
+/// A request for more blocks than the burst can never be served, so this is large enough for the
+/// batches requested by Lighthouse's range sync.
+pub const MIN_RANGE_BURST: u64 = 64;
+
+/// Returns the time taken to replenish a full burst of `range_burst` blocks when each peer may
+/// request `range_request_limit` blocks every `RANGE_REQUEST_LIMIT_WINDOW`.
+///
+/// Returns an error if the limit or burst are invalid, or if they cannot be represented by the
+/// rate limiter.
+pub fn range_replenish_period(
+    range_request_limit: u64,
+    range_burst: u64,
+) -> Result<Duration, String> {
+    if range_request_limit == 0 {
+        return Err("The BlocksByRange request limit must be positive".to_string());
+    }
+    if range_burst < MIN_RANGE_BURST {
+        return Err(format!(
+            "The BlocksByRange burst must be at least {}",
+            MIN_RANGE_BURST
+        ));
+    }
+
+    let period_nanos =
+        RANGE_REQUEST_LIMIT_WINDOW.as_nanos() * range_burst as u128 / range_request_limit as u128;
+
+    // The rate limiter replenishes one block every `period_nanos / range_burst` nanoseconds, which
+    // must be non-zero, and stores the period as a `u64`.
+    if period_nanos / (range_burst as u128) == 0 || period_nanos > u64::max_value() as u128 {
+        return Err(format!(
+            "The BlocksByRange burst of {} is incompatible with the limit of {}",
+            range_burst, range_request_limit
+        ));
+    }
+
+    Ok(Duration::from_nanos(period_nanos as u64))
+}
+
 /// RPC events sent from Lighthouse.
 #[derive(Debug, Clone)]
 pub enum RPCSend<T: EthSpec> {
@@ -94,6 +140,10 @@ pub struct RPCMessage<TSpec: EthSpec> {
 pub struct RPC<TSpec: EthSpec> {
     /// Rate limiter
     limiter: RateLimiter,
+    /// The inbound requests of each peer that are still awaiting a final response.
+    active_inbound_requests: HashMap<PeerId, HashSet<(ConnectionId, SubstreamId)>>,
+    /// The maximum number of inbound requests a peer may have open at once.
+    max_concurrent_requests_per_peer: usize,
     /// Queue of events to be processed.
     events: Vec<NetworkBehaviourAction<RPCSend<TSpec>, RPCMessage<TSpec>>>,
     /// Slog logger for RPC behaviour.
@@ -101,18 +151,26 @@ pub struct RPC<TSpec: EthSpec> {
 }
 
 impl<TSpec: EthSpec> RPC<TSpec> {
-    pub fn new(log: slog::Logger) -> Self {
+    /// Creates a new RPC behaviour.
+    ///
+    /// Each peer may request `range_request_limit` blocks via `BlocksByRange` every
+    /// `RANGE_REQUEST_LIMIT_WINDOW`, in bursts of at most `range_burst` blocks. The limit and burst
+    /// must be accepted by `range_replenish_period`.
+    pub fn new(
+        max_concurrent_requests_per_peer: usize,
+        range_request_limit: u64,
+        range_burst: u64,
+        log: slog::Logger,
+    ) -> Self {
         let log = log.new(o!("service" => "libp2p_rpc"));
+        let range_replenish_period = range_replenish_period(range_request_limit, range_burst)
+            .expect("BlocksByRange quota is validated by the network config");
         let limiter = RPCRateLimiterBuilder::new()
             .n_every(Protocol::MetaData, 2, Duration::from_secs(5))
             .n_every(Protocol::Ping, 2, Duration::from_secs(10))
             .n_every(Protocol::Status, 5, Duration::from_secs(15))
             .one_every(Protocol::Goodbye, Duration::from_secs(10))
-            .n_every(
-                Protocol::BlocksByRange,
-                methods::MAX_REQUEST_BLOCKS,
-                Duration::from_secs(10),
-            )
+            .n_every(Protocol::BlocksByRange, range_burst, range_replenish_period)
             .n_every(
                 Protocol::BlocksByRoot,
                 methods::MAX_REQUEST_BLOCKS,
@@ -122,6 +180,8 @@ impl<TSpec: EthSpec> RPC<TSpec> {
             .expect("Configuration parameters are valid");
         RPC {
             limiter,
+            active_inbound_requests: HashMap::new(),
+            max_concurrent_requests_per_peer,
             events: Vec::new(),
             log,
         }
@@ -136,6 +196,11 @@ impl<TSpec: EthSpec> RPC<TSpec> {
         id: (ConnectionId, SubstreamId),
         event: RPCCodedResponse<TSpec>,
     ) {
+        // Single-chunk responses (e.g. Status, Ping and MetaData) complete the request without a
+        // stream termination, so they must also release the request's slot.
+        if event.close_after() || !event.multiple_responses() {
+            self.remove_active_request(&peer_id, id);
+        }
         self.events.push(NetworkBehaviourAction::NotifyHandler {
             peer_id,
             handler: NotifyHandler::One(id.0),
@@ -158,6 +223,16 @@ impl<TSpec: EthSpec> RPC<TSpec> {
             event: RPCSend::Request(request_id, event),
         });
     }
+
+    /// Stops tracking an inbound request of a peer, once it has been fully answered or has failed.
+    fn remove_active_request(&mut self, peer_id: &PeerId, id: (ConnectionId, SubstreamId)) {
+        if let Some(requests) = self.active_inbound_requests.get_mut(peer_id) {
+            requests.remove(&id);
+            if requests.is_empty() {
+                self.active_inbound_requests.remove(peer_id);
+            }
+        }
+    }
 }
 
 impl<TSpec> NetworkBehaviour for RPC<TSpec>
@@ -196,7 +271,9 @@ where
         });
     }
 
-    fn inject_disconnected(&mut self, _peer_id: &PeerId) {}
+    fn inject_disconnected(&mut self, peer_id: &PeerId) {
+        self.active_inbound_requests.remove(peer_id);
+    }
 
     fn inject_connection_established(
         &mut self,
@@ -208,10 +285,17 @@ where
 
     fn inject_connection_closed(
         &mut self,
-        _peer_id: &PeerId,
-        _: &ConnectionId,
+        peer_id: &PeerId,
+        conn_id: &ConnectionId,
         _connected_point: &ConnectedPoint,
     ) {
+        // requests on a closed connection can no longer be answered
+        if let Some(requests) = self.active_inbound_requests.get_mut(peer_id) {
+            requests.retain(|(id, _)| id != conn_id);
+            if requests.is_empty() {
+                self.active_inbound_requests.remove(peer_id);
+            }
+        }
     }
 
     fn inject_event(
@@ -224,6 +308,34 @@ where
             // check if the request is conformant to the quota
             match self.limiter.allows(&peer_id, req) {
                 Ok(()) => {
+                    // requests without a response (i.e goodbye) don't occupy a substream
+                    if req.expected_responses() > 0 {
+                        let active_requests = self
+                            .active_inbound_requests
+                            .entry(peer_id)
+                            .or_insert_with(HashSet::new);
+                        if active_requests.len() >= self.max_concurrent_requests_per_peer {
+                            debug!(self.log, "Request exceeds the concurrency limit";
+                                "request" => %req, "peer_id" => %peer_id,
+                                "active_requests" => active_requests.len());
+                            metrics::inc_counter_vec(
+                                &metrics::RPC_REQUEST_CONCURRENCY_REJECTED,
+                                &[&req.protocol().to_string()],
+                            );
+                            // send an error code to the peer.
+                            // the handler upon receiving the error code will send it back to the behaviour
+                            self.send_response(
+                                peer_id,
+                                (conn_id, *id),
+                                RPCCodedResponse::Error(
+                                    RPCResponseErrorCode::ResourceUnavailable,
+                                    "Too many concurrent requests".into(),
+                                ),
+                            );
+                            return;
+                        }
+                        active_requests.insert((conn_id, *id));
+                    }
                     // send the event to the user
                     self.events
                         .push(NetworkBehaviourAction::GenerateEvent(RPCMessage {
@@ -267,6 +379,10 @@ where
                 }
             }
         } else {
+            if let Err(HandlerErr::Inbound { id, .. }) = event {
+                // the substream of this request has failed, it no longer counts as active
+                self.remove_active_request(&peer_id, (conn_id, id));
+            }
             self.events
                 .push(NetworkBehaviourAction::GenerateEvent(RPCMessage {
                     peer_id,
@@ -104,7 +104,7 @@ impl<TSpec: EthSpec> Service<TSpec> {
 
         let (mut swarm, bandwidth) = {
             // Set up the transport - tcp/ws with noise and mplex
-            let (transport, bandwidth) = build_transport(local_keypair.clone())
+            let (transport, bandwidth) = build_transport(local_keypair.clone(), config)
                 .map_err(|e| format!("Failed to build transport: {:?}", e))?;
 
             // Lighthouse network behaviour
@@ -349,6 +349,7 @@ type BoxedTransport = Boxed<(PeerId, StreamMuxerBox)>;
 /// mplex as the multiplexing layer.
 fn build_transport(
     local_private_key: Keypair,
+    config: &NetworkConfig,
 ) -> std::io::Result<(BoxedTransport, Arc<BandwidthSinks>)> {
     let transport = libp2p::tcp::TokioTcpConfig::new().nodelay(true);
     let transport = libp2p::dns::DnsConfig::new(transport)?;
@@ -365,13 +366,18 @@ fn build_transport(
     mplex_config.set_max_buffer_size(256);
     mplex_config.set_max_buffer_behaviour(libp2p::mplex::MaxBufferBehaviour::Block);
 
+    // yamux config
+    let mut yamux_config = libp2p::yamux::YamuxConfig::default();
+    yamux_config.set_max_buffer_size(config.yamux_max_stream_buffer_size);
+    yamux_config.set_receive_window_size(config.yamux_window_size);
+
     // Authentication
     Ok((
         transport
             .upgrade(core::upgrade::Version::V1)
             .authenticate(generate_noise_config(&local_private_key))
             .multiplex(core::upgrade::SelectUpgrade::new(
-                libp2p::yamux::YamuxConfig::default(),
+                yamux_config,
                 mplex_config,
             ))
             .timeout(Duration::from_secs(10))
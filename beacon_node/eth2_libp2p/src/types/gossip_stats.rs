@@ -0,0 +1,122 @@
+use crate::types::GossipKind;
+use libp2p::gossipsub::MessageAcceptance;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// The number of distinct topics counted by `GossipStats`.
+const NUM_TOPICS: usize = 6;
+
+/// The names of the topics counted by `GossipStats`, indexed by `topic_index`.
+const TOPIC_NAMES: [&str; NUM_TOPICS] = [
+    "beacon_block",
+    "beacon_aggregate_and_proof",
+    "beacon_attestation",
+    "voluntary_exit",
+    "proposer_slashing",
+    "attester_slashing",
+];
+
+/// Counts of the gossip messages seen on a single topic and the outcome of their validation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct GossipMessageCounters {
+    /// Messages received from the network and handed to validation.
+    pub seen: u64,
+    /// Messages which passed validation and were propagated.
+    pub accepted: u64,
+    /// Messages which were invalid and penalised the sender.
+    pub rejected_invalid: u64,
+    /// Messages which were ignored without penalising the sender.
+    pub rejected_ignore: u64,
+}
+
+impl GossipMessageCounters {
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// The atomic equivalent of `GossipMessageCounters`.
+#[derive(Debug, Default)]
+struct AtomicGossipMessageCounters {
+    seen: AtomicU64,
+    accepted: AtomicU64,
+    rejected_invalid: AtomicU64,
+    rejected_ignore: AtomicU64,
+}
+
+impl AtomicGossipMessageCounters {
+    fn load(&self) -> GossipMessageCounters {
+        GossipMessageCounters {
+            seen: self.seen.load(Ordering::Relaxed),
+            accepted: self.accepted.load(Ordering::Relaxed),
+            rejected_invalid: self.rejected_invalid.load(Ordering::Relaxed),
+            rejected_ignore: self.rejected_ignore.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Per-topic gossip message counters.
+///
+/// The counters are atomic so that they can be updated by many beacon processor workers at once
+/// without locking.
+///
+/// Attestation subnets are not counted separately, all subnets share the `beacon_attestation`
+/// counters.
+#[derive(Debug, Default)]
+pub struct GossipStats {
+    topics: [AtomicGossipMessageCounters; NUM_TOPICS],
+}
+
+impl GossipStats {
+    /// Records that a message on the topic for `kind` has been received for validation.
+    pub fn register_seen(&self, kind: &GossipKind) {
+        self.counters(kind).seen.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Records the outcome of validating a message on the topic for `kind`.
+    pub fn register_validation_result(
+        &self,
+        kind: &GossipKind,
+        validation_result: &MessageAcceptance,
+    ) {
+        let counters = self.counters(kind);
+        let counter = match validation_result {
+            MessageAcceptance::Accept => &counters.accepted,
+            MessageAcceptance::Reject => &counters.rejected_invalid,
+            MessageAcceptance::Ignore => &counters.rejected_ignore,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns the counters for the topic for `kind`, if any message has been seen on it.
+    pub fn get(&self, kind: &GossipKind) -> Option<GossipMessageCounters> {
+        Some(self.counters(kind).load()).filter(|counters| !counters.is_empty())
+    }
+
+    /// Returns the counters for every topic on which a message has been seen, keyed by topic name.
+    pub fn topics(&self) -> HashMap<String, GossipMessageCounters> {
+        TOPIC_NAMES
+            .iter()
+            .zip(self.topics.iter())
+            .map(|(name, counters)| (name.to_string(), counters.load()))
+            .filter(|(_, counters)| !counters.is_empty())
+            .collect()
+    }
+
+    fn counters(&self, kind: &GossipKind) -> &AtomicGossipMessageCounters {
+        &self.topics[topic_index(kind)]
+    }
+}
+
+/// Returns the index of the topic for `kind` in `TOPIC_NAMES`.
+fn topic_index(kind: &GossipKind) -> usize {
+    match kind {
+        GossipKind::BeaconBlock => 0,
+        GossipKind::BeaconAggregateAndProof => 1,
+        GossipKind::Attestation(_) => 2,
+        GossipKind::VoluntaryExit => 3,
+        GossipKind::ProposerSlashing => 4,
+        GossipKind::AttesterSlashing => 5,
+    }
+}
@@ -1,5 +1,6 @@
 pub mod error;
 mod globals;
+mod gossip_stats;
 mod pubsub;
 mod subnet;
 mod sync_state;
@@ -13,6 +14,7 @@ pub type EnrBitfield<T: EthSpec> = BitVector<T::SubnetBitfieldLength>;
 pub type Enr = discv5::enr::Enr<discv5::enr::CombinedKey>;
 
 pub use globals::NetworkGlobals;
+pub use gossip_stats::{GossipMessageCounters, GossipStats};
 pub use pubsub::{PubsubMessage, SnappyTransform};
 pub use subnet::SubnetDiscovery;
 pub use sync_state::SyncState;
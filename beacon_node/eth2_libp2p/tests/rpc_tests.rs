@@ -801,3 +801,187 @@ fn test_goodbye_rpc() {
         }
     })
 }
+
+// Tests that requests exceeding the per-peer concurrency limit are rejected
+#[test]
+#[allow(clippy::single_match)]
+fn test_concurrent_rpc_requests_limit() {
+    // set up the logging. The level and enabled logging or not
+    let log_level = Level::Debug;
+    let enable_logging = false;
+
+    // the default value of `max_concurrent_rpc_requests_per_peer`
+    let max_concurrent_requests = 4;
+
+    let log = common::build_log(log_level, enable_logging);
+
+    let rt = Arc::new(Runtime::new().unwrap());
+
+    rt.block_on(async {
+        // get sender/receiver
+        let (mut sender, mut receiver) = common::build_node_pair(Arc::downgrade(&rt), &log).await;
+
+        // BlocksByRange Request
+        let rpc_request = Request::BlocksByRange(BlocksByRangeRequest {
+            start_slot: 0,
+            count: 1,
+            step: 0,
+        });
+
+        // build the sender future
+        let sender_future = async {
+            loop {
+                match sender.next_event().await {
+                    Libp2pEvent::Behaviour(BehaviourEvent::PeerDialed(peer_id)) => {
+                        // Send one more request than the receiver allows at once
+                        debug!(log, "Sending RPCs");
+                        for i in 0..=max_concurrent_requests {
+                            sender.swarm.send_request(
+                                peer_id,
+                                RequestId::Sync(i),
+                                rpc_request.clone(),
+                            );
+                        }
+                    }
+                    Libp2pEvent::Behaviour(BehaviourEvent::RPCFailed {
+                        id: RequestId::Sync(_),
+                        ..
+                    }) => {
+                        // The extra request should be rejected
+                        debug!(log, "Sender request rejected");
+                        return;
+                    }
+                    _ => {} // Ignore other behaviour events
+                }
+            }
+        };
+
+        // build the receiver future
+        let mut requests_received = 0;
+        let receiver_future = async {
+            loop {
+                match receiver.next_event().await {
+                    Libp2pEvent::Behaviour(BehaviourEvent::RequestReceived { request, .. }) => {
+                        if request == rpc_request {
+                            // hold the request open without responding
+                            requests_received += 1;
+                            assert!(requests_received <= max_concurrent_requests);
+                        }
+                    }
+                    _ => {} // Ignore other events
+                }
+            }
+        };
+
+        tokio::select! {
+            _ = sender_future => {}
+            _ = receiver_future => {}
+            _ = sleep(Duration::from_secs(10)) => {
+                panic!("Future timed out");
+            }
+        }
+    })
+}
+
+// Tests that answered single-response requests no longer count towards the concurrency limit
+#[test]
+#[allow(clippy::single_match)]
+fn test_sequential_status_requests_above_concurrency_limit() {
+    // set up the logging. The level and enabled logging or not
+    let log_level = Level::Debug;
+    let enable_logging = false;
+
+    // one more than the default value of `max_concurrent_rpc_requests_per_peer`, but within the
+    // Status rate limit
+    let requests_to_send = 5;
+
+    let log = common::build_log(log_level, enable_logging);
+
+    let rt = Arc::new(Runtime::new().unwrap());
+
+    rt.block_on(async {
+        // get sender/receiver
+        let (mut sender, mut receiver) = common::build_node_pair(Arc::downgrade(&rt), &log).await;
+
+        // Dummy STATUS RPC message
+        let status = StatusMessage {
+            fork_digest: [0; 4],
+            finalized_root: Hash256::from_low_u64_be(0),
+            finalized_epoch: Epoch::new(1),
+            head_root: Hash256::from_low_u64_be(0),
+            head_slot: Slot::new(1),
+        };
+        let rpc_request = Request::Status(status.clone());
+        let rpc_response = Response::Status(status);
+
+        // build the sender future
+        let sender_future = async {
+            let mut responses_received = 0;
+            loop {
+                match sender.next_event().await {
+                    Libp2pEvent::Behaviour(BehaviourEvent::PeerDialed(peer_id)) => {
+                        debug!(log, "Sending RPC");
+                        sender.swarm.send_request(
+                            peer_id,
+                            RequestId::Sync(responses_received),
+                            rpc_request.clone(),
+                        );
+                    }
+                    Libp2pEvent::Behaviour(BehaviourEvent::ResponseReceived {
+                        peer_id,
+                        id: RequestId::Sync(_),
+                        response,
+                    }) => {
+                        assert_eq!(response, rpc_response.clone());
+                        responses_received += 1;
+                        if responses_received == requests_to_send {
+                            debug!(log, "Sender Completed");
+                            return;
+                        }
+                        // only send the next request once the previous one is answered
+                        sender.swarm.send_request(
+                            peer_id,
+                            RequestId::Sync(responses_received),
+                            rpc_request.clone(),
+                        );
+                    }
+                    Libp2pEvent::Behaviour(BehaviourEvent::RPCFailed {
+                        id: RequestId::Sync(_),
+                        ..
+                    }) => panic!("Sequential status request was rejected"),
+                    _ => {}
+                }
+            }
+        };
+
+        // build the receiver future
+        let receiver_future = async {
+            loop {
+                match receiver.next_event().await {
+                    Libp2pEvent::Behaviour(BehaviourEvent::RequestReceived {
+                        peer_id,
+                        id,
+                        request,
+                    }) => {
+                        if request == rpc_request {
+                            receiver.swarm.send_successful_response(
+                                peer_id,
+                                id,
+                                rpc_response.clone(),
+                            );
+                        }
+                    }
+                    _ => {} // Ignore other events
+                }
+            }
+        };
+
+        tokio::select! {
+            _ = sender_future => {}
+            _ = receiver_future => {}
+            _ = sleep(Duration::from_secs(30)) => {
+                panic!("Future timed out");
+            }
+        }
+    })
+}
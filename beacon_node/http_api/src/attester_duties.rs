@@ -1,6 +1,6 @@
 //! Contains the handler for the `GET validator/duties/attester/{epoch}` endpoint.
 
-use crate::state_id::StateId;
+use crate::state_id::checkpoint_state;
 use beacon_chain::{
     BeaconChain, BeaconChainError, BeaconChainTypes, MAXIMUM_GOSSIP_CLOCK_DISPARITY,
 };
@@ -104,7 +104,7 @@ fn compute_historic_attester_duties<T: BeaconChainTypes>(
         )?;
         state
     } else {
-        StateId::slot(request_epoch.start_slot(T::EthSpec::slots_per_epoch())).state(&chain)?
+        checkpoint_state(&chain, request_epoch)?
     };
 
     // Sanity-check the state lookup.
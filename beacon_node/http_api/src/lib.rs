@@ -20,6 +20,7 @@ use beacon_chain::{
     WhenSlotSkipped,
 };
 use block_id::BlockId;
+use eth2::lighthouse::CommitteeAttestationCount;
 use eth2::types::{self as api_types, ValidatorId};
 use eth2_libp2p::{types::SyncState, EnrExt, NetworkGlobals, PeerId, PubsubMessage};
 use lighthouse_version::version_with_platform;
@@ -37,10 +38,12 @@ use std::sync::Arc;
 use tokio::sync::mpsc::UnboundedSender;
 use tokio_stream::{wrappers::BroadcastStream, StreamExt};
 use types::{
-    Attestation, AttesterSlashing, CommitteeCache, Epoch, EthSpec, ProposerSlashing, RelativeEpoch,
-    SignedAggregateAndProof, SignedBeaconBlock, SignedVoluntaryExit, Slot, YamlConfig,
+    Attestation, AttesterSlashing, BeaconState, BeaconStateError, Checkpoint, CommitteeCache,
+    Epoch, EthSpec, ProposerSlashing, RelativeEpoch, SignedAggregateAndProof, SignedBeaconBlock,
+    SignedVoluntaryExit, Slot, Validator, YamlConfig,
 };
 use warp::http::StatusCode;
+use warp::hyper::body::HttpBody;
 use warp::sse::Event;
 use warp::Reply;
 use warp::{http::Response, Filter};
@@ -49,6 +52,9 @@ use warp_utils::task::{blocking_json_task, blocking_task};
 const API_PREFIX: &str = "eth";
 const API_VERSION: &str = "v1";
 
+/// The default value for `Config::max_response_body_size` (2 GiB).
+pub const DEFAULT_MAX_RESPONSE_BODY_SIZE: u64 = 2 * 1024 * 1024 * 1024;
+
 /// If the node is within this many epochs from the head, we declare it to be synced regardless of
 /// the network sync state.
 ///
@@ -75,6 +81,8 @@ pub struct Config {
     pub listen_addr: Ipv4Addr,
     pub listen_port: u16,
     pub allow_origin: Option<String>,
+    /// Responses with a body larger than this many bytes are replaced with a `413` error.
+    pub max_response_body_size: u64,
 }
 
 impl Default for Config {
@@ -84,6 +92,7 @@ impl Default for Config {
             listen_addr: Ipv4Addr::new(127, 0, 0, 1),
             listen_port: 5052,
             allow_origin: None,
+            max_response_body_size: DEFAULT_MAX_RESPONSE_BODY_SIZE,
         }
     }
 }
@@ -237,6 +246,10 @@ pub fn serve<T: BeaconChainTypes>(
     }
 
     let eth1_v1 = warp::path(API_PREFIX).and(warp::path(API_VERSION));
+    let max_response_body_size = config.max_response_body_size;
+
+    // Create a `warp` filter that provides the maximum response body size.
+    let max_response_body_size_filter = warp::any().map(move || max_response_body_size);
 
     // Create a `warp` filter that provides access to the network globals.
     let inner_network_globals = ctx.network_globals.clone();
@@ -466,15 +479,19 @@ pub fn serve<T: BeaconChainTypes>(
         .and(warp::path("validators"))
         .and(warp::query::<api_types::ValidatorsQuery>())
         .and(warp::path::end())
+        .and(max_response_body_size_filter.clone())
         .and_then(
-            |state_id: StateId, chain: Arc<BeaconChain<T>>, query: api_types::ValidatorsQuery| {
+            |state_id: StateId,
+             chain: Arc<BeaconChain<T>>,
+             query: api_types::ValidatorsQuery,
+             max_response_body_size: u64| {
                 blocking_json_task(move || {
                     state_id
                         .map_state(&chain, |state| {
                             let epoch = state.current_epoch();
                             let far_future_epoch = chain.spec.far_future_epoch;
 
-                            Ok(state
+                            let validators = state
                                 .validators
                                 .iter()
                                 .zip(state.balances.iter())
@@ -517,7 +534,16 @@ pub fn serve<T: BeaconChainTypes>(
                                         None
                                     }
                                 })
-                                .collect::<Vec<_>>())
+                                .collect::<Vec<_>>();
+
+                            // The JSON encoding of each validator is larger than its SSZ
+                            // encoding.
+                            check_response_body_size(
+                                validators.len() * <Validator as Encode>::ssz_fixed_len(),
+                                max_response_body_size,
+                            )?;
+
+                            Ok(validators)
                         })
                         .map(api_types::GenericResponse::from)
                 })
@@ -1304,6 +1330,9 @@ pub fn serve<T: BeaconChainTypes>(
      */
 
     // GET debug/beacon/states/{state_id}
+    //
+    // With `?by_block_root=true` the `state_id` must be a block root and the post-state of that
+    // block (which need not be canonical) is returned.
     let get_debug_beacon_states = eth1_v1
         .and(warp::path("debug"))
         .and(warp::path("beacon"))
@@ -1314,32 +1343,87 @@ pub fn serve<T: BeaconChainTypes>(
             ))
         }))
         .and(warp::path::end())
+        .and(warp::query::<api_types::DebugBeaconStateQuery>())
         .and(warp::header::optional::<api_types::Accept>("accept"))
         .and(chain_filter.clone())
+        .and(max_response_body_size_filter.clone())
         .and_then(
             |state_id: StateId,
+             query: api_types::DebugBeaconStateQuery,
              accept_header: Option<api_types::Accept>,
-             chain: Arc<BeaconChain<T>>| {
-                blocking_task(move || match accept_header {
-                    Some(api_types::Accept::Ssz) => {
-                        let state = state_id.state(&chain)?;
-                        Response::builder()
-                            .status(200)
-                            .header("Content-Type", "application/octet-stream")
-                            .body(state.as_ssz_bytes().into())
-                            .map_err(|e| {
-                                warp_utils::reject::custom_server_error(format!(
-                                    "failed to create response: {}",
-                                    e
-                                ))
+             chain: Arc<BeaconChain<T>>,
+             max_response_body_size: u64| {
+                blocking_task(move || {
+                    let by_block_root = if query.by_block_root.unwrap_or(false) {
+                        Some(state_id.as_root().ok_or_else(|| {
+                            warp_utils::reject::custom_bad_request(
+                                "by_block_root requires a block root".to_string(),
+                            )
+                        })?)
+                    } else {
+                        None
+                    };
+                    let state_at_block_root = |block_root| {
+                        chain
+                            .get_state_at_block_root(block_root)
+                            .map_err(|e| match e {
+                                BeaconChainError::MissingBeaconBlock(_) => {
+                                    warp_utils::reject::custom_not_found(format!(
+                                        "beacon block with root {}",
+                                        block_root
+                                    ))
+                                }
+                                e => warp_utils::reject::beacon_chain_error(e),
                             })
+                    };
+
+                    match accept_header {
+                        Some(api_types::Accept::Ssz) => {
+                            let state = if let Some(block_root) = by_block_root {
+                                state_at_block_root(block_root)?
+                            } else {
+                                state_id.state(&chain)?
+                            };
+                            check_response_body_size(
+                                state.ssz_bytes_len(),
+                                max_response_body_size,
+                            )?;
+                            Response::builder()
+                                .status(200)
+                                .header("Content-Type", "application/octet-stream")
+                                .body(state.as_ssz_bytes().into())
+                                .map_err(|e| {
+                                    warp_utils::reject::custom_server_error(format!(
+                                        "failed to create response: {}",
+                                        e
+                                    ))
+                                })
+                        }
+                        _ => {
+                            let json_response = |state: &BeaconState<T::EthSpec>| -> Result<
+                                warp::reply::Response,
+                                warp::Rejection,
+                            > {
+                                // The JSON encoding is always larger than the SSZ encoding, so
+                                // this rejects oversized states before any serialization is
+                                // performed.
+                                check_response_body_size(
+                                    state.ssz_bytes_len(),
+                                    max_response_body_size,
+                                )?;
+                                Ok(
+                                    warp::reply::json(&api_types::GenericResponseRef::from(state))
+                                        .into_response(),
+                                )
+                            };
+
+                            if let Some(block_root) = by_block_root {
+                                json_response(&state_at_block_root(block_root)?)
+                            } else {
+                                state_id.map_state(&chain, json_response)
+                            }
+                        }
                     }
-                    _ => state_id.map_state(&chain, |state| {
-                        Ok(
-                            warp::reply::json(&api_types::GenericResponseRef::from(&state))
-                                .into_response(),
-                        )
-                    }),
                 })
             },
         );
@@ -1770,7 +1854,7 @@ pub fn serve<T: BeaconChainTypes>(
         .and(warp::path("validator"))
         .and(warp::path("aggregate_and_proofs"))
         .and(warp::path::end())
-        .and(not_while_syncing_filter)
+        .and(not_while_syncing_filter.clone())
         .and(chain_filter.clone())
         .and(warp::body::json())
         .and(network_tx_filter.clone())
@@ -1872,7 +1956,7 @@ pub fn serve<T: BeaconChainTypes>(
         .and(warp::path("beacon_committee_subscriptions"))
         .and(warp::path::end())
         .and(warp::body::json())
-        .and(network_tx_filter)
+        .and(network_tx_filter.clone())
         .and(chain_filter.clone())
         .and_then(
             |subscriptions: Vec<api_types::BeaconCommitteeSubscription>,
@@ -1923,13 +2007,27 @@ pub fn serve<T: BeaconChainTypes>(
         .and(warp::path("syncing"))
         .and(warp::path::end())
         .and(network_globals.clone())
-        .and_then(|network_globals: Arc<NetworkGlobals<T::EthSpec>>| {
-            blocking_json_task(move || {
-                Ok(api_types::GenericResponse::from(
-                    network_globals.sync_state(),
-                ))
-            })
-        });
+        .and(chain_filter.clone())
+        .and_then(
+            |network_globals: Arc<NetworkGlobals<T::EthSpec>>, chain: Arc<BeaconChain<T>>| {
+                blocking_json_task(move || {
+                    let head_info = chain
+                        .head_info()
+                        .map_err(warp_utils::reject::beacon_chain_error)?;
+                    let peers = network_globals.peers.read();
+
+                    Ok(api_types::GenericResponse::from(
+                        eth2::lighthouse::SyncingStatus {
+                            sync_state: network_globals.sync_state(),
+                            peers_at_head: peers
+                                .peers_at_head(head_info.slot, head_info.block_root)
+                                .count(),
+                            total_connected_peers: peers.connected_peer_ids().count(),
+                        },
+                    ))
+                })
+            },
+        );
 
     // GET lighthouse/peers
     let get_lighthouse_peers = warp::path("lighthouse")
@@ -1955,7 +2053,7 @@ pub fn serve<T: BeaconChainTypes>(
         .and(warp::path("peers"))
         .and(warp::path("connected"))
         .and(warp::path::end())
-        .and(network_globals)
+        .and(network_globals.clone())
         .and_then(|network_globals: Arc<NetworkGlobals<T::EthSpec>>| {
             blocking_json_task(move || {
                 Ok(network_globals
@@ -1970,6 +2068,46 @@ pub fn serve<T: BeaconChainTypes>(
             })
         });
 
+    // GET lighthouse/sync/checkpoint_peers?epoch,root
+    let get_lighthouse_sync_checkpoint_peers = warp::path("lighthouse")
+        .and(warp::path("sync"))
+        .and(warp::path("checkpoint_peers"))
+        .and(warp::path::end())
+        .and(warp::query::<api_types::CheckpointPeersQuery>())
+        .and(network_globals.clone())
+        .and_then(
+            |query: api_types::CheckpointPeersQuery,
+             network_globals: Arc<NetworkGlobals<T::EthSpec>>| {
+                blocking_json_task(move || {
+                    let checkpoint = Checkpoint {
+                        epoch: query.epoch,
+                        root: query.root,
+                    };
+                    let peers = network_globals
+                        .peers
+                        .read()
+                        .peers_with_finalized_checkpoint(checkpoint)
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>();
+                    Ok(api_types::GenericResponse::from(peers))
+                })
+            },
+        );
+
+    // GET lighthouse/network/gossip_stats
+    let get_lighthouse_network_gossip_stats = warp::path("lighthouse")
+        .and(warp::path("network"))
+        .and(warp::path("gossip_stats"))
+        .and(warp::path::end())
+        .and(network_globals)
+        .and_then(|network_globals: Arc<NetworkGlobals<T::EthSpec>>| {
+            blocking_json_task(move || {
+                Ok(api_types::GenericResponse::from(
+                    network_globals.gossip_stats.topics(),
+                ))
+            })
+        });
+
     // GET lighthouse/proto_array
     let get_lighthouse_proto_array = warp::path("lighthouse")
         .and(warp::path("proto_array"))
@@ -1983,6 +2121,204 @@ pub fn serve<T: BeaconChainTypes>(
             })
         });
 
+    // GET lighthouse/fork_choice/justified_checkpoints?epoch
+    let get_lighthouse_fork_choice_justified_checkpoints = warp::path("lighthouse")
+        .and(warp::path("fork_choice"))
+        .and(warp::path("justified_checkpoints"))
+        .and(warp::path::end())
+        .and(warp::query::<api_types::JustifiedCheckpointsQuery>())
+        .and(chain_filter.clone())
+        .and_then(
+            |query: api_types::JustifiedCheckpointsQuery, chain: Arc<BeaconChain<T>>| {
+                blocking_json_task(move || {
+                    chain
+                        .count_justified_checkpoints_in_epoch(query.epoch)
+                        .map(|count| {
+                            api_types::GenericResponse::from(
+                                eth2::lighthouse::JustifiedCheckpoints {
+                                    epoch: query.epoch,
+                                    count,
+                                },
+                            )
+                        })
+                        .map_err(warp_utils::reject::beacon_chain_error)
+                })
+            },
+        );
+
+    // GET lighthouse/beacon/finality_checkpoints_history?count
+    let get_lighthouse_beacon_finality_checkpoints_history = warp::path("lighthouse")
+        .and(warp::path("beacon"))
+        .and(warp::path("finality_checkpoints_history"))
+        .and(warp::path::end())
+        .and(warp::query::<api_types::FinalityCheckpointsHistoryQuery>())
+        .and(chain_filter.clone())
+        .and_then(
+            |query: api_types::FinalityCheckpointsHistoryQuery, chain: Arc<BeaconChain<T>>| {
+                blocking_json_task(move || {
+                    let checkpoints = chain
+                        .get_finality_checkpoints_history(query.count.unwrap_or(usize::max_value()))
+                        .into_iter()
+                        .map(|(epoch, root)| Checkpoint { epoch, root })
+                        .collect::<Vec<_>>();
+                    Ok(api_types::GenericResponse::from(checkpoints))
+                })
+            },
+        );
+
+    // GET lighthouse/beacon/checkpoint_state_root?epoch
+    let get_lighthouse_beacon_checkpoint_state_root = warp::path("lighthouse")
+        .and(warp::path("beacon"))
+        .and(warp::path("checkpoint_state_root"))
+        .and(warp::path::end())
+        .and(warp::query::<api_types::CheckpointStateRootQuery>())
+        .and(chain_filter.clone())
+        .and_then(
+            |query: api_types::CheckpointStateRootQuery, chain: Arc<BeaconChain<T>>| {
+                blocking_json_task(move || {
+                    chain
+                        .get_checkpoint_state_root(query.epoch)
+                        .map(|root| {
+                            api_types::GenericResponse::from(api_types::RootData::from(root))
+                        })
+                        .map_err(|e| match e {
+                            BeaconChainError::NoStateForSlot(slot) => {
+                                warp_utils::reject::custom_not_found(format!(
+                                    "beacon state at slot {}",
+                                    slot
+                                ))
+                            }
+                            e => warp_utils::reject::beacon_chain_error(e),
+                        })
+                })
+            },
+        );
+
+    // GET lighthouse/beacon/blocks/{block_id}/attesting_validators
+    let get_lighthouse_beacon_block_attesting_validators = warp::path("lighthouse")
+        .and(warp::path("beacon"))
+        .and(warp::path("blocks"))
+        .and(warp::path::param::<BlockId>())
+        .and(warp::path("attesting_validators"))
+        .and(warp::path::end())
+        .and(chain_filter.clone())
+        .and_then(|block_id: BlockId, chain: Arc<BeaconChain<T>>| {
+            blocking_json_task(move || {
+                let block_root = block_id.root(&chain)?;
+
+                chain
+                    .get_attesting_validators_for_block(block_root)
+                    .map(api_types::GenericResponse::from)
+                    .map_err(|e| match e {
+                        BeaconChainError::MissingBeaconBlock(root) => {
+                            warp_utils::reject::custom_not_found(format!(
+                                "beacon block with root {}",
+                                root
+                            ))
+                        }
+                        e => warp_utils::reject::beacon_chain_error(e),
+                    })
+            })
+        });
+
+    // GET lighthouse/beacon/exits_by_epoch/{epoch}
+    let get_lighthouse_beacon_exits_by_epoch = warp::path("lighthouse")
+        .and(warp::path("beacon"))
+        .and(warp::path("exits_by_epoch"))
+        .and(warp::path::param::<Epoch>())
+        .and(warp::path::end())
+        .and(chain_filter.clone())
+        .and_then(|epoch: Epoch, chain: Arc<BeaconChain<T>>| {
+            blocking_json_task(move || {
+                chain
+                    .get_on_chain_exits_for_epoch(epoch)
+                    .map(|exits| {
+                        let exits = exits
+                            .into_iter()
+                            .map(|(validator_index, epoch)| eth2::lighthouse::OnChainExit {
+                                validator_index,
+                                epoch,
+                            })
+                            .collect::<Vec<_>>();
+                        api_types::GenericResponse::from(exits)
+                    })
+                    .map_err(warp_utils::reject::beacon_chain_error)
+            })
+        });
+
+    // GET lighthouse/beacon/proposer_slashings_by_epoch/{epoch}
+    let get_lighthouse_beacon_proposer_slashings_by_epoch = warp::path("lighthouse")
+        .and(warp::path("beacon"))
+        .and(warp::path("proposer_slashings_by_epoch"))
+        .and(warp::path::param::<Epoch>())
+        .and(warp::path::end())
+        .and(chain_filter.clone())
+        .and_then(|epoch: Epoch, chain: Arc<BeaconChain<T>>| {
+            blocking_json_task(move || {
+                chain
+                    .get_proposer_slashings_for_epoch(epoch)
+                    .map(api_types::GenericResponse::from)
+                    .map_err(warp_utils::reject::beacon_chain_error)
+            })
+        });
+
+    // GET lighthouse/beacon/block_roots_range?start,end
+    let get_lighthouse_beacon_block_roots_range = warp::path("lighthouse")
+        .and(warp::path("beacon"))
+        .and(warp::path("block_roots_range"))
+        .and(warp::path::end())
+        .and(warp::query::<api_types::BlockRootsRangeQuery>())
+        .and(chain_filter.clone())
+        .and_then(
+            |query: api_types::BlockRootsRangeQuery, chain: Arc<BeaconChain<T>>| {
+                blocking_json_task(move || {
+                    if query.start > query.end {
+                        return Err(warp_utils::reject::custom_bad_request(format!(
+                            "start slot {} is later than end slot {}",
+                            query.start, query.end
+                        )));
+                    }
+
+                    chain
+                        .get_all_block_roots_in_slot_range(query.start, query.end)
+                        .map(|roots| {
+                            let roots = roots
+                                .into_iter()
+                                .map(|(slot, root)| eth2::lighthouse::SlotBlockRoot { slot, root })
+                                .collect::<Vec<_>>();
+                            api_types::GenericResponse::from(roots)
+                        })
+                        .map_err(|e| match e {
+                            BeaconChainError::BeaconStateError(
+                                BeaconStateError::SlotOutOfBounds,
+                            ) => warp_utils::reject::custom_bad_request(format!(
+                                "start slot {} is older than the block roots of the head state",
+                                query.start
+                            )),
+                            e => warp_utils::reject::beacon_chain_error(e),
+                        })
+                })
+            },
+        );
+
+    // GET lighthouse/beacon/attestation_pool_by_committee
+    let get_lighthouse_beacon_attestation_pool_by_committee = warp::path("lighthouse")
+        .and(warp::path("beacon"))
+        .and(warp::path("attestation_pool_by_committee"))
+        .and(warp::path::end())
+        .and(chain_filter.clone())
+        .and_then(|chain: Arc<BeaconChain<T>>| {
+            blocking_json_task(move || {
+                let mut counts = chain
+                    .get_pending_attestation_count_by_committee()
+                    .into_iter()
+                    .map(|((slot, index), count)| CommitteeAttestationCount { slot, index, count })
+                    .collect::<Vec<_>>();
+                counts.sort_by_key(|count| (count.slot, count.index));
+                Ok(api_types::GenericResponse::from(counts))
+            })
+        });
+
     // GET lighthouse/validator_inclusion/{epoch}/{validator_id}
     let get_lighthouse_validator_inclusion_global = warp::path("lighthouse")
         .and(warp::path("validator_inclusion"))
@@ -2013,6 +2349,98 @@ pub fn serve<T: BeaconChainTypes>(
             })
         });
 
+    // GET lighthouse/validator/{index}/performance?start_epoch,end_epoch
+    let get_lighthouse_validator_performance = warp::path("lighthouse")
+        .and(warp::path("validator"))
+        .and(warp::path::param::<u64>())
+        .and(warp::path("performance"))
+        .and(warp::path::end())
+        .and(warp::query::<api_types::ValidatorPerformanceQuery>())
+        .and(chain_filter.clone())
+        .and_then(
+            |validator_index: u64,
+             query: api_types::ValidatorPerformanceQuery,
+             chain: Arc<BeaconChain<T>>| {
+                blocking_json_task(move || {
+                    if query.start_epoch > query.end_epoch {
+                        return Err(warp_utils::reject::custom_bad_request(format!(
+                            "start epoch {} is later than end epoch {}",
+                            query.start_epoch, query.end_epoch
+                        )));
+                    }
+
+                    chain
+                        .get_validator_performance_summary(
+                            validator_index,
+                            query.start_epoch,
+                            query.end_epoch,
+                        )
+                        .map(api_types::GenericResponse::from)
+                        .map_err(|e| match e {
+                            BeaconChainError::UnmonitoredValidator(index) => {
+                                warp_utils::reject::custom_not_found(format!(
+                                    "validator {} is not monitored",
+                                    index
+                                ))
+                            }
+                            e => warp_utils::reject::beacon_chain_error(e),
+                        })
+                })
+            },
+        );
+
+    // GET lighthouse/validator/{index}/missed_attestation_diagnosis?slot
+    let get_lighthouse_validator_missed_attestation_diagnosis = warp::path("lighthouse")
+        .and(warp::path("validator"))
+        .and(warp::path::param::<u64>())
+        .and(warp::path("missed_attestation_diagnosis"))
+        .and(warp::path::end())
+        .and(warp::query::<api_types::MissedAttestationDiagnosisQuery>())
+        .and(chain_filter.clone())
+        .and_then(
+            |validator_index: u64,
+             query: api_types::MissedAttestationDiagnosisQuery,
+             chain: Arc<BeaconChain<T>>| {
+                blocking_json_task(move || {
+                    chain
+                        .diagnose_missed_attestation(validator_index, query.slot)
+                        .map(api_types::GenericResponse::from)
+                        .map_err(warp_utils::reject::beacon_chain_error)
+                })
+            },
+        );
+
+    // GET lighthouse/validator/attestation_data_batch?slot
+    let get_lighthouse_validator_attestation_data_batch = warp::path("lighthouse")
+        .and(warp::path("validator"))
+        .and(warp::path("attestation_data_batch"))
+        .and(warp::path::end())
+        .and(warp::query::<api_types::AttestationDataBatchQuery>())
+        .and(not_while_syncing_filter.clone())
+        .and(chain_filter.clone())
+        .and_then(
+            |query: api_types::AttestationDataBatchQuery, chain: Arc<BeaconChain<T>>| {
+                blocking_json_task(move || {
+                    let current_slot = chain
+                        .slot()
+                        .map_err(warp_utils::reject::beacon_chain_error)?;
+
+                    // allow a tolerance of one slot to account for clock skew
+                    if query.slot > current_slot + 1 {
+                        return Err(warp_utils::reject::custom_bad_request(format!(
+                            "request slot {} is more than one slot past the current slot {}",
+                            query.slot, current_slot
+                        )));
+                    }
+
+                    chain
+                        .get_attestation_data_for_all_committees(query.slot)
+                        .map(api_types::GenericResponse::from)
+                        .map_err(warp_utils::reject::beacon_chain_error)
+                })
+            },
+        );
+
     // GET lighthouse/eth1/syncing
     let get_lighthouse_eth1_syncing = warp::path("lighthouse")
         .and(warp::path("eth1"))
@@ -2085,6 +2513,35 @@ pub fn serve<T: BeaconChainTypes>(
             })
         });
 
+    // GET lighthouse/eth1/deposit/{index}
+    let get_lighthouse_eth1_deposit = warp::path("lighthouse")
+        .and(warp::path("eth1"))
+        .and(warp::path("deposit"))
+        .and(warp::path::param::<u64>())
+        .and(warp::path::end())
+        .and(chain_filter.clone())
+        .and_then(|index: u64, chain: Arc<BeaconChain<T>>| {
+            blocking_json_task(move || {
+                chain
+                    .get_deposit_contract_call_data(index)
+                    .map(api_types::GenericResponse::from)
+                    .map_err(|e| match e {
+                        BeaconChainError::NoEth1ChainConnection => {
+                            warp_utils::reject::custom_not_found(
+                                "Eth1 sync is disabled. See the --eth1 CLI flag.".to_string(),
+                            )
+                        }
+                        BeaconChainError::UnknownDeposit(index) => {
+                            warp_utils::reject::custom_not_found(format!(
+                                "deposit with index {}",
+                                index
+                            ))
+                        }
+                        e => warp_utils::reject::beacon_chain_error(e),
+                    })
+            })
+        });
+
     // GET lighthouse/beacon/states/{state_id}/ssz
     let get_lighthouse_beacon_states_ssz = warp::path("lighthouse")
         .and(warp::path("beacon"))
@@ -2093,21 +2550,25 @@ pub fn serve<T: BeaconChainTypes>(
         .and(warp::path("ssz"))
         .and(warp::path::end())
         .and(chain_filter.clone())
-        .and_then(|state_id: StateId, chain: Arc<BeaconChain<T>>| {
-            blocking_task(move || {
-                let state = state_id.state(&chain)?;
-                Response::builder()
-                    .status(200)
-                    .header("Content-Type", "application/ssz")
-                    .body(state.as_ssz_bytes())
-                    .map_err(|e| {
-                        warp_utils::reject::custom_server_error(format!(
-                            "failed to create response: {}",
-                            e
-                        ))
-                    })
-            })
-        });
+        .and(max_response_body_size_filter)
+        .and_then(
+            |state_id: StateId, chain: Arc<BeaconChain<T>>, max_response_body_size: u64| {
+                blocking_task(move || {
+                    let state = state_id.state(&chain)?;
+                    check_response_body_size(state.ssz_bytes_len(), max_response_body_size)?;
+                    Response::builder()
+                        .status(200)
+                        .header("Content-Type", "application/ssz")
+                        .body(state.as_ssz_bytes())
+                        .map_err(|e| {
+                            warp_utils::reject::custom_server_error(format!(
+                                "failed to create response: {}",
+                                e
+                            ))
+                        })
+                })
+            },
+        );
 
     // GET lighthouse/staking
     let get_lighthouse_staking = warp::path("lighthouse")
@@ -2128,6 +2589,106 @@ pub fn serve<T: BeaconChainTypes>(
             })
         });
 
+    // POST lighthouse/debug/simulate_block_import
+    let post_lighthouse_debug_simulate_block_import = warp::path("lighthouse")
+        .and(warp::path("debug"))
+        .and(warp::path("simulate_block_import"))
+        .and(warp::path::end())
+        .and(warp::body::json())
+        .and(chain_filter.clone())
+        .and_then(
+            |block: SignedBeaconBlock<T::EthSpec>, chain: Arc<BeaconChain<T>>| {
+                blocking_json_task(move || {
+                    chain
+                        .simulate_block_import(&block)
+                        .map(|result| {
+                            api_types::GenericResponse::from(
+                                eth2::lighthouse::SimulatedBlockImport {
+                                    state_root: result.state_root,
+                                    processing_time_ms: result.processing_time.as_millis() as u64,
+                                    validation_error: result
+                                        .validation_error
+                                        .map(|e| format!("{:?}", e)),
+                                },
+                            )
+                        })
+                        .map_err(|e| match e {
+                            BeaconChainError::MissingBeaconBlock(parent_root) => {
+                                warp_utils::reject::custom_bad_request(format!(
+                                    "unknown parent block {:?}",
+                                    parent_root
+                                ))
+                            }
+                            e => warp_utils::reject::beacon_chain_error(e),
+                        })
+                })
+            },
+        );
+
+    // GET lighthouse/admin/db_stats?include_sizes
+    //
+    // Sizes are only computed with `?include_sizes=true`, since that reads every value in the hot
+    // database.
+    let get_lighthouse_admin_db_stats = warp::path("lighthouse")
+        .and(warp::path("admin"))
+        .and(warp::path("db_stats"))
+        .and(warp::path::end())
+        .and(warp::query::<api_types::DbStatsQuery>())
+        .and(chain_filter.clone())
+        .and_then(
+            |query: api_types::DbStatsQuery, chain: Arc<BeaconChain<T>>| {
+                blocking_json_task(move || {
+                    chain
+                        .list_hot_db_columns(query.include_sizes.unwrap_or(false))
+                        .map(api_types::GenericResponse::from)
+                        .map_err(warp_utils::reject::beacon_chain_error)
+                })
+            },
+        );
+
+    // POST lighthouse/admin/rebroadcast_block/{block_root}
+    let post_lighthouse_admin_rebroadcast_block = warp::path("lighthouse")
+        .and(warp::path("admin"))
+        .and(warp::path("rebroadcast_block"))
+        .and(warp::path::param::<BlockId>())
+        .and(warp::path::end())
+        .and(chain_filter.clone())
+        .and(network_tx_filter.clone())
+        .and(log_filter.clone())
+        .and_then(
+            |block_id: BlockId,
+             chain: Arc<BeaconChain<T>>,
+             network_tx: UnboundedSender<NetworkMessage<T::EthSpec>>,
+             log: Logger| {
+                blocking_json_task(move || {
+                    let block = block_id.block(&chain)?;
+
+                    let finalized_slot = chain
+                        .head_info()
+                        .map_err(warp_utils::reject::beacon_chain_error)?
+                        .finalized_checkpoint
+                        .epoch
+                        .start_slot(T::EthSpec::slots_per_epoch());
+                    if block.slot() < finalized_slot {
+                        return Err(warp_utils::reject::custom_bad_request(format!(
+                            "block at slot {} is prior to finalization at slot {}",
+                            block.slot(),
+                            finalized_slot
+                        )));
+                    }
+
+                    info!(
+                        log,
+                        "Re-broadcasting block from HTTP API";
+                        "root" => ?block.canonical_root(),
+                        "slot" => block.slot(),
+                    );
+
+                    publish_pubsub_message(&network_tx, PubsubMessage::BeaconBlock(Box::new(block)))
+                })
+            },
+        );
+
     let get_events = eth1_v1
         .and(warp::path("events"))
         .and(warp::path::end())
@@ -2153,6 +2714,12 @@ pub fn serve<T: BeaconChainTypes>(
                                 api_types::EventTopic::FinalizedCheckpoint => {
                                     event_handler.subscribe_finalized()
                                 }
+                                api_types::EventTopic::ChainSplitDetected => {
+                                    event_handler.subscribe_chain_split()
+                                }
+                                api_types::EventTopic::ForkChoiceEquivocation => {
+                                    event_handler.subscribe_fork_choice_equivocation()
+                                }
                             };
 
                             receivers.push(BroadcastStream::new(receiver).map(|msg| {
@@ -2226,14 +2793,29 @@ pub fn serve<T: BeaconChainTypes>(
                 .or(get_lighthouse_syncing.boxed())
                 .or(get_lighthouse_peers.boxed())
                 .or(get_lighthouse_peers_connected.boxed())
+                .or(get_lighthouse_sync_checkpoint_peers.boxed())
+                .or(get_lighthouse_network_gossip_stats.boxed())
                 .or(get_lighthouse_proto_array.boxed())
+                .or(get_lighthouse_fork_choice_justified_checkpoints.boxed())
+                .or(get_lighthouse_beacon_finality_checkpoints_history.boxed())
+                .or(get_lighthouse_beacon_checkpoint_state_root.boxed())
+                .or(get_lighthouse_beacon_block_attesting_validators.boxed())
+                .or(get_lighthouse_beacon_exits_by_epoch.boxed())
+                .or(get_lighthouse_beacon_proposer_slashings_by_epoch.boxed())
+                .or(get_lighthouse_beacon_block_roots_range.boxed())
+                .or(get_lighthouse_beacon_attestation_pool_by_committee.boxed())
                 .or(get_lighthouse_validator_inclusion_global.boxed())
                 .or(get_lighthouse_validator_inclusion.boxed())
+                .or(get_lighthouse_validator_performance.boxed())
+                .or(get_lighthouse_validator_missed_attestation_diagnosis.boxed())
+                .or(get_lighthouse_validator_attestation_data_batch.boxed())
                 .or(get_lighthouse_eth1_syncing.boxed())
                 .or(get_lighthouse_eth1_block_cache.boxed())
                 .or(get_lighthouse_eth1_deposit_cache.boxed())
+                .or(get_lighthouse_eth1_deposit.boxed())
                 .or(get_lighthouse_beacon_states_ssz.boxed())
                 .or(get_lighthouse_staking.boxed())
+                .or(get_lighthouse_admin_db_stats.boxed())
                 .or(get_events.boxed()),
         )
         .or(warp::post().and(
@@ -2245,9 +2827,12 @@ pub fn serve<T: BeaconChainTypes>(
                 .or(post_beacon_pool_voluntary_exits.boxed())
                 .or(post_validator_duties_attester.boxed())
                 .or(post_validator_aggregate_and_proofs.boxed())
-                .or(post_validator_beacon_committee_subscriptions.boxed()),
+                .or(post_validator_beacon_committee_subscriptions.boxed())
+                .or(post_lighthouse_debug_simulate_block_import.boxed())
+                .or(post_lighthouse_admin_rebroadcast_block.boxed()),
         ))
         .recover(warp_utils::reject::handle_rejection)
+        .map(move |reply| limit_response_body_size(reply, max_response_body_size))
         .with(slog_logging(log.clone()))
         .with(prometheus_metrics())
         // Add a `Server` header.
@@ -2272,6 +2857,56 @@ pub fn serve<T: BeaconChainTypes>(
     Ok((listening_socket, server))
 }
 
+/// Returns a `413` rejection if a response body of `size` bytes would exceed `max_size`.
+fn check_response_body_size(size: usize, max_size: u64) -> Result<(), warp::Rejection> {
+    if size as u64 > max_size {
+        Err(warp_utils::reject::payload_too_large(format!(
+            "response body of at least {} bytes exceeds the limit of {} bytes",
+            size, max_size
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Replaces a response with a `413` error if its body is larger than `max_size` bytes.
+///
+/// This runs after the body has been produced, so endpoints which may return large bodies should
+/// also use `check_response_body_size` to avoid serializing them at all.
+///
+/// Streaming bodies (e.g., server-sent events) have no known length. They are ended once
+/// `max_size` bytes have been written, dropping the chunk which would exceed the limit.
+fn limit_response_body_size(reply: impl Reply, max_size: u64) -> warp::reply::Response {
+    let response = reply.into_response();
+    match response.body().size_hint().exact() {
+        None => {
+            let (parts, body) = response.into_parts();
+            let mut bytes_written = 0_u64;
+            let limited_body = body.take_while(move |chunk| match chunk {
+                Ok(bytes) => {
+                    bytes_written = bytes_written.saturating_add(bytes.len() as u64);
+                    bytes_written <= max_size
+                }
+                Err(_) => true,
+            });
+            warp::reply::Response::from_parts(parts, warp::hyper::Body::wrap_stream(limited_body))
+        }
+        Some(size) if size > max_size => {
+            let code = StatusCode::PAYLOAD_TOO_LARGE;
+            let json = warp::reply::json(&api_types::ErrorMessage {
+                code: code.as_u16(),
+                message: format!(
+                    "PAYLOAD_TOO_LARGE: response body of {} bytes exceeds the limit of {} bytes",
+                    size, max_size
+                ),
+                stacktraces: vec![],
+            });
+            warp::reply::with_status(json, code).into_response()
+        }
+        _ => response,
+    }
+}
+
 /// Publish a message to the libp2p pubsub network.
 fn publish_pubsub_message<T: EthSpec>(
     network_tx: &UnboundedSender<NetworkMessage<T>>,
@@ -1,6 +1,6 @@
 //! Contains the handler for the `GET validator/duties/proposer/{epoch}` endpoint.
 
-use crate::state_id::StateId;
+use crate::state_id::checkpoint_state;
 use beacon_chain::{
     BeaconChain, BeaconChainError, BeaconChainTypes, MAXIMUM_GOSSIP_CLOCK_DISPARITY,
 };
@@ -185,7 +185,7 @@ fn compute_historic_proposer_duties<T: BeaconChainTypes>(
         ensure_state_is_in_epoch(&mut state, state_root, epoch, &chain.spec)?;
         state
     } else {
-        StateId::slot(epoch.start_slot(T::EthSpec::slots_per_epoch())).state(&chain)?
+        checkpoint_state(&chain, epoch)?
     };
 
     // Ensure the state lookup was correct.
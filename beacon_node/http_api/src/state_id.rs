@@ -1,7 +1,7 @@
-use beacon_chain::{BeaconChain, BeaconChainTypes};
+use beacon_chain::{BeaconChain, BeaconChainError, BeaconChainTypes};
 use eth2::types::StateId as CoreStateId;
 use std::str::FromStr;
-use types::{BeaconState, EthSpec, Fork, Hash256, Slot};
+use types::{BeaconState, Epoch, EthSpec, Fork, Hash256, Slot};
 
 /// Wraps `eth2::types::StateId` and provides common state-access functionality. E.g., reading
 /// states or parts of states from the database.
@@ -16,6 +16,14 @@ impl StateId {
         Self(CoreStateId::Slot(slot))
     }
 
+    /// Returns the root contained in `self`, if any.
+    pub fn as_root(&self) -> Option<Hash256> {
+        match self.0 {
+            CoreStateId::Root(root) => Some(root),
+            _ => None,
+        }
+    }
+
     /// Return the state root identified by `self`.
     pub fn root<T: BeaconChainTypes>(
         &self,
@@ -116,3 +124,16 @@ impl FromStr for StateId {
         CoreStateId::from_str(s).map(Self)
     }
 }
+
+/// Return the canonical state at the start of `epoch`, using the checkpoint state cache of `chain`.
+pub fn checkpoint_state<T: BeaconChainTypes>(
+    chain: &BeaconChain<T>,
+    epoch: Epoch,
+) -> Result<BeaconState<T::EthSpec>, warp::Rejection> {
+    chain.get_checkpoint_state(epoch).map_err(|e| match e {
+        BeaconChainError::NoStateForSlot(slot) => {
+            warp_utils::reject::custom_not_found(format!("beacon state at slot {}", slot))
+        }
+        e => warp_utils::reject::beacon_chain_error(e),
+    })
+}
@@ -11,15 +11,17 @@ use eth2::StatusCode;
 use eth2::{types::*, BeaconNodeHttpClient};
 use eth2_libp2p::{
     rpc::methods::MetaData,
-    types::{EnrBitfield, SyncState},
-    Enr, EnrExt, NetworkGlobals, PeerId,
+    types::{EnrBitfield, GossipEncoding, GossipKind, SyncState},
+    Enr, EnrExt, MessageAcceptance, NetworkGlobals, PeerId, PeerSyncStatus, PubsubMessage,
+    SyncInfo,
 };
 use futures::stream::{Stream, StreamExt};
 use futures::FutureExt;
-use http_api::{Config, Context};
+use http_api::{Config, Context, DEFAULT_MAX_RESPONSE_BODY_SIZE};
 use network::NetworkMessage;
 use sensitive_url::SensitiveUrl;
 use slot_clock::SlotClock;
+use ssz::Encode;
 use state_processing::per_slot_processing;
 use std::convert::TryInto;
 use std::iter::Iterator;
@@ -73,6 +75,10 @@ struct ApiTester {
 
 impl ApiTester {
     pub fn new() -> Self {
+        Self::new_with_max_response_body_size(DEFAULT_MAX_RESPONSE_BODY_SIZE)
+    }
+
+    pub fn new_with_max_response_body_size(max_response_body_size: u64) -> Self {
         let mut harness = BeaconChainHarness::new(
             MainnetEthSpec,
             generate_deterministic_keypairs(VALIDATOR_COUNT),
@@ -172,8 +178,30 @@ impl ApiTester {
             None,
         );
 
+        // Have the peer report our finalized checkpoint in its STATUS.
+        let head_info = chain.head_info().unwrap();
+        network_globals
+            .peers
+            .write()
+            .peer_info_mut(&peer_id)
+            .unwrap()
+            .sync_status = PeerSyncStatus::Synced {
+            info: SyncInfo {
+                head_slot: head_info.slot,
+                head_root: head_info.block_root,
+                finalized_epoch: head_info.finalized_checkpoint.epoch,
+                finalized_root: head_info.finalized_checkpoint.root,
+            },
+        };
+
         *network_globals.sync_state.write() = SyncState::Synced;
 
+        // Record a single valid gossip block to be reported by the gossip stats endpoint.
+        let gossip_stats = &network_globals.gossip_stats;
+        gossip_stats.register_seen(&GossipKind::BeaconBlock);
+        gossip_stats
+            .register_validation_result(&GossipKind::BeaconBlock, &MessageAcceptance::Accept);
+
         let eth1_service =
             eth1::Service::new(eth1::Config::default(), log.clone(), chain.spec.clone());
 
@@ -183,6 +211,7 @@ impl ApiTester {
                 listen_addr: Ipv4Addr::new(127, 0, 0, 1),
                 listen_port: 0,
                 allow_origin: None,
+                max_response_body_size,
             },
             chain: Some(chain.clone()),
             network_tx: Some(network_tx),
@@ -290,6 +319,7 @@ impl ApiTester {
                 listen_addr: Ipv4Addr::new(127, 0, 0, 1),
                 listen_port: 0,
                 allow_origin: None,
+                max_response_body_size: DEFAULT_MAX_RESPONSE_BODY_SIZE,
             },
             chain: Some(chain.clone()),
             network_tx: Some(network_tx),
@@ -1443,6 +1473,88 @@ impl ApiTester {
         self
     }
 
+    pub async fn test_get_debug_beacon_states_by_block_root(self) -> Self {
+        for block_id in self.interesting_block_ids() {
+            let block_root = match self.get_block_root(block_id) {
+                Some(root) => root,
+                None => continue,
+            };
+
+            let result = self
+                .client
+                .get_debug_beacon_states_by_block_root(block_root)
+                .await
+                .unwrap()
+                .map(|res| res.data);
+
+            let mut expected = self.chain.get_block(&block_root).unwrap().map(|block| {
+                self.chain
+                    .get_state(&block.state_root(), Some(block.slot()))
+                    .unwrap()
+                    .unwrap()
+            });
+            expected.as_mut().map(|state| state.drop_all_caches());
+
+            assert_eq!(result, expected, "{:?}", block_id);
+        }
+
+        self
+    }
+
+    pub async fn test_get_debug_beacon_states_too_large(self) -> Self {
+        let state_id = StateId::Head;
+
+        assert_eq!(
+            self.client
+                .get_debug_beacon_states::<E>(state_id)
+                .await
+                .unwrap_err()
+                .status()
+                .map(Into::into),
+            Some(413)
+        );
+        assert_eq!(
+            self.client
+                .get_debug_beacon_states_ssz::<E>(state_id)
+                .await
+                .unwrap_err()
+                .status()
+                .map(Into::into),
+            Some(413)
+        );
+        assert_eq!(
+            self.client
+                .get_lighthouse_beacon_states_ssz::<E>(&state_id)
+                .await
+                .unwrap_err()
+                .status()
+                .map(Into::into),
+            Some(413)
+        );
+        assert_eq!(
+            self.client
+                .get_beacon_states_validators(state_id, None, None)
+                .await
+                .unwrap_err()
+                .status()
+                .map(Into::into),
+            Some(413)
+        );
+
+        // All other responses are also subject to the limit.
+        assert_eq!(
+            self.client
+                .get_beacon_genesis()
+                .await
+                .unwrap_err()
+                .status()
+                .map(Into::into),
+            Some(413)
+        );
+
+        self
+    }
+
     pub async fn test_get_debug_beacon_heads(self) -> Self {
         let result = self
             .client
@@ -2048,7 +2160,35 @@ impl ApiTester {
     }
 
     pub async fn test_get_lighthouse_syncing(self) -> Self {
-        self.client.get_lighthouse_syncing().await.unwrap();
+        let result = self.client.get_lighthouse_syncing().await.unwrap().data;
+
+        // The only peer reports our head in its STATUS.
+        assert_eq!(result.sync_state, SyncState::Synced);
+        assert_eq!(result.peers_at_head, 1);
+        assert_eq!(result.total_connected_peers, 1);
+
+        self
+    }
+
+    pub async fn test_get_lighthouse_network_gossip_stats(self) -> Self {
+        let result = self
+            .client
+            .get_lighthouse_network_gossip_stats()
+            .await
+            .unwrap()
+            .data;
+
+        let expected = eth2::lighthouse::GossipMessageCounters {
+            seen: 1,
+            accepted: 1,
+            rejected_invalid: 0,
+            rejected_ignore: 0,
+        };
+        assert_eq!(result.len(), 1);
+        assert_eq!(
+            result.get(GossipKind::BeaconBlock.as_ref()),
+            Some(&expected)
+        );
 
         self
     }
@@ -2059,6 +2199,169 @@ impl ApiTester {
         self
     }
 
+    pub async fn test_get_lighthouse_fork_choice_justified_checkpoints(self) -> Self {
+        let justified_epoch = self
+            .chain
+            .head_info()
+            .unwrap()
+            .current_justified_checkpoint
+            .epoch;
+
+        for (epoch, expected) in vec![(justified_epoch, 1), (justified_epoch + 2, 0)] {
+            let result = self
+                .client
+                .get_lighthouse_fork_choice_justified_checkpoints(epoch)
+                .await
+                .unwrap()
+                .data;
+
+            assert_eq!(result.epoch, epoch);
+            assert_eq!(result.count, expected);
+            assert_eq!(
+                result.count,
+                self.chain
+                    .count_justified_checkpoints_in_epoch(epoch)
+                    .unwrap()
+            );
+        }
+
+        self
+    }
+
+    pub async fn test_get_lighthouse_beacon_checkpoint_state_root(self) -> Self {
+        let current_epoch = self.chain.epoch().unwrap();
+
+        for epoch in (0..=current_epoch.as_u64()).map(Epoch::new) {
+            let result = self
+                .client
+                .get_lighthouse_beacon_checkpoint_state_root(epoch)
+                .await
+                .unwrap()
+                .map(|res| res.data.root);
+
+            let expected = self
+                .chain
+                .state_root_at_slot(epoch.start_slot(E::slots_per_epoch()))
+                .unwrap();
+
+            assert_eq!(result, expected, "{:?}", epoch);
+        }
+
+        // There is no checkpoint state for a future epoch.
+        assert_eq!(
+            self.client
+                .get_lighthouse_beacon_checkpoint_state_root(current_epoch + 100)
+                .await
+                .unwrap(),
+            None
+        );
+
+        self
+    }
+
+    pub async fn test_get_lighthouse_beacon_finality_checkpoints_history(self) -> Self {
+        let expected = self
+            .chain
+            .get_finality_checkpoints_history(usize::max_value())
+            .into_iter()
+            .map(|(epoch, root)| Checkpoint { epoch, root })
+            .collect::<Vec<_>>();
+
+        let result = self
+            .client
+            .get_lighthouse_beacon_finality_checkpoints_history(None)
+            .await
+            .unwrap()
+            .data;
+        assert_eq!(result, expected);
+
+        let result = self
+            .client
+            .get_lighthouse_beacon_finality_checkpoints_history(Some(1))
+            .await
+            .unwrap()
+            .data;
+        assert_eq!(
+            result,
+            expected[expected.len().saturating_sub(1)..].to_vec()
+        );
+
+        self
+    }
+
+    pub async fn test_get_lighthouse_beacon_exits_by_epoch(self) -> Self {
+        for epoch in 0..=self.chain.epoch().unwrap().as_u64() {
+            let epoch = Epoch::new(epoch);
+            let result = self
+                .client
+                .get_lighthouse_beacon_exits_by_epoch(epoch)
+                .await
+                .unwrap()
+                .data
+                .into_iter()
+                .map(|exit| (exit.validator_index, exit.epoch))
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                result,
+                self.chain.get_on_chain_exits_for_epoch(epoch).unwrap()
+            );
+        }
+
+        self
+    }
+
+    pub async fn test_get_lighthouse_beacon_proposer_slashings_by_epoch(self) -> Self {
+        for epoch in 0..=self.chain.epoch().unwrap().as_u64() {
+            let epoch = Epoch::new(epoch);
+            let result = self
+                .client
+                .get_lighthouse_beacon_proposer_slashings_by_epoch(epoch)
+                .await
+                .unwrap()
+                .data;
+
+            assert_eq!(
+                result,
+                self.chain.get_proposer_slashings_for_epoch(epoch).unwrap()
+            );
+        }
+
+        self
+    }
+
+    pub async fn test_get_lighthouse_beacon_block_roots_range(self) -> Self {
+        let head_slot = self.chain.head_info().unwrap().slot;
+        let start = head_slot.saturating_sub(E::slots_per_epoch());
+
+        let result = self
+            .client
+            .get_lighthouse_beacon_block_roots_range(start, head_slot)
+            .await
+            .unwrap()
+            .data
+            .into_iter()
+            .map(|block_root| (block_root.slot, block_root.root))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            result,
+            self.chain
+                .get_all_block_roots_in_slot_range(start, head_slot)
+                .unwrap()
+        );
+
+        // The start of the range must not be after the end.
+        let err = self
+            .client
+            .get_lighthouse_beacon_block_roots_range(head_slot, start)
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), Some(StatusCode::BAD_REQUEST));
+
+        self
+    }
+
     pub async fn test_get_lighthouse_validator_inclusion_global(self) -> Self {
         let epoch = self.chain.epoch().unwrap() - 1;
         self.client
@@ -2069,6 +2372,32 @@ impl ApiTester {
         self
     }
 
+    pub async fn test_get_lighthouse_beacon_attestation_pool_by_committee(self) -> Self {
+        let result = self
+            .client
+            .get_lighthouse_beacon_attestation_pool_by_committee()
+            .await
+            .unwrap()
+            .data;
+
+        let mut expected = self
+            .chain
+            .get_pending_attestation_count_by_committee()
+            .into_iter()
+            .collect::<Vec<_>>();
+        expected.sort();
+
+        assert_eq!(
+            result
+                .into_iter()
+                .map(|count| ((count.slot, count.index), count.count))
+                .collect::<Vec<_>>(),
+            expected
+        );
+
+        self
+    }
+
     pub async fn test_get_lighthouse_validator_inclusion(self) -> Self {
         let epoch = self.chain.epoch().unwrap() - 1;
         self.client
@@ -2079,6 +2408,156 @@ impl ApiTester {
         self
     }
 
+    pub async fn test_get_lighthouse_validator_performance(self) -> Self {
+        let end_epoch = self.chain.epoch().unwrap();
+        let start_epoch = end_epoch.saturating_sub(1_u64);
+
+        // The validator is not monitored.
+        assert_eq!(
+            self.client
+                .get_lighthouse_validator_performance(0, start_epoch, end_epoch)
+                .await
+                .unwrap(),
+            None
+        );
+
+        self.chain
+            .validator_monitor
+            .write()
+            .auto_register_local_validator(0);
+
+        let result = self
+            .client
+            .get_lighthouse_validator_performance(0, start_epoch, end_epoch)
+            .await
+            .unwrap()
+            .unwrap()
+            .data;
+
+        let expected = self
+            .chain
+            .get_validator_performance_summary(0, start_epoch, end_epoch)
+            .unwrap();
+
+        assert_eq!(result, expected);
+
+        // The start of the range must not be after the end.
+        let err = self
+            .client
+            .get_lighthouse_validator_performance(0, end_epoch, start_epoch)
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), Some(StatusCode::BAD_REQUEST));
+
+        self
+    }
+
+    pub async fn test_get_lighthouse_validator_missed_attestation_diagnosis(self) -> Self {
+        let epoch = self.chain.epoch().unwrap() - 1;
+        let head_block_root = self.chain.head_beacon_block_root().unwrap();
+        let (duties, _) = self
+            .chain
+            .validator_attestation_duties(&[0], epoch, head_block_root)
+            .unwrap();
+        let slot = duties[0].unwrap().slot;
+
+        let result = self
+            .client
+            .get_lighthouse_validator_missed_attestation_diagnosis(0, slot)
+            .await
+            .unwrap()
+            .data;
+
+        let expected = self.chain.diagnose_missed_attestation(0, slot).unwrap();
+
+        assert_eq!(result, expected);
+
+        self
+    }
+
+    pub async fn test_get_lighthouse_validator_attestation_data_batch(self) -> Self {
+        let mut state = self.chain.head_beacon_state().unwrap();
+        let slot = state.slot;
+        state
+            .build_committee_cache(RelativeEpoch::Current, &self.chain.spec)
+            .unwrap();
+
+        let result = self
+            .client
+            .get_lighthouse_validator_attestation_data_batch(slot)
+            .await
+            .unwrap()
+            .data;
+
+        let committee_count = state.get_committee_count_at_slot(slot).unwrap();
+        assert_eq!(result.len() as u64, committee_count);
+
+        for index in 0..committee_count {
+            let expected = self
+                .chain
+                .produce_unaggregated_attestation(slot, index)
+                .unwrap()
+                .data;
+
+            assert_eq!(result[&index], expected);
+        }
+
+        self
+    }
+
+    pub async fn test_get_lighthouse_beacon_block_attesting_validators(self) -> Self {
+        let block_root = self.chain.head_beacon_block_root().unwrap();
+
+        let result = self
+            .client
+            .get_lighthouse_beacon_block_attesting_validators(BlockId::Root(block_root))
+            .await
+            .unwrap()
+            .unwrap()
+            .data;
+
+        let expected = self
+            .chain
+            .get_attesting_validators_for_block(block_root)
+            .unwrap();
+
+        assert_eq!(result, expected);
+
+        assert!(self
+            .client
+            .get_lighthouse_beacon_block_attesting_validators(BlockId::Root(Hash256::repeat_byte(
+                42
+            )))
+            .await
+            .unwrap()
+            .is_none());
+
+        self
+    }
+
+    pub async fn test_get_lighthouse_admin_db_stats(self) -> Self {
+        for &include_sizes in &[false, true] {
+            let result = self
+                .client
+                .get_lighthouse_admin_db_stats(include_sizes)
+                .await
+                .unwrap()
+                .data;
+
+            let expected = self.chain.list_hot_db_columns(include_sizes).unwrap();
+
+            assert_eq!(result, expected);
+            assert!(result
+                .iter()
+                .any(|column| column.column_name == "BeaconBlock" && column.key_count > 0));
+            assert!(result
+                .iter()
+                .all(|column| column.approximate_size_bytes.is_some() == include_sizes));
+        }
+
+        self
+    }
+
     pub async fn test_get_lighthouse_eth1_syncing(self) -> Self {
         self.client.get_lighthouse_eth1_syncing().await.unwrap();
 
@@ -2105,6 +2584,16 @@ impl ApiTester {
         self
     }
 
+    pub async fn test_get_lighthouse_eth1_deposit(self) -> Self {
+        // The test harness uses the dummy eth1 backend, which never has deposits.
+        assert_eq!(
+            self.client.get_lighthouse_eth1_deposit(0).await.unwrap(),
+            None
+        );
+
+        self
+    }
+
     pub async fn test_get_lighthouse_beacon_states_ssz(self) -> Self {
         for state_id in self.interesting_state_ids() {
             let result = self
@@ -2130,6 +2619,128 @@ impl ApiTester {
         self
     }
 
+    pub async fn test_get_lighthouse_sync_checkpoint_peers(self) -> Self {
+        let finalized_checkpoint = self.chain.head_info().unwrap().finalized_checkpoint;
+
+        let peers = self
+            .client
+            .get_lighthouse_sync_checkpoint_peers(finalized_checkpoint)
+            .await
+            .unwrap()
+            .data;
+        assert_eq!(peers, vec![self.external_peer_id.to_string()]);
+
+        let other_checkpoint = Checkpoint {
+            epoch: finalized_checkpoint.epoch,
+            root: Hash256::repeat_byte(42),
+        };
+        let peers = self
+            .client
+            .get_lighthouse_sync_checkpoint_peers(other_checkpoint)
+            .await
+            .unwrap()
+            .data;
+        assert!(peers.is_empty());
+
+        self
+    }
+
+    pub async fn test_post_lighthouse_debug_simulate_block_import(self) -> Self {
+        let result = self
+            .client
+            .post_lighthouse_debug_simulate_block_import(&self.next_block)
+            .await
+            .unwrap()
+            .data;
+        assert_eq!(result.state_root, Some(self.next_block.state_root()));
+        assert_eq!(result.validation_error, None);
+
+        // The block should not have been imported.
+        assert!(self
+            .chain
+            .get_block(&self.next_block.canonical_root())
+            .unwrap()
+            .is_none());
+
+        let mut invalid_block = self.next_block.clone();
+        invalid_block.message.proposer_index += 1;
+        let result = self
+            .client
+            .post_lighthouse_debug_simulate_block_import(&invalid_block)
+            .await
+            .unwrap()
+            .data;
+        assert_eq!(result.state_root, None);
+        assert!(result.validation_error.is_some());
+
+        let mut orphan_block = self.next_block.clone();
+        orphan_block.message.parent_root = Hash256::repeat_byte(42);
+        assert_eq!(
+            self.client
+                .post_lighthouse_debug_simulate_block_import(&orphan_block)
+                .await
+                .unwrap_err()
+                .status()
+                .map(Into::into),
+            Some(400)
+        );
+
+        self
+    }
+
+    pub async fn test_post_lighthouse_admin_rebroadcast_block(mut self) -> Self {
+        let head_block = self.chain.head_beacon_block().unwrap();
+
+        self.client
+            .post_lighthouse_admin_rebroadcast_block(head_block.canonical_root())
+            .await
+            .unwrap();
+
+        match self.network_rx.recv().await {
+            Some(NetworkMessage::Publish { messages }) => {
+                assert_eq!(
+                    messages,
+                    vec![PubsubMessage::BeaconBlock(Box::new(head_block.clone()))]
+                );
+                assert_eq!(messages[0].kind(), GossipKind::BeaconBlock);
+                assert_eq!(
+                    messages[0].encode(GossipEncoding::default()),
+                    head_block.as_ssz_bytes()
+                );
+            }
+            other => panic!("block should be published to the network, got {:?}", other),
+        }
+
+        // Blocks prior to finalization should not be re-broadcast.
+        assert_eq!(
+            self.client
+                .post_lighthouse_admin_rebroadcast_block(self.chain.genesis_block_root)
+                .await
+                .unwrap_err()
+                .status()
+                .map(Into::into),
+            Some(400)
+        );
+
+        // Unknown blocks should not be re-broadcast.
+        assert_eq!(
+            self.client
+                .post_lighthouse_admin_rebroadcast_block(Hash256::repeat_byte(42))
+                .await
+                .unwrap_err()
+                .status()
+                .map(Into::into),
+            Some(404)
+        );
+
+        assert!(
+            self.network_rx.recv().now_or_never().is_none(),
+            "no further messages should be published"
+        );
+
+        self
+    }
+
     pub async fn test_get_events(self) -> Self {
         // Subscribe to all events
         let topics = vec![
@@ -2236,6 +2847,26 @@ impl ApiTester {
         self
     }
 
+    pub async fn test_get_events_too_large(self) -> Self {
+        let topics = vec![EventTopic::Block];
+        let mut events_future = self
+            .client
+            .get_events::<E>(topics.as_slice())
+            .await
+            .unwrap();
+
+        // The block is imported even though its response body is over the limit.
+        let _ = self.client.post_beacon_blocks(&self.next_block).await;
+
+        // The first event is larger than the limit, so the stream is ended instead.
+        let next_event = tokio::time::timeout(Duration::from_secs(10), events_future.next())
+            .await
+            .expect("event stream should end");
+        assert!(next_event.is_none());
+
+        self
+    }
+
     pub async fn test_get_events_from_genesis(self) -> Self {
         let topics = vec![EventTopic::Block, EventTopic::Head];
         let mut events_future = self
@@ -2302,6 +2933,13 @@ async fn get_events() {
     ApiTester::new().test_get_events().await;
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn get_events_too_large() {
+    ApiTester::new_with_max_response_body_size(1)
+        .test_get_events_too_large()
+        .await;
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn get_events_from_genesis() {
     ApiTester::new_from_genesis()
@@ -2432,10 +3070,19 @@ async fn debug_get() {
     ApiTester::new()
         .test_get_debug_beacon_states()
         .await
+        .test_get_debug_beacon_states_by_block_root()
+        .await
         .test_get_debug_beacon_heads()
         .await;
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn debug_get_response_too_large() {
+    ApiTester::new_with_max_response_body_size(1)
+        .test_get_debug_beacon_states_too_large()
+        .await;
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn node_get() {
     ApiTester::new()
@@ -2571,20 +3218,54 @@ async fn lighthouse_endpoints() {
         .await
         .test_get_lighthouse_syncing()
         .await
+        .test_get_lighthouse_network_gossip_stats()
+        .await
         .test_get_lighthouse_proto_array()
         .await
+        .test_get_lighthouse_fork_choice_justified_checkpoints()
+        .await
+        .test_get_lighthouse_beacon_finality_checkpoints_history()
+        .await
+        .test_get_lighthouse_beacon_checkpoint_state_root()
+        .await
+        .test_get_lighthouse_beacon_exits_by_epoch()
+        .await
+        .test_get_lighthouse_beacon_proposer_slashings_by_epoch()
+        .await
+        .test_get_lighthouse_beacon_block_roots_range()
+        .await
+        .test_get_lighthouse_beacon_attestation_pool_by_committee()
+        .await
         .test_get_lighthouse_validator_inclusion()
         .await
         .test_get_lighthouse_validator_inclusion_global()
         .await
+        .test_get_lighthouse_validator_missed_attestation_diagnosis()
+        .await
+        .test_get_lighthouse_validator_performance()
+        .await
+        .test_get_lighthouse_validator_attestation_data_batch()
+        .await
+        .test_get_lighthouse_beacon_block_attesting_validators()
+        .await
         .test_get_lighthouse_eth1_syncing()
         .await
         .test_get_lighthouse_eth1_block_cache()
         .await
         .test_get_lighthouse_eth1_deposit_cache()
         .await
+        .test_get_lighthouse_eth1_deposit()
+        .await
         .test_get_lighthouse_beacon_states_ssz()
         .await
         .test_get_lighthouse_staking()
+        .await
+        .test_get_lighthouse_admin_db_stats()
+        .await
+        .test_get_lighthouse_sync_checkpoint_peers()
+        .await
+        .test_post_lighthouse_debug_simulate_block_import()
+        .await
+        .test_post_lighthouse_admin_rebroadcast_block()
         .await;
 }
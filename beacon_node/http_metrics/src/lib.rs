@@ -11,7 +11,7 @@ use std::future::Future;
 use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
 use std::path::PathBuf;
 use std::sync::Arc;
-use warp::{http::Response, Filter};
+use warp::{filters::BoxedFilter, http::Response, Filter};
 
 #[derive(Debug)]
 pub enum Error {
@@ -50,6 +50,8 @@ pub struct Config {
     pub listen_port: u16,
     pub allow_origin: Option<String>,
     pub allocator_metrics_enabled: bool,
+    /// If `Some`, requests must provide this token in an `Authorization: Bearer <token>` header.
+    pub auth_token: Option<String>,
 }
 
 impl Default for Config {
@@ -60,6 +62,7 @@ impl Default for Config {
             listen_port: 5054,
             allow_origin: None,
             allocator_metrics_enabled: true,
+            auth_token: None,
         }
     }
 }
@@ -90,7 +93,7 @@ pub fn serve<T: BeaconChainTypes>(
     let cors_builder = {
         let builder = warp::cors()
             .allow_method("GET")
-            .allow_headers(vec!["Content-Type"]);
+            .allow_headers(vec!["Content-Type", "Authorization"]);
 
         warp_utils::cors::set_builder_origins(
             builder,
@@ -110,6 +113,7 @@ pub fn serve<T: BeaconChainTypes>(
     let inner_ctx = ctx.clone();
     let routes = warp::get()
         .and(warp::path("metrics"))
+        .and(authorization_filter(config.auth_token.clone()))
         .map(move || inner_ctx.clone())
         .and_then(|ctx: Arc<Context<T>>| async move {
             Ok::<_, warp::Rejection>(
@@ -124,6 +128,7 @@ pub fn serve<T: BeaconChainTypes>(
                     }),
             )
         })
+        .recover(handle_unauthorized)
         // Add a `Server` header.
         .map(|reply| warp::reply::with_header(reply, "Server", &version_with_platform()))
         .with(cors_builder.build());
@@ -143,3 +148,64 @@ pub fn serve<T: BeaconChainTypes>(
 
     Ok((listening_socket, server))
 }
+
+/// Rejects requests which do not satisfy `auth_token`, see `is_authorized`.
+fn authorization_filter(auth_token: Option<String>) -> BoxedFilter<()> {
+    warp::any()
+        .map(move || auth_token.clone())
+        .and(warp::header::optional::<String>("Authorization"))
+        .and_then(
+            |auth_token: Option<String>, authorization: Option<String>| async move {
+                if is_authorized(auth_token.as_deref(), authorization.as_deref()) {
+                    Ok(())
+                } else {
+                    Err(warp::reject::custom(Unauthorized))
+                }
+            },
+        )
+        .untuple_one()
+        .boxed()
+}
+
+/// The rejection produced by `authorization_filter`.
+#[derive(Debug)]
+struct Unauthorized;
+
+impl warp::reject::Reject for Unauthorized {}
+
+/// Responds to an `Unauthorized` rejection with a `401` error, leaving all other rejections to
+/// the default handler.
+async fn handle_unauthorized(err: warp::Rejection) -> Result<Response<String>, warp::Rejection> {
+    if err.find::<Unauthorized>().is_some() {
+        Ok(Response::builder()
+            .status(401)
+            .header("WWW-Authenticate", "Bearer realm=\"metrics\"")
+            .header("Content-Type", "text/plain")
+            .body("Missing or invalid metrics auth token".to_string())
+            .unwrap())
+    } else {
+        Err(err)
+    }
+}
+
+/// Returns `true` if `authorization` (the value of the `Authorization` header) satisfies
+/// `auth_token`.
+///
+/// All requests are authorized if there is no `auth_token`. The token is compared in constant
+/// time so that response times do not reveal how much of it a request got right.
+fn is_authorized(auth_token: Option<&str>, authorization: Option<&str>) -> bool {
+    match auth_token {
+        Some(token) => {
+            let expected = format!("Bearer {}", token);
+            authorization.map_or(false, |authorization| {
+                constant_time_eq(authorization.as_bytes(), expected.as_bytes())
+            })
+        }
+        None => true,
+    }
+}
+
+/// Returns `true` if `a == b`, taking the same time for any two slices of the same length.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
@@ -21,6 +21,7 @@ async fn returns_200_ok() {
                 listen_port: 0,
                 allow_origin: None,
                 allocator_metrics_enabled: true,
+                auth_token: None,
             },
             chain: None,
             db_path: None,
@@ -48,3 +49,56 @@ async fn returns_200_ok() {
     }
     .await
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn requires_auth_token() {
+    async {
+        let log = null_logger().unwrap();
+
+        let context = Arc::new(Context {
+            config: Config {
+                enabled: true,
+                listen_addr: Ipv4Addr::new(127, 0, 0, 1),
+                listen_port: 0,
+                allow_origin: None,
+                allocator_metrics_enabled: true,
+                auth_token: Some("secret".to_string()),
+            },
+            chain: None,
+            db_path: None,
+            freezer_db_path: None,
+            log,
+        });
+
+        let ctx = context.clone();
+        let (_shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
+        let server_shutdown = async {
+            // It's not really interesting why this triggered, just that it happened.
+            let _ = shutdown_rx.await;
+        };
+        let (listening_socket, server) = http_metrics::serve(ctx, server_shutdown).unwrap();
+
+        tokio::spawn(async { server.await });
+
+        let url = format!(
+            "http://{}:{}/metrics",
+            listening_socket.ip(),
+            listening_socket.port()
+        );
+        let client = reqwest::Client::new();
+
+        let response = client.get(&url).send().await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            response.headers().get("WWW-Authenticate").unwrap(),
+            "Bearer realm=\"metrics\""
+        );
+
+        let response = client.get(&url).bearer_auth("wrong").send().await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let response = client.get(&url).bearer_auth("secret").send().await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+    .await
+}
@@ -8,6 +8,7 @@
 //! There is the edge-case where the slot arrives before this queue manages to process it. In that
 //! case, the block will be sent off for immediate processing (skipping the `DelayQueue`).
 use super::MAX_DELAYED_BLOCK_QUEUE_LEN;
+use crate::metrics;
 use beacon_chain::{BeaconChainTypes, GossipVerifiedBlock};
 use eth2_libp2p::PeerId;
 use futures::stream::{Stream, StreamExt};
@@ -135,11 +136,16 @@ pub fn spawn_block_delay_queue<T: BeaconChainTypes>(
                                 "queue_size" => MAXIMUM_QUEUED_BLOCKS,
                                 "msg" => "check system clock"
                             );
+                            metrics::inc_counter(&metrics::BEACON_REPROCESS_QUEUE_OVERFLOW_TOTAL);
                             // Drop the block.
                             continue;
                         }
 
                         queued_block_roots.insert(block_root);
+                        metrics::set_gauge(
+                            &metrics::BEACON_REPROCESS_QUEUE_LENGTH,
+                            queued_block_roots.len() as i64,
+                        );
                         // Queue the block until the start of the appropriate slot, plus
                         // `ADDITIONAL_DELAY`.
                         inbound_events
@@ -179,6 +185,10 @@ pub fn spawn_block_delay_queue<T: BeaconChainTypes>(
                             "block_root" => ?block_root
                         );
                     }
+                    metrics::set_gauge(
+                        &metrics::BEACON_REPROCESS_QUEUE_LENGTH,
+                        queued_block_roots.len() as i64,
+                    );
 
                     if ready_blocks_tx.try_send(ready_block).is_err() {
                         error!(
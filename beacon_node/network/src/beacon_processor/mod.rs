@@ -54,8 +54,8 @@ use std::time::{Duration, Instant};
 use task_executor::TaskExecutor;
 use tokio::sync::{mpsc, oneshot};
 use types::{
-    Attestation, AttesterSlashing, Hash256, ProposerSlashing, SignedAggregateAndProof,
-    SignedBeaconBlock, SignedVoluntaryExit, SubnetId,
+    Attestation, AttesterSlashing, EthSpec, Hash256, ProposerSlashing, SignedAggregateAndProof,
+    SignedBeaconBlock, SignedVoluntaryExit, Slot, SubnetId,
 };
 
 use worker::{Toolbox, Worker};
@@ -121,10 +121,37 @@ const MAX_STATUS_QUEUE_LEN: usize = 1_024;
 /// will be stored before we start dropping them.
 const MAX_BLOCKS_BY_RANGE_QUEUE_LEN: usize = 1_024;
 
+/// The maximum number of queued low-priority `BlocksByRangeRequest` objects (i.e., requests for
+/// historical ranges) that will be stored before we start dropping them.
+const MAX_LOW_PRIORITY_BLOCKS_BY_RANGE_QUEUE_LEN: usize = 4_096;
+
+/// `BlocksByRangeRequest`s which start within this many epochs of our head are `High` priority.
+const HIGH_PRIORITY_RANGE_EPOCHS: u64 = 2;
+
+/// `BlocksByRangeRequest`s which start within this many epochs of our head are `Normal` priority.
+/// Anything older is `Low` priority.
+const NORMAL_PRIORITY_RANGE_EPOCHS: u64 = 8;
+
 /// The maximum number of queued `BlocksByRootRequest` objects received from the network RPC that
 /// will be stored before we start dropping them.
 const MAX_BLOCKS_BY_ROOTS_QUEUE_LEN: usize = 1_024;
 
+/// Once this many work items are queued, only work which is estimated to be cheaper than the
+/// admission cost threshold will be accepted (if a threshold is configured).
+const ADMISSION_CONTROL_QUEUE_LEN: usize = MAX_WORK_EVENT_QUEUE_LEN * 3 / 4;
+
+/// The estimated time to process a gossip attestation, aggregate, exit or slashing.
+const GOSSIP_OPERATION_PROCESSING_COST: Duration = Duration::from_millis(1);
+
+/// The estimated time to process a single block.
+const BLOCK_PROCESSING_COST: Duration = Duration::from_millis(100);
+
+/// The estimated time to process each block in a chain segment.
+const CHAIN_SEGMENT_BLOCK_PROCESSING_COST: Duration = Duration::from_millis(500);
+
+/// The estimated time to process a request from the RPC network.
+const RPC_REQUEST_PROCESSING_COST: Duration = Duration::from_millis(1);
+
 /// The name of the manager tokio task.
 const MANAGER_TASK_NAME: &str = "beacon_processor_manager";
 /// The name of the worker tokio tasks.
@@ -153,6 +180,14 @@ pub const BLOCKS_BY_ROOTS_REQUEST: &str = "blocks_by_roots_request";
 pub type BlockResultSender<E> = oneshot::Sender<Result<Hash256, BlockError<E>>>;
 pub type BlockResultReceiver<E> = oneshot::Receiver<Result<Hash256, BlockError<E>>>;
 
+/// Record that an item of `work_type` was dropped because its work queue was full.
+///
+/// The item will never be started by a worker, so it no longer counts towards the queue depth.
+fn record_dropped_work(work_type: &str) {
+    metrics::dec_gauge_vec(&metrics::BEACON_PROCESSOR_QUEUE_DEPTH, &[work_type]);
+    metrics::inc_counter_vec(&metrics::BEACON_PROCESSOR_DROPPED_WORK_TOTAL, &[work_type]);
+}
+
 /// A simple first-in-first-out queue with a maximum length.
 struct FifoQueue<T> {
     queue: VecDeque<T>,
@@ -173,6 +208,7 @@ impl<T> FifoQueue<T> {
     /// Drops `item` if the queue is full.
     pub fn push(&mut self, item: T, item_desc: &str, log: &Logger) {
         if self.queue.len() == self.max_length {
+            record_dropped_work(item_desc);
             error!(
                 log,
                 "Work queue is full";
@@ -214,9 +250,10 @@ impl<T> LifoQueue<T> {
     /// Add a new item to the front of the queue.
     ///
     /// If the queue is full, the item at the back of the queue is dropped.
-    pub fn push(&mut self, item: T) {
+    pub fn push(&mut self, item: T, item_desc: &str) {
         if self.queue.len() == self.max_length {
             self.queue.pop_back();
+            record_dropped_work(item_desc);
         }
         self.queue.push_front(item);
     }
@@ -237,10 +274,91 @@ impl<T> LifoQueue<T> {
     }
 }
 
+/// The priority with which a `BlocksByRangeRequest` is served, determined by how far the start of
+/// the requested range is behind the current slot.
+///
+/// Peers syncing close to the head are more time-sensitive than peers downloading historical
+/// blocks, so we serve them first.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RangeRequestPriority {
+    High,
+    Normal,
+    Low,
+}
+
+impl RangeRequestPriority {
+    /// Determine the priority of a request starting at `start_slot`, given the `current_slot`.
+    pub fn from_start_slot(start_slot: Slot, current_slot: Slot, slots_per_epoch: u64) -> Self {
+        let distance = current_slot.saturating_sub(start_slot).as_u64();
+
+        if distance <= HIGH_PRIORITY_RANGE_EPOCHS * slots_per_epoch {
+            RangeRequestPriority::High
+        } else if distance <= NORMAL_PRIORITY_RANGE_EPOCHS * slots_per_epoch {
+            RangeRequestPriority::Normal
+        } else {
+            RangeRequestPriority::Low
+        }
+    }
+}
+
+/// A two-level queue for `BlocksByRangeRequest`s.
+///
+/// `High` and `Normal` priority requests are served from the first level, with `High` requests
+/// always served before `Normal` ones. `Low` priority requests are stored separately and are only
+/// served once the first level is empty.
+struct BlocksByRangeQueue<T> {
+    high: FifoQueue<T>,
+    normal: FifoQueue<T>,
+    low: FifoQueue<T>,
+}
+
+impl<T> BlocksByRangeQueue<T> {
+    /// Create a new, empty queue.
+    pub fn new() -> Self {
+        Self {
+            high: FifoQueue::new(MAX_BLOCKS_BY_RANGE_QUEUE_LEN),
+            normal: FifoQueue::new(MAX_BLOCKS_BY_RANGE_QUEUE_LEN),
+            low: FifoQueue::new(MAX_LOW_PRIORITY_BLOCKS_BY_RANGE_QUEUE_LEN),
+        }
+    }
+
+    /// Add a new item to the queue with the given `priority`.
+    ///
+    /// Drops `item` if the queue for `priority` is full.
+    pub fn push(&mut self, item: T, priority: RangeRequestPriority, item_desc: &str, log: &Logger) {
+        match priority {
+            RangeRequestPriority::High => self.high.push(item, item_desc, log),
+            RangeRequestPriority::Normal => self.normal.push(item, item_desc, log),
+            RangeRequestPriority::Low => self.low.push(item, item_desc, log),
+        }
+    }
+
+    /// Remove the highest-priority item from the queue.
+    pub fn pop(&mut self) -> Option<T> {
+        self.high
+            .pop()
+            .or_else(|| self.normal.pop())
+            .or_else(|| self.low.pop())
+    }
+
+    /// Returns the total number of items in the queue.
+    pub fn len(&self) -> usize {
+        self.high.len() + self.normal.len() + self.low.len()
+    }
+
+    /// Returns the number of low-priority items in the queue.
+    pub fn low_priority_len(&self) -> usize {
+        self.low.len()
+    }
+}
+
 /// An event to be processed by the manager task.
 pub struct WorkEvent<T: BeaconChainTypes> {
     drop_during_sync: bool,
     work: Work<T>,
+    /// The time at which this event was created, used to measure how long it waits before a worker
+    /// starts processing it.
+    queued_at: Instant,
 }
 
 impl<T: BeaconChainTypes> fmt::Debug for WorkEvent<T> {
@@ -261,6 +379,7 @@ impl<T: BeaconChainTypes> WorkEvent<T> {
     ) -> Self {
         Self {
             drop_during_sync: true,
+            queued_at: Instant::now(),
             work: Work::GossipAttestation {
                 message_id,
                 peer_id,
@@ -281,6 +400,7 @@ impl<T: BeaconChainTypes> WorkEvent<T> {
     ) -> Self {
         Self {
             drop_during_sync: true,
+            queued_at: Instant::now(),
             work: Work::GossipAggregate {
                 message_id,
                 peer_id,
@@ -299,6 +419,7 @@ impl<T: BeaconChainTypes> WorkEvent<T> {
     ) -> Self {
         Self {
             drop_during_sync: false,
+            queued_at: Instant::now(),
             work: Work::GossipBlock {
                 message_id,
                 peer_id,
@@ -316,6 +437,7 @@ impl<T: BeaconChainTypes> WorkEvent<T> {
     ) -> Self {
         Self {
             drop_during_sync: false,
+            queued_at: Instant::now(),
             work: Work::DelayedImportBlock {
                 peer_id,
                 block,
@@ -332,6 +454,7 @@ impl<T: BeaconChainTypes> WorkEvent<T> {
     ) -> Self {
         Self {
             drop_during_sync: false,
+            queued_at: Instant::now(),
             work: Work::GossipVoluntaryExit {
                 message_id,
                 peer_id,
@@ -348,6 +471,7 @@ impl<T: BeaconChainTypes> WorkEvent<T> {
     ) -> Self {
         Self {
             drop_during_sync: false,
+            queued_at: Instant::now(),
             work: Work::GossipProposerSlashing {
                 message_id,
                 peer_id,
@@ -364,6 +488,7 @@ impl<T: BeaconChainTypes> WorkEvent<T> {
     ) -> Self {
         Self {
             drop_during_sync: false,
+            queued_at: Instant::now(),
             work: Work::GossipAttesterSlashing {
                 message_id,
                 peer_id,
@@ -380,6 +505,7 @@ impl<T: BeaconChainTypes> WorkEvent<T> {
         let (result_tx, result_rx) = oneshot::channel();
         let event = Self {
             drop_during_sync: false,
+            queued_at: Instant::now(),
             work: Work::RpcBlock { block, result_tx },
         };
         (event, result_rx)
@@ -392,6 +518,7 @@ impl<T: BeaconChainTypes> WorkEvent<T> {
     ) -> Self {
         Self {
             drop_during_sync: false,
+            queued_at: Instant::now(),
             work: Work::ChainSegment { process_id, blocks },
         }
     }
@@ -400,6 +527,7 @@ impl<T: BeaconChainTypes> WorkEvent<T> {
     pub fn status_message(peer_id: PeerId, message: StatusMessage) -> Self {
         Self {
             drop_during_sync: false,
+            queued_at: Instant::now(),
             work: Work::Status { peer_id, message },
         }
     }
@@ -412,6 +540,7 @@ impl<T: BeaconChainTypes> WorkEvent<T> {
     ) -> Self {
         Self {
             drop_during_sync: false,
+            queued_at: Instant::now(),
             work: Work::BlocksByRangeRequest {
                 peer_id,
                 request_id,
@@ -428,6 +557,7 @@ impl<T: BeaconChainTypes> WorkEvent<T> {
     ) -> Self {
         Self {
             drop_during_sync: false,
+            queued_at: Instant::now(),
             work: Work::BlocksByRootsRequest {
                 peer_id,
                 request_id,
@@ -440,6 +570,75 @@ impl<T: BeaconChainTypes> WorkEvent<T> {
     pub fn work_type(&self) -> &'static str {
         self.work.str_id()
     }
+
+    /// Get the origin of the work this `WorkEvent` contains.
+    pub fn origin(&self) -> WorkOrigin {
+        self.work.origin()
+    }
+
+    /// Returns a rough estimate of the CPU time required to process this work.
+    pub fn estimated_processing_cost(&self) -> Duration {
+        match &self.work {
+            Work::GossipAttestation { .. }
+            | Work::GossipAggregate { .. }
+            | Work::GossipVoluntaryExit { .. }
+            | Work::GossipProposerSlashing { .. }
+            | Work::GossipAttesterSlashing { .. } => GOSSIP_OPERATION_PROCESSING_COST,
+            Work::GossipBlock { .. } | Work::DelayedImportBlock { .. } | Work::RpcBlock { .. } => {
+                BLOCK_PROCESSING_COST
+            }
+            Work::ChainSegment { blocks, .. } => {
+                CHAIN_SEGMENT_BLOCK_PROCESSING_COST * blocks.len() as u32
+            }
+            Work::Status { .. }
+            | Work::BlocksByRangeRequest { .. }
+            | Work::BlocksByRootsRequest { .. } => RPC_REQUEST_PROCESSING_COST,
+        }
+    }
+}
+
+/// Returns `true` if `work_event` should be accepted when there are `queued_len` work items
+/// already queued.
+///
+/// Once the queues are congested, only work estimated to be cheaper than `cost_threshold` is
+/// accepted. All work is accepted if there is no `cost_threshold`.
+///
+/// Blocks requested by sync are always accepted, since sync waits on the result of processing
+/// them and would stall if they were dropped.
+fn is_admissible<T: BeaconChainTypes>(
+    work_event: &WorkEvent<T>,
+    cost_threshold: Option<Duration>,
+    queued_len: usize,
+) -> bool {
+    match (&work_event.work, cost_threshold) {
+        (Work::ChainSegment { .. }, _) | (Work::RpcBlock { .. }, _) => true,
+        (_, Some(threshold)) if queued_len >= ADMISSION_CONTROL_QUEUE_LEN => {
+            work_event.estimated_processing_cost() < threshold
+        }
+        _ => true,
+    }
+}
+
+/// Where some `Work` came from, used to label the `BeaconProcessor` metrics.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WorkOrigin {
+    /// Received from a peer on a gossip topic.
+    Gossip,
+    /// Received from (or requested from) a peer via RPC.
+    Rpc,
+    /// Created by the `BeaconProcessor` itself, e.g., a gossip block that was delayed for import.
+    Internal,
+}
+
+impl WorkOrigin {
+    /// Provides the `&str` used for the `origin` metric label.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WorkOrigin::Gossip => "gossip",
+            WorkOrigin::Rpc => "rpc",
+            WorkOrigin::Internal => "internal",
+        }
+    }
 }
 
 /// A consensus message (or multiple) from the network that requires processing.
@@ -527,6 +726,31 @@ impl<T: BeaconChainTypes> Work<T> {
             Work::BlocksByRootsRequest { .. } => BLOCKS_BY_ROOTS_REQUEST,
         }
     }
+
+    /// Returns where this work came from.
+    fn origin(&self) -> WorkOrigin {
+        match self {
+            Work::GossipAttestation { .. }
+            | Work::GossipAggregate { .. }
+            | Work::GossipBlock { .. }
+            | Work::GossipVoluntaryExit { .. }
+            | Work::GossipProposerSlashing { .. }
+            | Work::GossipAttesterSlashing { .. } => WorkOrigin::Gossip,
+            Work::DelayedImportBlock { .. } => WorkOrigin::Internal,
+            Work::RpcBlock { .. }
+            | Work::ChainSegment { .. }
+            | Work::Status { .. }
+            | Work::BlocksByRangeRequest { .. }
+            | Work::BlocksByRootsRequest { .. } => WorkOrigin::Rpc,
+        }
+    }
+}
+
+/// Some `Work` held by the `BeaconProcessor`, along with the time at which its `WorkEvent` was
+/// created.
+struct QueuedWork<T: BeaconChainTypes> {
+    work: Work<T>,
+    queued_at: Instant,
 }
 
 /// Provides de-bounce functionality for logging.
@@ -558,6 +782,27 @@ enum InboundEvent<T: BeaconChainTypes> {
     QueuedBlock(Box<QueuedBlock<T>>),
 }
 
+/// Sends `event` to the `BeaconProcessor` via `tx`.
+///
+/// The event is counted in the `beacon_processor_queue_depth` metric for its type until a worker
+/// starts processing it or the `BeaconProcessor` discards it, allowing operators to see which types
+/// of work are backing up. Events dropped because the channel is full are counted in
+/// `beacon_processor_dropped_work_total`.
+pub fn try_send_work_event<T: BeaconChainTypes>(
+    tx: &mpsc::Sender<WorkEvent<T>>,
+    event: WorkEvent<T>,
+) -> Result<(), mpsc::error::TrySendError<WorkEvent<T>>> {
+    let work_type = event.work_type();
+    metrics::inc_gauge_vec(&metrics::BEACON_PROCESSOR_QUEUE_DEPTH, &[work_type]);
+    tx.try_send(event).map_err(|e| {
+        metrics::dec_gauge_vec(&metrics::BEACON_PROCESSOR_QUEUE_DEPTH, &[work_type]);
+        if let mpsc::error::TrySendError::Full(_) = e {
+            metrics::inc_counter_vec(&metrics::BEACON_PROCESSOR_DROPPED_WORK_TOTAL, &[work_type]);
+        }
+        e
+    })
+}
+
 /// Combines the various incoming event streams for the `BeaconProcessor` into a single stream.
 ///
 /// This struct has a similar purpose to `tokio::select!`, however it allows for more fine-grained
@@ -625,6 +870,8 @@ pub struct BeaconProcessor<T: BeaconChainTypes> {
     pub executor: TaskExecutor,
     pub max_workers: usize,
     pub current_workers: usize,
+    /// When the queues are congested, reject work estimated to take longer than this to process.
+    pub admission_cost_threshold: Option<Duration>,
     pub log: Logger,
 }
 
@@ -674,7 +921,8 @@ impl<T: BeaconChainTypes> BeaconProcessor<T> {
         let mut delayed_block_queue = FifoQueue::new(MAX_DELAYED_BLOCK_QUEUE_LEN);
 
         let mut status_queue = FifoQueue::new(MAX_STATUS_QUEUE_LEN);
-        let mut bbrange_queue = FifoQueue::new(MAX_BLOCKS_BY_RANGE_QUEUE_LEN);
+        // Serve requests for ranges near the head before historical ranges.
+        let mut bbrange_queue = BlocksByRangeQueue::new();
         let mut bbroots_queue = FifoQueue::new(MAX_BLOCKS_BY_ROOTS_QUEUE_LEN);
 
         // The delayed block queues are used to re-queue blocks for processing at a later time if
@@ -715,6 +963,12 @@ impl<T: BeaconChainTypes> BeaconProcessor<T> {
                     }
                     Some(InboundEvent::WorkEvent(event)) => Some(event),
                     Some(InboundEvent::QueuedBlock(queued_block)) => {
+                        // Delayed blocks are not sent via `try_send_work_event`, so count them
+                        // here to balance the decrement when they are started or discarded.
+                        metrics::inc_gauge_vec(
+                            &metrics::BEACON_PROCESSOR_QUEUE_DEPTH,
+                            &[DELAYED_IMPORT_BLOCK],
+                        );
                         Some(WorkEvent::delayed_import_beacon_block(
                             queued_block.peer_id,
                             Box::new(queued_block.block),
@@ -736,7 +990,7 @@ impl<T: BeaconChainTypes> BeaconProcessor<T> {
                 if let Some(event) = &work_event {
                     metrics::inc_counter_vec(
                         &metrics::BEACON_PROCESSOR_WORK_EVENTS_RX_COUNT,
-                        &[event.work.str_id()],
+                        &[event.work.str_id(), event.work.origin().as_str()],
                     );
                 } else {
                     metrics::inc_counter(&metrics::BEACON_PROCESSOR_IDLE_EVENTS_TOTAL);
@@ -757,6 +1011,22 @@ impl<T: BeaconChainTypes> BeaconProcessor<T> {
                 let drop_during_sync = work_event
                     .as_ref()
                     .map_or(false, |event| event.drop_during_sync);
+                let admissible = can_spawn
+                    || work_event.as_ref().map_or(true, |event| {
+                        let queued_len = aggregate_queue.len()
+                            + attestation_queue.len()
+                            + gossip_voluntary_exit_queue.len()
+                            + gossip_proposer_slashing_queue.len()
+                            + gossip_attester_slashing_queue.len()
+                            + rpc_block_queue.len()
+                            + chain_segment_queue.len()
+                            + gossip_block_queue.len()
+                            + delayed_block_queue.len()
+                            + status_queue.len()
+                            + bbrange_queue.len()
+                            + bbroots_queue.len();
+                        is_admissible(event, self.admission_cost_threshold, queued_len)
+                    });
 
                 match work_event {
                     // There is no new work event, but we are able to spawn a new worker.
@@ -840,9 +1110,10 @@ impl<T: BeaconChainTypes> BeaconProcessor<T> {
                             && drop_during_sync =>
                     {
                         let work_id = work_event.work.str_id();
+                        metrics::dec_gauge_vec(&metrics::BEACON_PROCESSOR_QUEUE_DEPTH, &[work_id]);
                         metrics::inc_counter_vec(
                             &metrics::BEACON_PROCESSOR_WORK_EVENTS_IGNORED_COUNT,
-                            &[work_id],
+                            &[work_id, work_event.work.origin().as_str()],
                         );
                         trace!(
                             self.log,
@@ -851,44 +1122,64 @@ impl<T: BeaconChainTypes> BeaconProcessor<T> {
                             "work_id" => work_id
                         );
                     }
+                    // The queues are congested and this event is too expensive to be queued.
+                    Some(work_event) if !admissible => {
+                        let work_id = work_event.work.str_id();
+                        metrics::dec_gauge_vec(&metrics::BEACON_PROCESSOR_QUEUE_DEPTH, &[work_id]);
+                        metrics::inc_counter_vec(
+                            &metrics::BEACON_PROCESSOR_WORK_EVENTS_REJECTED_COUNT,
+                            &[work_id, work_event.work.origin().as_str()],
+                        );
+                        debug!(
+                            self.log,
+                            "Gossip processor rejecting work";
+                            "msg" => "work queues are congested",
+                            "work_id" => work_id,
+                            "estimated_cost_ms" => work_event.estimated_processing_cost().as_millis(),
+                        );
+                    }
                     // There is a new work event and the chain is not syncing. Process it or queue
                     // it.
-                    Some(WorkEvent { work, .. }) => {
+                    Some(WorkEvent {
+                        work, queued_at, ..
+                    }) => {
                         let work_id = work.str_id();
                         let toolbox = Toolbox {
                             idle_tx: idle_tx.clone(),
                             delayed_block_tx: pre_delay_block_queue_tx.clone(),
                         };
+                        let item = QueuedWork { work, queued_at };
 
-                        match work {
-                            _ if can_spawn => self.spawn_worker(work, toolbox),
-                            Work::GossipAttestation { .. } => attestation_queue.push(work),
-                            Work::GossipAggregate { .. } => aggregate_queue.push(work),
+                        match item.work {
+                            _ if can_spawn => self.spawn_worker(item, toolbox),
+                            Work::GossipAttestation { .. } => attestation_queue.push(item, work_id),
+                            Work::GossipAggregate { .. } => aggregate_queue.push(item, work_id),
                             Work::GossipBlock { .. } => {
-                                gossip_block_queue.push(work, work_id, &self.log)
+                                gossip_block_queue.push(item, work_id, &self.log)
                             }
                             Work::DelayedImportBlock { .. } => {
-                                delayed_block_queue.push(work, work_id, &self.log)
+                                delayed_block_queue.push(item, work_id, &self.log)
                             }
                             Work::GossipVoluntaryExit { .. } => {
-                                gossip_voluntary_exit_queue.push(work, work_id, &self.log)
+                                gossip_voluntary_exit_queue.push(item, work_id, &self.log)
                             }
                             Work::GossipProposerSlashing { .. } => {
-                                gossip_proposer_slashing_queue.push(work, work_id, &self.log)
+                                gossip_proposer_slashing_queue.push(item, work_id, &self.log)
                             }
                             Work::GossipAttesterSlashing { .. } => {
-                                gossip_attester_slashing_queue.push(work, work_id, &self.log)
+                                gossip_attester_slashing_queue.push(item, work_id, &self.log)
                             }
-                            Work::RpcBlock { .. } => rpc_block_queue.push(work, work_id, &self.log),
+                            Work::RpcBlock { .. } => rpc_block_queue.push(item, work_id, &self.log),
                             Work::ChainSegment { .. } => {
-                                chain_segment_queue.push(work, work_id, &self.log)
+                                chain_segment_queue.push(item, work_id, &self.log)
                             }
-                            Work::Status { .. } => status_queue.push(work, work_id, &self.log),
-                            Work::BlocksByRangeRequest { .. } => {
-                                bbrange_queue.push(work, work_id, &self.log)
+                            Work::Status { .. } => status_queue.push(item, work_id, &self.log),
+                            Work::BlocksByRangeRequest { ref request, .. } => {
+                                let priority = self.range_request_priority(request);
+                                bbrange_queue.push(item, priority, work_id, &self.log)
                             }
                             Work::BlocksByRootsRequest { .. } => {
-                                bbroots_queue.push(work, work_id, &self.log)
+                                bbroots_queue.push(item, work_id, &self.log)
                             }
                         }
                     }
@@ -930,6 +1221,14 @@ impl<T: BeaconChainTypes> BeaconProcessor<T> {
                     &metrics::BEACON_PROCESSOR_ATTESTER_SLASHING_QUEUE_TOTAL,
                     gossip_attester_slashing_queue.len() as i64,
                 );
+                metrics::set_gauge(
+                    &metrics::BEACON_PROCESSOR_BLOCKS_BY_RANGE_QUEUE_TOTAL,
+                    bbrange_queue.len() as i64,
+                );
+                metrics::set_gauge(
+                    &metrics::BEACON_PROCESSOR_LOW_PRIORITY_BLOCKS_BY_RANGE_QUEUE_TOTAL,
+                    bbrange_queue.low_priority_len() as i64,
+                );
 
                 if aggregate_queue.is_full() && aggregate_debounce.elapsed() {
                     error!(
@@ -955,10 +1254,29 @@ impl<T: BeaconChainTypes> BeaconProcessor<T> {
         executor.spawn(manager_future, MANAGER_TASK_NAME);
     }
 
+    /// Determine the `RangeRequestPriority` of `request` relative to the current slot.
+    ///
+    /// The slot clock is used rather than the head, since reading the head requires a lock which
+    /// should not be held by the manager task. Defaults to `Normal` if the slot cannot be read.
+    fn range_request_priority(&self, request: &BlocksByRangeRequest) -> RangeRequestPriority {
+        self.beacon_chain
+            .upgrade()
+            .and_then(|chain| chain.slot().ok())
+            .map(|current_slot| {
+                RangeRequestPriority::from_start_slot(
+                    Slot::new(request.start_slot),
+                    current_slot,
+                    T::EthSpec::slots_per_epoch(),
+                )
+            })
+            .unwrap_or(RangeRequestPriority::Normal)
+    }
+
     /// Spawns a blocking worker thread to process some `Work`.
     ///
     /// Sends an message on `idle_tx` when the work is complete and the task is stopping.
-    fn spawn_worker(&mut self, work: Work<T>, toolbox: Toolbox<T>) {
+    fn spawn_worker(&mut self, item: QueuedWork<T>, toolbox: Toolbox<T>) {
+        let QueuedWork { work, queued_at } = item;
         let idle_tx = toolbox.idle_tx;
         let delayed_block_tx = toolbox.delayed_block_tx;
 
@@ -972,12 +1290,18 @@ impl<T: BeaconChainTypes> BeaconProcessor<T> {
         };
 
         let work_id = work.str_id();
+        metrics::dec_gauge_vec(&metrics::BEACON_PROCESSOR_QUEUE_DEPTH, &[work_id]);
+        metrics::observe_timer_vec(
+            &metrics::BEACON_PROCESSOR_QUEUE_WAIT_SECONDS,
+            &[work_id],
+            queued_at.elapsed(),
+        );
         let worker_timer =
             metrics::start_timer_vec(&metrics::BEACON_PROCESSOR_WORKER_TIME, &[work_id]);
         metrics::inc_counter(&metrics::BEACON_PROCESSOR_WORKERS_SPAWNED_TOTAL);
         metrics::inc_counter_vec(
             &metrics::BEACON_PROCESSOR_WORK_EVENTS_STARTED_COUNT,
-            &[work.str_id()],
+            &[work.str_id(), work.origin().as_str()],
         );
 
         let worker_id = self.current_workers;
@@ -1000,6 +1324,7 @@ impl<T: BeaconChainTypes> BeaconProcessor<T> {
             chain,
             network_tx: self.network_tx.clone(),
             sync_tx: self.sync_tx.clone(),
+            network_globals: self.network_globals.clone(),
             log: self.log.clone(),
         };
 
@@ -2,14 +2,21 @@
 #![cfg(test)]
 
 use crate::beacon_processor::*;
-use crate::{service::NetworkMessage, sync::SyncMessage};
+use crate::metrics;
+use crate::sync::manager::{spawn as spawn_sync_manager, SLOT_IMPORT_TOLERANCE};
+use crate::{service::NetworkMessage, status::ToStatusMessage, sync::SyncMessage};
 use beacon_chain::{
     test_utils::{AttestationStrategy, BeaconChainHarness, BlockStrategy, EphemeralHarnessType},
-    BeaconChain, MAXIMUM_GOSSIP_CLOCK_DISPARITY,
+    BeaconChain, ChainConfig, MAXIMUM_GOSSIP_CLOCK_DISPARITY,
 };
 use discv5::enr::{CombinedKey, EnrBuilder};
 use environment::{null_logger, Environment, EnvironmentBuilder};
-use eth2_libp2p::{rpc::methods::MetaData, types::EnrBitfield, MessageId, NetworkGlobals, PeerId};
+use eth2_libp2p::{
+    rpc::{methods::MetaData, StatusMessage},
+    types::{EnrBitfield, GossipKind, GossipMessageCounters},
+    MessageId, Multiaddr, NetworkGlobals, PeerId, PeerSyncStatus, Request, SyncInfo,
+};
+use futures::FutureExt;
 use slot_clock::SlotClock;
 use std::cmp;
 use std::iter::Iterator;
@@ -18,8 +25,9 @@ use std::time::Duration;
 use tokio::runtime::Runtime;
 use tokio::sync::mpsc;
 use types::{
-    test_utils::generate_deterministic_keypairs, Attestation, AttesterSlashing, MainnetEthSpec,
-    ProposerSlashing, SignedBeaconBlock, SignedVoluntaryExit, SubnetId,
+    test_utils::generate_deterministic_keypairs, AggregateSignature, Attestation, AttesterSlashing,
+    BeaconBlock, Epoch, EthSpec, Hash256, MainnetEthSpec, ProposerSlashing, Signature,
+    SignedBeaconBlock, SignedVoluntaryExit, Slot, SubnetId,
 };
 
 type E = MainnetEthSpec;
@@ -45,10 +53,11 @@ struct TestRig {
     attester_slashing: AttesterSlashing<E>,
     proposer_slashing: ProposerSlashing,
     voluntary_exit: SignedVoluntaryExit,
+    network_globals: Arc<NetworkGlobals<E>>,
     beacon_processor_tx: mpsc::Sender<WorkEvent<T>>,
     work_journal_rx: mpsc::Receiver<String>,
     _network_rx: mpsc::UnboundedReceiver<NetworkMessage<E>>,
-    _sync_rx: mpsc::UnboundedReceiver<SyncMessage<E>>,
+    sync_rx: mpsc::UnboundedReceiver<SyncMessage<E>>,
     environment: Option<Environment<E>>,
 }
 
@@ -64,6 +73,10 @@ impl Drop for TestRig {
 
 impl TestRig {
     pub fn new(chain_length: u64) -> Self {
+        Self::new_with_chain_config(chain_length, ChainConfig::default())
+    }
+
+    pub fn new_with_chain_config(chain_length: u64, chain_config: ChainConfig) -> Self {
         let mut harness = BeaconChainHarness::new(
             MainnetEthSpec,
             generate_deterministic_keypairs(VALIDATOR_COUNT),
@@ -119,6 +132,7 @@ impl TestRig {
         //
         // This allows for testing voluntary exits without building out a massive chain.
         harness.chain.spec.shard_committee_period = 2;
+        harness.chain.config = chain_config;
 
         let chain = Arc::new(harness.chain);
 
@@ -127,7 +141,7 @@ impl TestRig {
         let log = null_logger().unwrap();
 
         let (beacon_processor_tx, beacon_processor_rx) = mpsc::channel(MAX_WORK_EVENT_QUEUE_LEN);
-        let (sync_tx, _sync_rx) = mpsc::unbounded_channel();
+        let (sync_tx, sync_rx) = mpsc::unbounded_channel();
 
         // Default metadata
         let meta_data = MetaData {
@@ -161,10 +175,11 @@ impl TestRig {
             beacon_chain: Arc::downgrade(&chain),
             network_tx,
             sync_tx,
-            network_globals,
+            network_globals: network_globals.clone(),
             executor,
             max_workers: cmp::max(1, num_cpus::get()),
             current_workers: 0,
+            admission_cost_threshold: None,
             log: log.clone(),
         }
         .spawn_manager(beacon_processor_rx, Some(work_journal_tx));
@@ -176,10 +191,11 @@ impl TestRig {
             attester_slashing,
             proposer_slashing,
             voluntary_exit,
+            network_globals,
             beacon_processor_tx,
             work_journal_rx,
             _network_rx,
-            _sync_rx,
+            sync_rx,
             environment: Some(environment),
         }
     }
@@ -197,6 +213,16 @@ impl TestRig {
 
     pub fn enqueue_unaggregated_attestation(&self) {
         let (attestation, subnet_id) = self.attestations.first().unwrap().clone();
+        self.enqueue_attestation(attestation, subnet_id);
+    }
+
+    pub fn enqueue_invalid_unaggregated_attestation(&self) {
+        let (mut attestation, subnet_id) = self.attestations.last().unwrap().clone();
+        attestation.signature = AggregateSignature::empty();
+        self.enqueue_attestation(attestation, subnet_id);
+    }
+
+    fn enqueue_attestation(&self, attestation: Attestation<E>, subnet_id: SubnetId) {
         self.beacon_processor_tx
             .try_send(WorkEvent::unaggregated_attestation(
                 junk_message_id(),
@@ -209,6 +235,14 @@ impl TestRig {
             .unwrap();
     }
 
+    pub fn enqueue_status_with_head_slot(&self, head_slot: Slot) {
+        let mut status = self.chain.status_message().unwrap();
+        status.head_slot = head_slot;
+        self.beacon_processor_tx
+            .try_send(WorkEvent::status_message(junk_peer_id(), status))
+            .unwrap();
+    }
+
     pub fn enqueue_gossip_attester_slashing(&self) {
         self.beacon_processor_tx
             .try_send(WorkEvent::gossip_attester_slashing(
@@ -426,6 +460,162 @@ fn import_gossip_block_at_current_slot() {
     );
 }
 
+/// Sync should be told about the peer's head when it sends us a valid block.
+#[test]
+fn gossip_block_updates_peer_head() {
+    let mut rig = TestRig::new(SMALL_CHAIN);
+
+    rig.enqueue_gossip_block();
+
+    rig.assert_event_journal(&[GOSSIP_BLOCK, WORKER_FREED, NOTHING_TO_DO]);
+
+    match rig.sync_rx.recv().now_or_never() {
+        Some(Some(SyncMessage::PeerHeadUpdated { slot, root, .. })) => {
+            assert_eq!(slot, rig.next_block.slot());
+            assert_eq!(root, rig.next_block.canonical_root());
+        }
+        _ => panic!("sync should be notified of the peer's head"),
+    }
+}
+
+/// Sync should start requesting blocks from a peer once it learns the peer's head is ahead of ours.
+#[test]
+fn peer_head_update_starts_range_sync() {
+    let mut rig = TestRig::new(SMALL_CHAIN);
+
+    let status = rig.chain.status_message().unwrap();
+    let local_info = SyncInfo {
+        head_slot: status.head_slot,
+        head_root: status.head_root,
+        finalized_epoch: status.finalized_epoch,
+        finalized_root: status.finalized_root,
+    };
+
+    // A connected peer which last reported the same head as us.
+    let peer_id = junk_peer_id();
+    {
+        let mut peers = rig.network_globals.peers.write();
+        peers.connect_ingoing(&peer_id, Multiaddr::empty(), None);
+        peers.peer_info_mut(&peer_id).unwrap().sync_status = PeerSyncStatus::Synced {
+            info: local_info.clone(),
+        };
+    }
+
+    let (network_tx, mut network_rx) = mpsc::unbounded_channel();
+    let executor = rig.environment.as_mut().unwrap().core_context().executor;
+    let sync_tx = spawn_sync_manager(
+        executor,
+        rig.chain.clone(),
+        rig.network_globals.clone(),
+        network_tx,
+        rig.beacon_processor_tx.clone(),
+        null_logger().unwrap(),
+    );
+
+    let peer_head_slot = local_info.head_slot + 2 * SLOT_IMPORT_TOLERANCE as u64;
+    sync_tx
+        .send(SyncMessage::PeerHeadUpdated {
+            peer_id,
+            slot: peer_head_slot,
+            root: Hash256::repeat_byte(42),
+        })
+        .unwrap();
+
+    let request = rig.runtime().block_on(async {
+        tokio::time::timeout(STANDARD_TIMEOUT, async {
+            while let Some(message) = network_rx.recv().await {
+                if let NetworkMessage::SendRequest {
+                    peer_id: request_peer_id,
+                    request: Request::BlocksByRange(request),
+                    ..
+                } = message
+                {
+                    if request_peer_id == peer_id {
+                        return Some(request);
+                    }
+                }
+            }
+            None
+        })
+        .await
+    });
+
+    match request {
+        Ok(Some(request)) => assert!(
+            request.start_slot <= local_info.head_slot.as_u64() + 1,
+            "range sync should start from our chain"
+        ),
+        _ => panic!("sync should request blocks from the advanced peer"),
+    }
+
+    // The sync manager does not stop when its channel closes, so shut down the runtime first.
+    drop(rig);
+    drop(sync_tx);
+}
+
+/// Work metrics should be labelled with the origin of the work.
+#[test]
+fn work_metrics_labelled_by_origin() {
+    let started_count = |work_type: &str, origin: WorkOrigin| {
+        metrics::get_int_counter(
+            &metrics::BEACON_PROCESSOR_WORK_EVENTS_STARTED_COUNT,
+            &[work_type, origin.as_str()],
+        )
+        .map_or(0, |counter| counter.get())
+    };
+
+    let mut rig = TestRig::new(SMALL_CHAIN);
+
+    let gossip_blocks = started_count(GOSSIP_BLOCK, WorkOrigin::Gossip);
+    rig.enqueue_gossip_block();
+    rig.assert_event_journal(&[GOSSIP_BLOCK, WORKER_FREED, NOTHING_TO_DO]);
+    assert!(started_count(GOSSIP_BLOCK, WorkOrigin::Gossip) > gossip_blocks);
+
+    let rpc_blocks = started_count(RPC_BLOCK, WorkOrigin::Rpc);
+    let (event, _result_rx) = WorkEvent::rpc_beacon_block(Box::new(rig.next_block.clone()));
+    assert_eq!(event.origin(), WorkOrigin::Rpc);
+    rig.beacon_processor_tx.try_send(event).unwrap();
+    rig.assert_event_journal(&[RPC_BLOCK, WORKER_FREED, NOTHING_TO_DO]);
+    assert!(started_count(RPC_BLOCK, WorkOrigin::Rpc) > rpc_blocks);
+
+    // Work is never labelled with an origin it cannot have.
+    assert_eq!(started_count(GOSSIP_BLOCK, WorkOrigin::Rpc), 0);
+    assert_eq!(started_count(RPC_BLOCK, WorkOrigin::Gossip), 0);
+}
+
+/// Ensure a peer whose head is ahead of our clock is only accepted within the configured
+/// `future_slot_tolerance`.
+#[test]
+fn status_within_future_slot_tolerance() {
+    let status_adds_peer = |future_slot_tolerance: u64| {
+        let mut rig = TestRig::new_with_chain_config(
+            SMALL_CHAIN,
+            ChainConfig {
+                future_slot_tolerance,
+                ..ChainConfig::default()
+            },
+        );
+
+        rig.enqueue_status_with_head_slot(rig.chain.slot().unwrap() + 2);
+
+        rig.assert_event_journal(&[STATUS_PROCESSING, WORKER_FREED, NOTHING_TO_DO]);
+
+        matches!(
+            rig.sync_rx.recv().now_or_never(),
+            Some(Some(SyncMessage::AddPeer(..)))
+        )
+    };
+
+    assert!(
+        !status_adds_peer(1),
+        "a head two slots ahead should be rejected with the default tolerance"
+    );
+    assert!(
+        status_adds_peer(2),
+        "a head two slots ahead should be accepted with a tolerance of two"
+    );
+}
+
 /// Ensure a valid attestation can be imported.
 #[test]
 fn import_gossip_attestation() {
@@ -498,3 +688,145 @@ fn import_misc_gossip_ops() {
         "op pool should have one more exit"
     );
 }
+
+/// Ensure the gossip stats count each message and the outcome of its validation.
+#[test]
+fn gossip_stats_count_validation_results() {
+    let mut rig = TestRig::new(SMALL_CHAIN);
+
+    // A valid attestation is accepted.
+    rig.enqueue_unaggregated_attestation();
+    rig.assert_event_journal(&[GOSSIP_ATTESTATION, WORKER_FREED, NOTHING_TO_DO]);
+
+    // The same attestation is ignored the second time around.
+    rig.enqueue_unaggregated_attestation();
+    rig.assert_event_journal(&[GOSSIP_ATTESTATION, WORKER_FREED, NOTHING_TO_DO]);
+
+    // An attestation with a bad signature is rejected.
+    rig.enqueue_invalid_unaggregated_attestation();
+    rig.assert_event_journal(&[GOSSIP_ATTESTATION, WORKER_FREED, NOTHING_TO_DO]);
+
+    let stats = &rig.network_globals.gossip_stats;
+    let (_, subnet_id) = rig.attestations.first().unwrap();
+    assert_eq!(
+        stats.get(&GossipKind::Attestation(*subnet_id)),
+        Some(GossipMessageCounters {
+            seen: 3,
+            accepted: 1,
+            rejected_invalid: 1,
+            rejected_ignore: 1,
+        })
+    );
+    assert_eq!(stats.topics().len(), 1, "no other topics should be counted");
+}
+
+#[test]
+fn range_request_priority_from_start_slot() {
+    let current_slot = Slot::new(SLOTS_PER_EPOCH * 20);
+    let priority = |epochs_behind: u64| {
+        RangeRequestPriority::from_start_slot(
+            current_slot - epochs_behind * SLOTS_PER_EPOCH,
+            current_slot,
+            SLOTS_PER_EPOCH,
+        )
+    };
+
+    assert_eq!(priority(0), RangeRequestPriority::High);
+    assert_eq!(priority(2), RangeRequestPriority::High);
+    assert_eq!(priority(3), RangeRequestPriority::Normal);
+    assert_eq!(priority(8), RangeRequestPriority::Normal);
+    assert_eq!(priority(9), RangeRequestPriority::Low);
+
+    // Requests for ranges beyond the current slot are served with the highest priority.
+    assert_eq!(
+        RangeRequestPriority::from_start_slot(current_slot + 1, current_slot, SLOTS_PER_EPOCH),
+        RangeRequestPriority::High
+    );
+}
+
+#[test]
+fn head_range_request_pre_empts_historical_range_request() {
+    let log = null_logger().unwrap();
+    let mut queue = BlocksByRangeQueue::new();
+
+    queue.push(
+        "historical",
+        RangeRequestPriority::Low,
+        BLOCKS_BY_RANGE_REQUEST,
+        &log,
+    );
+    queue.push(
+        "normal",
+        RangeRequestPriority::Normal,
+        BLOCKS_BY_RANGE_REQUEST,
+        &log,
+    );
+    queue.push(
+        "head",
+        RangeRequestPriority::High,
+        BLOCKS_BY_RANGE_REQUEST,
+        &log,
+    );
+
+    assert_eq!(queue.len(), 3);
+    assert_eq!(queue.low_priority_len(), 1);
+
+    assert_eq!(queue.pop(), Some("head"));
+    assert_eq!(queue.pop(), Some("normal"));
+    assert_eq!(queue.pop(), Some("historical"));
+    assert_eq!(queue.pop(), None);
+}
+
+#[test]
+fn congested_queues_reject_expensive_work_first() {
+    let threshold = Some(Duration::from_millis(50));
+
+    let cheap = WorkEvent::<T>::status_message(
+        junk_peer_id(),
+        StatusMessage {
+            fork_digest: [0; 4],
+            finalized_root: Hash256::zero(),
+            finalized_epoch: Epoch::new(0),
+            head_root: Hash256::zero(),
+            head_slot: Slot::new(0),
+        },
+    );
+    let block = SignedBeaconBlock {
+        message: BeaconBlock::empty(&E::default_spec()),
+        signature: Signature::empty(),
+    };
+    let expensive = WorkEvent::<T>::gossip_beacon_block(
+        junk_message_id(),
+        junk_peer_id(),
+        Box::new(block.clone()),
+        Duration::from_secs(0),
+    );
+    let chain_segment = WorkEvent::<T>::chain_segment(
+        ProcessId::ParentLookup(junk_peer_id(), Hash256::zero()),
+        vec![block.clone(); 2],
+    );
+    let (rpc_block, _result_rx) = WorkEvent::<T>::rpc_beacon_block(Box::new(block));
+
+    assert_eq!(
+        chain_segment.estimated_processing_cost(),
+        Duration::from_millis(1_000)
+    );
+
+    // All work is accepted whilst the queues are not congested.
+    let uncongested_len = ADMISSION_CONTROL_QUEUE_LEN - 1;
+    assert!(is_admissible(&cheap, threshold, uncongested_len));
+    assert!(is_admissible(&expensive, threshold, uncongested_len));
+
+    // Only cheap work is accepted once the queues are congested.
+    let congested_len = ADMISSION_CONTROL_QUEUE_LEN;
+    assert!(is_admissible(&cheap, threshold, congested_len));
+    assert!(!is_admissible(&expensive, threshold, congested_len));
+
+    // Blocks requested by sync are never rejected, otherwise the requesting batch or lookup would
+    // wait forever on their result.
+    assert!(is_admissible(&chain_segment, threshold, congested_len));
+    assert!(is_admissible(&rpc_block, threshold, congested_len));
+
+    // Without a threshold, admission control is disabled.
+    assert!(is_admissible(&expensive, None, congested_len));
+}
@@ -5,8 +5,12 @@ use beacon_chain::{
     observed_operations::ObservationOutcome,
     validator_monitor::get_block_delay_ms,
     BeaconChainError, BeaconChainTypes, BlockError, ForkChoiceError, GossipVerifiedBlock,
+    MIN_AGGREGATE_ATTESTATION_EFFICIENCY,
+};
+use eth2_libp2p::{
+    types::GossipKind, MessageAcceptance, MessageId, PeerAction, PeerId, PubsubMessage,
+    ReportSource,
 };
-use eth2_libp2p::{MessageAcceptance, MessageId, PeerAction, PeerId, ReportSource};
 use slog::{debug, error, info, trace, warn};
 use slot_clock::SlotClock;
 use ssz::Encode;
@@ -31,6 +35,28 @@ impl<T: BeaconChainTypes> Worker<T> {
         })
     }
 
+    /// Check the attesters of `block` for double votes against the op pool and publish any new
+    /// attester slashings on gossip.
+    fn publish_op_pool_attester_slashings(&self, block: &SignedBeaconBlock<T::EthSpec>) {
+        let slashings = self.chain.monitor_attester_slashing_opportunity(block);
+        if slashings.is_empty() {
+            return;
+        }
+
+        debug!(
+            self.log,
+            "Publishing attester slashings from op pool";
+            "count" => slashings.len(),
+        );
+
+        let messages = slashings
+            .into_iter()
+            .map(|slashing| PubsubMessage::AttesterSlashing(Box::new(slashing)))
+            .collect();
+
+        self.send_network_message(NetworkMessage::Publish { messages });
+    }
+
     /// Send a message on `message_tx` that the `message_id` sent by `peer_id` should be propagated on
     /// the gossip network.
     ///
@@ -41,8 +67,14 @@ impl<T: BeaconChainTypes> Worker<T> {
         &self,
         message_id: MessageId,
         propagation_source: PeerId,
+        kind: &GossipKind,
         validation_result: MessageAcceptance,
     ) {
+        metrics::register_gossip_validation_result(kind, &validation_result);
+        self.network_globals
+            .gossip_stats
+            .register_validation_result(kind, &validation_result);
+
         self.send_network_message(NetworkMessage::ValidationResult {
             propagation_source,
             message_id,
@@ -50,6 +82,12 @@ impl<T: BeaconChainTypes> Worker<T> {
         })
     }
 
+    /// Records that a gossip message for the topic of `kind` is about to be validated.
+    fn register_gossip_message_seen(&self, kind: &GossipKind) {
+        metrics::register_gossip_message_seen(kind);
+        self.network_globals.gossip_stats.register_seen(kind);
+    }
+
     /* Processing functions */
 
     /// Process the unaggregated attestation received from the gossip network and:
@@ -68,6 +106,8 @@ impl<T: BeaconChainTypes> Worker<T> {
         should_import: bool,
         seen_timestamp: Duration,
     ) {
+        self.register_gossip_message_seen(&GossipKind::Attestation(subnet_id));
+
         let beacon_block_root = attestation.data.beacon_block_root;
 
         let attestation = match self
@@ -81,6 +121,7 @@ impl<T: BeaconChainTypes> Worker<T> {
                     message_id,
                     beacon_block_root,
                     "unaggregated",
+                    &GossipKind::Attestation(subnet_id),
                     e,
                 );
                 return;
@@ -99,7 +140,12 @@ impl<T: BeaconChainTypes> Worker<T> {
 
         // Indicate to the `Network` service that this message is valid and can be
         // propagated on the gossip network.
-        self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Accept);
+        self.propagate_validation_result(
+            message_id,
+            peer_id,
+            &GossipKind::Attestation(subnet_id),
+            MessageAcceptance::Accept,
+        );
 
         if !should_import {
             return;
@@ -155,6 +201,8 @@ impl<T: BeaconChainTypes> Worker<T> {
         aggregate: SignedAggregateAndProof<T::EthSpec>,
         seen_timestamp: Duration,
     ) {
+        self.register_gossip_message_seen(&GossipKind::BeaconAggregateAndProof);
+
         let beacon_block_root = aggregate.message.aggregate.data.beacon_block_root;
 
         let aggregate = match self
@@ -169,6 +217,7 @@ impl<T: BeaconChainTypes> Worker<T> {
                     message_id,
                     beacon_block_root,
                     "aggregated",
+                    &GossipKind::BeaconAggregateAndProof,
                     e,
                 );
                 return;
@@ -177,7 +226,29 @@ impl<T: BeaconChainTypes> Worker<T> {
 
         // Indicate to the `Network` service that this message is valid and can be
         // propagated on the gossip network.
-        self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Accept);
+        self.propagate_validation_result(
+            message_id,
+            peer_id,
+            &GossipKind::BeaconAggregateAndProof,
+            MessageAcceptance::Accept,
+        );
+
+        let efficiency = self
+            .chain
+            .compute_aggregate_attestation_efficiency(aggregate.aggregate());
+        metrics::observe(&metrics::GOSSIP_AGGREGATE_EFFICIENCY, efficiency);
+        if efficiency < MIN_AGGREGATE_ATTESTATION_EFFICIENCY {
+            // A nearly empty aggregate is valid, so it is only recorded. The peer which relayed it
+            // is not necessarily the aggregator and is not penalized.
+            metrics::inc_counter(&metrics::GOSSIP_AGGREGATE_INEFFICIENT_TOTAL);
+            debug!(
+                self.log,
+                "Inefficient aggregate from gossip";
+                "efficiency" => efficiency,
+                "peer" => %peer_id,
+                "beacon_block_root" => ?beacon_block_root
+            );
+        }
 
         // Register the attestation with any monitored validators.
         self.chain
@@ -241,6 +312,8 @@ impl<T: BeaconChainTypes> Worker<T> {
         delayed_import_tx: mpsc::Sender<QueuedBlock<T>>,
         seen_duration: Duration,
     ) {
+        self.register_gossip_message_seen(&GossipKind::BeaconBlock);
+
         // Log metrics to track delay from other nodes on the network.
         metrics::observe_duration(
             &metrics::BEACON_BLOCK_GOSSIP_SLOT_START_DELAY_TIME,
@@ -255,7 +328,20 @@ impl<T: BeaconChainTypes> Worker<T> {
                     "slot" => verified_block.block.slot(),
                     "hash" => %verified_block.block_root
                 );
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Accept);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    &GossipKind::BeaconBlock,
+                    MessageAcceptance::Accept,
+                );
+
+                // The peer's head is at least as recent as this block, which may be newer than
+                // the head it last told us about.
+                self.send_sync_message(SyncMessage::PeerHeadUpdated {
+                    peer_id,
+                    slot: verified_block.block.slot(),
+                    root: verified_block.block_root,
+                });
 
                 // Log metrics to keep track of propagation delay times.
                 if let Some(duration) = SystemTime::now()
@@ -290,7 +376,12 @@ impl<T: BeaconChainTypes> Worker<T> {
                             "error" => %e);
                 // Prevent recurring behaviour by penalizing the peer slightly.
                 self.gossip_penalize_peer(peer_id, PeerAction::HighToleranceError);
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Ignore);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    &GossipKind::BeaconBlock,
+                    MessageAcceptance::Ignore,
+                );
                 return;
             }
             Err(e @ BlockError::StateRootMismatch { .. })
@@ -308,7 +399,12 @@ impl<T: BeaconChainTypes> Worker<T> {
             | Err(e @ BlockError::GenesisBlock) => {
                 warn!(self.log, "Could not verify block for gossip, rejecting the block";
                             "error" => %e);
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Reject);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    &GossipKind::BeaconBlock,
+                    MessageAcceptance::Reject,
+                );
                 self.gossip_penalize_peer(peer_id, PeerAction::LowToleranceError);
                 return;
             }
@@ -429,6 +525,8 @@ impl<T: BeaconChainTypes> Worker<T> {
                         "location" => "block gossip"
                     ),
                 }
+
+                self.publish_op_pool_attester_slashings(&block);
             }
             Err(BlockError::ParentUnknown { .. }) => {
                 // Inform the sync manager to find parents for this block
@@ -464,12 +562,19 @@ impl<T: BeaconChainTypes> Worker<T> {
         peer_id: PeerId,
         voluntary_exit: SignedVoluntaryExit,
     ) {
+        self.register_gossip_message_seen(&GossipKind::VoluntaryExit);
+
         let validator_index = voluntary_exit.message.validator_index;
 
         let exit = match self.chain.verify_voluntary_exit_for_gossip(voluntary_exit) {
             Ok(ObservationOutcome::New(exit)) => exit,
             Ok(ObservationOutcome::AlreadyKnown) => {
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Ignore);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    &GossipKind::VoluntaryExit,
+                    MessageAcceptance::Ignore,
+                );
                 debug!(
                     self.log,
                     "Dropping exit for already exiting validator";
@@ -488,7 +593,12 @@ impl<T: BeaconChainTypes> Worker<T> {
                 );
                 // These errors occur due to a fault in the beacon chain. It is not necessarily
                 // the fault on the peer.
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Ignore);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    &GossipKind::VoluntaryExit,
+                    MessageAcceptance::Ignore,
+                );
                 // We still penalize a peer slightly to prevent overuse of invalids.
                 self.gossip_penalize_peer(peer_id, PeerAction::HighToleranceError);
                 return;
@@ -497,7 +607,12 @@ impl<T: BeaconChainTypes> Worker<T> {
 
         metrics::inc_counter(&metrics::BEACON_PROCESSOR_EXIT_VERIFIED_TOTAL);
 
-        self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Accept);
+        self.propagate_validation_result(
+            message_id,
+            peer_id,
+            &GossipKind::VoluntaryExit,
+            MessageAcceptance::Accept,
+        );
 
         // Register the exit with any monitored validators.
         self.chain
@@ -518,6 +633,8 @@ impl<T: BeaconChainTypes> Worker<T> {
         peer_id: PeerId,
         proposer_slashing: ProposerSlashing,
     ) {
+        self.register_gossip_message_seen(&GossipKind::ProposerSlashing);
+
         let validator_index = proposer_slashing.signed_header_1.message.proposer_index;
 
         let slashing = match self
@@ -533,7 +650,12 @@ impl<T: BeaconChainTypes> Worker<T> {
                     "validator_index" => validator_index,
                     "peer" => %peer_id
                 );
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Ignore);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    &GossipKind::ProposerSlashing,
+                    MessageAcceptance::Ignore,
+                );
                 return;
             }
             Err(e) => {
@@ -546,7 +668,12 @@ impl<T: BeaconChainTypes> Worker<T> {
                     "peer" => %peer_id,
                     "error" => ?e
                 );
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Ignore);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    &GossipKind::ProposerSlashing,
+                    MessageAcceptance::Ignore,
+                );
 
                 // Penalize peer slightly for invalids.
                 self.gossip_penalize_peer(peer_id, PeerAction::HighToleranceError);
@@ -556,7 +683,12 @@ impl<T: BeaconChainTypes> Worker<T> {
 
         metrics::inc_counter(&metrics::BEACON_PROCESSOR_PROPOSER_SLASHING_VERIFIED_TOTAL);
 
-        self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Accept);
+        self.propagate_validation_result(
+            message_id,
+            peer_id,
+            &GossipKind::ProposerSlashing,
+            MessageAcceptance::Accept,
+        );
 
         // Register the slashing with any monitored validators.
         self.chain
@@ -576,6 +708,8 @@ impl<T: BeaconChainTypes> Worker<T> {
         peer_id: PeerId,
         attester_slashing: AttesterSlashing<T::EthSpec>,
     ) {
+        self.register_gossip_message_seen(&GossipKind::AttesterSlashing);
+
         let slashing = match self
             .chain
             .verify_attester_slashing_for_gossip(attester_slashing)
@@ -588,7 +722,12 @@ impl<T: BeaconChainTypes> Worker<T> {
                     "reason" => "Slashings already known for all slashed validators",
                     "peer" => %peer_id
                 );
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Ignore);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    &GossipKind::AttesterSlashing,
+                    MessageAcceptance::Ignore,
+                );
                 return;
             }
             Err(e) => {
@@ -598,7 +737,12 @@ impl<T: BeaconChainTypes> Worker<T> {
                     "peer" => %peer_id,
                     "error" => ?e
                 );
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Ignore);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    &GossipKind::AttesterSlashing,
+                    MessageAcceptance::Ignore,
+                );
                 // Penalize peer slightly for invalids.
                 self.gossip_penalize_peer(peer_id, PeerAction::HighToleranceError);
                 return;
@@ -607,7 +751,12 @@ impl<T: BeaconChainTypes> Worker<T> {
 
         metrics::inc_counter(&metrics::BEACON_PROCESSOR_ATTESTER_SLASHING_VERIFIED_TOTAL);
 
-        self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Accept);
+        self.propagate_validation_result(
+            message_id,
+            peer_id,
+            &GossipKind::AttesterSlashing,
+            MessageAcceptance::Accept,
+        );
 
         // Register the slashing with any monitored validators.
         self.chain
@@ -632,6 +781,7 @@ impl<T: BeaconChainTypes> Worker<T> {
         message_id: MessageId,
         beacon_block_root: Hash256,
         attestation_type: &str,
+        kind: &GossipKind,
         error: AttnError,
     ) {
         metrics::register_attestation_error(&error);
@@ -659,7 +809,12 @@ impl<T: BeaconChainTypes> Worker<T> {
                 self.gossip_penalize_peer(peer_id, PeerAction::LowToleranceError);
 
                 // Do not propagate these messages.
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Ignore);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    kind,
+                    MessageAcceptance::Ignore,
+                );
             }
             AttnError::InvalidSelectionProof { .. } | AttnError::InvalidSignature => {
                 /*
@@ -667,7 +822,12 @@ impl<T: BeaconChainTypes> Worker<T> {
                  *
                  * The peer has published an invalid consensus message.
                  */
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Reject);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    kind,
+                    MessageAcceptance::Reject,
+                );
                 self.gossip_penalize_peer(peer_id, PeerAction::LowToleranceError);
             }
             AttnError::EmptyAggregationBitfield => {
@@ -678,7 +838,12 @@ impl<T: BeaconChainTypes> Worker<T> {
                  * violation of the spec nor indication of fault.
                  *
                  */
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Reject);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    kind,
+                    MessageAcceptance::Reject,
+                );
                 self.gossip_penalize_peer(peer_id, PeerAction::LowToleranceError);
             }
             AttnError::AggregatorPubkeyUnknown(_) => {
@@ -695,7 +860,12 @@ impl<T: BeaconChainTypes> Worker<T> {
                  *
                  * The peer has published an invalid consensus message.
                  */
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Reject);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    kind,
+                    MessageAcceptance::Reject,
+                );
                 self.gossip_penalize_peer(peer_id, PeerAction::LowToleranceError);
             }
             AttnError::AggregatorNotInCommittee { .. } => {
@@ -712,7 +882,12 @@ impl<T: BeaconChainTypes> Worker<T> {
                  *
                  * The peer has published an invalid consensus message.
                  */
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Reject);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    kind,
+                    MessageAcceptance::Reject,
+                );
                 self.gossip_penalize_peer(peer_id, PeerAction::LowToleranceError);
             }
             AttnError::AttestationAlreadyKnown { .. } => {
@@ -729,7 +904,12 @@ impl<T: BeaconChainTypes> Worker<T> {
                     "block" => %beacon_block_root,
                     "type" => ?attestation_type,
                 );
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Ignore);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    kind,
+                    MessageAcceptance::Ignore,
+                );
                 return;
             }
             AttnError::AggregatorAlreadyKnown(_) => {
@@ -747,7 +927,12 @@ impl<T: BeaconChainTypes> Worker<T> {
                     "type" => ?attestation_type,
                 );
                 // This is an allowed behaviour.
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Ignore);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    kind,
+                    MessageAcceptance::Ignore,
+                );
 
                 return;
             }
@@ -768,7 +953,12 @@ impl<T: BeaconChainTypes> Worker<T> {
                 // behaviour.
                 self.gossip_penalize_peer(peer_id, PeerAction::HighToleranceError);
 
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Ignore);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    kind,
+                    MessageAcceptance::Ignore,
+                );
 
                 return;
             }
@@ -786,7 +976,12 @@ impl<T: BeaconChainTypes> Worker<T> {
                     "block" => %beacon_block_root,
                     "type" => ?attestation_type,
                 );
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Reject);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    kind,
+                    MessageAcceptance::Reject,
+                );
                 self.gossip_penalize_peer(peer_id, PeerAction::LowToleranceError);
             }
             AttnError::UnknownHeadBlock { beacon_block_root } => {
@@ -813,7 +1008,12 @@ impl<T: BeaconChainTypes> Worker<T> {
                             "msg" => "UnknownBlockHash"
                         )
                     });
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Ignore);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    kind,
+                    MessageAcceptance::Ignore,
+                );
                 return;
             }
             AttnError::UnknownTargetRoot(_) => {
@@ -833,7 +1033,12 @@ impl<T: BeaconChainTypes> Worker<T> {
                  *
                  * The peer has published an invalid consensus message.
                  */
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Reject);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    kind,
+                    MessageAcceptance::Reject,
+                );
                 self.gossip_penalize_peer(peer_id, PeerAction::LowToleranceError);
             }
             AttnError::BadTargetEpoch => {
@@ -843,7 +1048,12 @@ impl<T: BeaconChainTypes> Worker<T> {
                  *
                  * The peer has published an invalid consensus message.
                  */
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Reject);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    kind,
+                    MessageAcceptance::Reject,
+                );
                 self.gossip_penalize_peer(peer_id, PeerAction::LowToleranceError);
             }
             AttnError::NoCommitteeForSlotAndIndex { .. } => {
@@ -852,7 +1062,12 @@ impl<T: BeaconChainTypes> Worker<T> {
                  *
                  * The peer has published an invalid consensus message.
                  */
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Reject);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    kind,
+                    MessageAcceptance::Reject,
+                );
                 self.gossip_penalize_peer(peer_id, PeerAction::LowToleranceError);
             }
             AttnError::NotExactlyOneAggregationBitSet(_) => {
@@ -861,7 +1076,12 @@ impl<T: BeaconChainTypes> Worker<T> {
                  *
                  * The peer has published an invalid consensus message.
                  */
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Reject);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    kind,
+                    MessageAcceptance::Reject,
+                );
                 self.gossip_penalize_peer(peer_id, PeerAction::LowToleranceError);
             }
             AttnError::AttestsToFutureBlock { .. } => {
@@ -870,7 +1090,12 @@ impl<T: BeaconChainTypes> Worker<T> {
                  *
                  * The peer has published an invalid consensus message.
                  */
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Reject);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    kind,
+                    MessageAcceptance::Reject,
+                );
                 self.gossip_penalize_peer(peer_id, PeerAction::LowToleranceError);
             }
 
@@ -884,7 +1109,12 @@ impl<T: BeaconChainTypes> Worker<T> {
                     "expected" => ?expected,
                     "received" => ?received,
                 );
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Reject);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    kind,
+                    MessageAcceptance::Reject,
+                );
                 self.gossip_penalize_peer(peer_id, PeerAction::LowToleranceError);
             }
             AttnError::Invalid(_) => {
@@ -893,7 +1123,12 @@ impl<T: BeaconChainTypes> Worker<T> {
                  *
                  * The peer has published an invalid consensus message.
                  */
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Reject);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    kind,
+                    MessageAcceptance::Reject,
+                );
                 self.gossip_penalize_peer(peer_id, PeerAction::LowToleranceError);
             }
             AttnError::InvalidTargetEpoch { .. } => {
@@ -902,7 +1137,12 @@ impl<T: BeaconChainTypes> Worker<T> {
                  *
                  * The peer has published an invalid consensus message.
                  */
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Reject);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    kind,
+                    MessageAcceptance::Reject,
+                );
                 self.gossip_penalize_peer(peer_id, PeerAction::LowToleranceError);
             }
             AttnError::InvalidTargetRoot { .. } => {
@@ -911,7 +1151,12 @@ impl<T: BeaconChainTypes> Worker<T> {
                  *
                  * The peer has published an invalid consensus message.
                  */
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Reject);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    kind,
+                    MessageAcceptance::Reject,
+                );
                 self.gossip_penalize_peer(peer_id, PeerAction::LowToleranceError);
             }
             AttnError::TooManySkippedSlots {
@@ -931,7 +1176,12 @@ impl<T: BeaconChainTypes> Worker<T> {
                 );
                 // In this case we wish to penalize gossipsub peers that do this to avoid future
                 // attestations that have too many skip slots.
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Reject);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    kind,
+                    MessageAcceptance::Reject,
+                );
                 self.gossip_penalize_peer(peer_id, PeerAction::MidToleranceError);
             }
             AttnError::BeaconChainError(e) => {
@@ -948,7 +1198,12 @@ impl<T: BeaconChainTypes> Worker<T> {
                     "peer_id" => %peer_id,
                     "error" => ?e,
                 );
-                self.propagate_validation_result(message_id, peer_id, MessageAcceptance::Ignore);
+                self.propagate_validation_result(
+                    message_id,
+                    peer_id,
+                    kind,
+                    MessageAcceptance::Ignore,
+                );
                 // Penalize the peer slightly
                 self.gossip_penalize_peer(peer_id, PeerAction::HighToleranceError);
             }
@@ -1,6 +1,7 @@
 use super::QueuedBlock;
 use crate::{service::NetworkMessage, sync::SyncMessage};
 use beacon_chain::{BeaconChain, BeaconChainTypes};
+use eth2_libp2p::NetworkGlobals;
 use slog::{error, Logger};
 use std::sync::Arc;
 use tokio::sync::mpsc;
@@ -11,13 +12,12 @@ mod sync_methods;
 
 pub use sync_methods::ProcessId;
 
-pub(crate) const FUTURE_SLOT_TOLERANCE: u64 = 1;
-
 /// Contains the context necessary to import blocks, attestations, etc to the beacon chain.
 pub struct Worker<T: BeaconChainTypes> {
     pub chain: Arc<BeaconChain<T>>,
     pub network_tx: mpsc::UnboundedSender<NetworkMessage<T::EthSpec>>,
     pub sync_tx: mpsc::UnboundedSender<SyncMessage<T::EthSpec>>,
+    pub network_globals: Arc<NetworkGlobals<T::EthSpec>>,
     pub log: Logger,
 }
 
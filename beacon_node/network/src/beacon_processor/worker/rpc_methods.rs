@@ -1,4 +1,3 @@
-use crate::beacon_processor::worker::FUTURE_SLOT_TOLERANCE;
 use crate::service::NetworkMessage;
 use crate::status::ToStatusMessage;
 use crate::sync::SyncMessage;
@@ -61,7 +60,7 @@ impl<T: BeaconChainTypes> Worker<T> {
                 .chain
                 .slot()
                 .unwrap_or_else(|_| self.chain.slot_clock.genesis_slot())
-                + FUTURE_SLOT_TOLERANCE
+                + self.chain.config.future_slot_tolerance
         {
             // The remote's head is on a slot that is significantly ahead of what we consider the
             // current slot. This could be because they are using a different genesis time, or that
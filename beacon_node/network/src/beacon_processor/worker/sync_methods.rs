@@ -1,5 +1,4 @@
 use super::Worker;
-use crate::beacon_processor::worker::FUTURE_SLOT_TOLERANCE;
 use crate::beacon_processor::BlockResultSender;
 use crate::metrics;
 use crate::sync::manager::SyncMessage;
@@ -184,14 +183,15 @@ impl<T: BeaconChainTypes> Worker<T> {
                 present_slot,
                 block_slot,
             } => {
-                if present_slot + FUTURE_SLOT_TOLERANCE >= block_slot {
+                let future_slot_tolerance = self.chain.config.future_slot_tolerance;
+                if present_slot + future_slot_tolerance >= block_slot {
                     // The block is too far in the future, drop it.
                     warn!(
                         self.log, "Block is ahead of our slot clock";
                         "msg" => "block for future slot rejected, check your time",
                         "present_slot" => present_slot,
                         "block_slot" => block_slot,
-                        "FUTURE_SLOT_TOLERANCE" => FUTURE_SLOT_TOLERANCE,
+                        "future_slot_tolerance" => future_slot_tolerance,
                     );
                 } else {
                     // The block is in the future, but not too far.
@@ -199,7 +199,7 @@ impl<T: BeaconChainTypes> Worker<T> {
                         self.log, "Block is slightly ahead of our slot clock, ignoring.";
                         "present_slot" => present_slot,
                         "block_slot" => block_slot,
-                        "FUTURE_SLOT_TOLERANCE" => FUTURE_SLOT_TOLERANCE,
+                        "future_slot_tolerance" => future_slot_tolerance,
                     );
                 }
 
@@ -1,7 +1,8 @@
 use beacon_chain::attestation_verification::Error as AttnError;
 use eth2_libp2p::PubsubMessage;
 use eth2_libp2p::{
-    types::GossipKind, BandwidthSinks, GossipTopic, Gossipsub, NetworkGlobals, TopicHash,
+    types::GossipKind, BandwidthSinks, GossipTopic, Gossipsub, MessageAcceptance, NetworkGlobals,
+    TopicHash,
 };
 use fnv::FnvHashMap;
 pub use lighthouse_metrics::*;
@@ -169,17 +170,40 @@ lazy_static! {
     pub static ref BEACON_PROCESSOR_WORK_EVENTS_RX_COUNT: Result<IntCounterVec> = try_create_int_counter_vec(
         "beacon_processor_work_events_rx_count",
         "Count of work events received (but not necessarily processed)",
+        &["type", "origin"]
+    );
+    pub static ref BEACON_PROCESSOR_QUEUE_DEPTH: Result<IntGaugeVec> = try_create_int_gauge_vec(
+        "beacon_processor_queue_depth",
+        "Count of work events sent to the beacon processor which have not yet been started by a worker",
+        &["type"]
+    );
+    pub static ref BEACON_PROCESSOR_DROPPED_WORK_TOTAL: Result<IntCounterVec> = try_create_int_counter_vec(
+        "beacon_processor_dropped_work_total",
+        "Count of work events dropped because the beacon processor channel or work queue was full",
         &["type"]
     );
+    pub static ref BEACON_PROCESSOR_QUEUE_WAIT_SECONDS: Result<HistogramVec> =
+        try_create_histogram_vec_with_buckets(
+            "beacon_processor_queue_wait_seconds",
+            "Time between a work event being created and a worker starting to process it",
+            // 10ms to ~10s.
+            exponential_buckets(0.01, 2.0, 11),
+            &["type"]
+        );
     pub static ref BEACON_PROCESSOR_WORK_EVENTS_IGNORED_COUNT: Result<IntCounterVec> = try_create_int_counter_vec(
         "beacon_processor_work_events_ignored_count",
         "Count of work events purposefully ignored",
-        &["type"]
+        &["type", "origin"]
+    );
+    pub static ref BEACON_PROCESSOR_WORK_EVENTS_REJECTED_COUNT: Result<IntCounterVec> = try_create_int_counter_vec(
+        "beacon_processor_work_events_rejected_count",
+        "Count of work events rejected because they were too expensive to queue whilst congested",
+        &["type", "origin"]
     );
     pub static ref BEACON_PROCESSOR_WORK_EVENTS_STARTED_COUNT: Result<IntCounterVec> = try_create_int_counter_vec(
         "beacon_processor_work_events_started_count",
         "Count of work events which have been started by a worker",
-        &["type"]
+        &["type", "origin"]
     );
     pub static ref BEACON_PROCESSOR_WORKER_TIME: Result<HistogramVec> = try_create_histogram_vec(
         "beacon_processor_worker_time",
@@ -266,6 +290,15 @@ lazy_static! {
         "beacon_processor_attester_slashing_error_total",
         "Total number of attester slashings that raised an error during processing."
     );
+    // Blocks by range requests.
+    pub static ref BEACON_PROCESSOR_BLOCKS_BY_RANGE_QUEUE_TOTAL: Result<IntGauge> = try_create_int_gauge(
+        "beacon_processor_blocks_by_range_queue_total",
+        "Count of blocks by range requests waiting to be served."
+    );
+    pub static ref BEACON_PROCESSOR_LOW_PRIORITY_BLOCKS_BY_RANGE_QUEUE_TOTAL: Result<IntGauge> = try_create_int_gauge(
+        "beacon_processor_low_priority_blocks_by_range_queue_total",
+        "Count of blocks by range requests for historical ranges waiting to be served."
+    );
     // Rpc blocks.
     pub static ref BEACON_PROCESSOR_RPC_BLOCK_QUEUE_TOTAL: Result<IntGauge> = try_create_int_gauge(
         "beacon_processor_rpc_block_queue_total",
@@ -314,6 +347,16 @@ lazy_static! {
         "beacon_processor_aggregated_attestation_imported_total",
         "Total number of aggregated attestations imported to fork choice, etc."
     );
+    pub static ref GOSSIP_AGGREGATE_EFFICIENCY: Result<Histogram> =
+        try_create_histogram_with_buckets(
+            "gossip_aggregate_efficiency",
+            "The fraction of the committee covered by each valid aggregate received from gossip.",
+            Ok(vec![0.01, 0.05, 0.1, 0.25, 0.5, 0.75, 0.9, 1.0])
+        );
+    pub static ref GOSSIP_AGGREGATE_INEFFICIENT_TOTAL: Result<IntCounter> = try_create_int_counter(
+        "gossip_aggregate_inefficient_total",
+        "Count of valid aggregates from gossip below the minimum aggregate attestation efficiency."
+    );
 }
 
 lazy_static! {
@@ -323,6 +366,11 @@ lazy_static! {
             "Gossipsub attestation errors per error type",
             &["type"]
         );
+    pub static ref GOSSIP_MESSAGES_TOTAL: Result<IntCounterVec> = try_create_int_counter_vec(
+        "gossip_messages_total",
+        "Count of gossip messages seen per topic and the result of their validation",
+        &["topic", "result"]
+    );
     pub static ref INBOUND_LIBP2P_BYTES: Result<IntGauge> =
         try_create_int_gauge("libp2p_inbound_bytes", "The inbound bandwidth over libp2p");
     pub static ref OUTBOUND_LIBP2P_BYTES: Result<IntGauge> = try_create_int_gauge(
@@ -370,12 +418,33 @@ lazy_static! {
         "beacon_block_gossip_slot_start_delay_time",
         "Duration between when the block is received and the start of the slot it belongs to.",
     );
+    pub static ref BEACON_REPROCESS_QUEUE_LENGTH: Result<IntGauge> = try_create_int_gauge(
+        "beacon_reprocess_queue_length",
+        "Count of early blocks waiting in the delay queue to be re-processed."
+    );
+    pub static ref BEACON_REPROCESS_QUEUE_OVERFLOW_TOTAL: Result<IntCounter> = try_create_int_counter(
+        "beacon_reprocess_queue_overflow_total",
+        "Total number of early blocks dropped because the delay queue was full."
+    );
 }
 
 pub fn register_attestation_error(error: &AttnError) {
     inc_counter_vec(&GOSSIP_ATTESTATION_ERRORS_PER_TYPE, &[error.as_ref()]);
 }
 
+pub fn register_gossip_message_seen(kind: &GossipKind) {
+    inc_counter_vec(&GOSSIP_MESSAGES_TOTAL, &[kind.as_ref(), "seen"]);
+}
+
+pub fn register_gossip_validation_result(kind: &GossipKind, validation_result: &MessageAcceptance) {
+    let result = match validation_result {
+        MessageAcceptance::Accept => "accepted",
+        MessageAcceptance::Reject => "rejected_invalid",
+        MessageAcceptance::Ignore => "rejected_ignore",
+    };
+    inc_counter_vec(&GOSSIP_MESSAGES_TOTAL, &[kind.as_ref(), result]);
+}
+
 /// Inspects the `messages` that were being sent to the network and updates Prometheus metrics.
 pub fn expose_publish_metrics<T: EthSpec>(messages: &[PubsubMessage<T>]) {
     for message in messages {
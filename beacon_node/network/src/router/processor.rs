@@ -1,12 +1,12 @@
 use crate::beacon_processor::{
-    BeaconProcessor, WorkEvent as BeaconWorkEvent, MAX_WORK_EVENT_QUEUE_LEN,
+    try_send_work_event, BeaconProcessor, WorkEvent as BeaconWorkEvent, MAX_WORK_EVENT_QUEUE_LEN,
 };
 use crate::service::NetworkMessage;
 use crate::sync::SyncMessage;
 use beacon_chain::{BeaconChain, BeaconChainError, BeaconChainTypes};
 use eth2_libp2p::rpc::*;
 use eth2_libp2p::{MessageId, NetworkGlobals, PeerId, PeerRequestId, Request, Response};
-use slog::{debug, error, o, trace, warn};
+use slog::{crit, debug, error, o, trace, warn};
 use std::cmp;
 use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
@@ -38,6 +38,7 @@ impl<T: BeaconChainTypes> Processor<T> {
         beacon_chain: Arc<BeaconChain<T>>,
         network_globals: Arc<NetworkGlobals<T::EthSpec>>,
         network_send: mpsc::UnboundedSender<NetworkMessage<T::EthSpec>>,
+        admission_cost_threshold: Option<Duration>,
         log: &slog::Logger,
     ) -> Self {
         let sync_logger = log.new(o!("service"=> "sync"));
@@ -62,6 +63,7 @@ impl<T: BeaconChainTypes> Processor<T> {
             executor,
             max_workers: cmp::max(1, num_cpus::get()),
             current_workers: 0,
+            admission_cost_threshold,
             log: log.clone(),
         }
         .spawn_manager(beacon_processor_receive, None);
@@ -310,16 +312,17 @@ impl<T: BeaconChainTypes> Processor<T> {
     }
 
     fn send_beacon_processor_work(&mut self, work: BeaconWorkEvent<T>) {
-        self.beacon_processor_send
-            .try_send(work)
-            .unwrap_or_else(|e| {
-                let work_type = match &e {
-                    mpsc::error::TrySendError::Closed(work)
-                    | mpsc::error::TrySendError::Full(work) => work.work_type(),
-                };
+        try_send_work_event(&self.beacon_processor_send, work).unwrap_or_else(|e| match e {
+            mpsc::error::TrySendError::Full(work) => {
+                crit!(&self.log, "Beacon processor channel full, dropping work";
+                    "type" => work.work_type(), "origin" => work.origin().as_str())
+            }
+            mpsc::error::TrySendError::Closed(work) => {
                 error!(&self.log, "Unable to send message to the beacon processor";
-                    "error" => %e, "type" => work_type)
-            })
+                    "error" => "channel closed", "type" => work.work_type(),
+                    "origin" => work.origin().as_str())
+            }
+        })
     }
 }
 
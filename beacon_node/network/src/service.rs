@@ -190,6 +190,9 @@ impl<T: BeaconChainTypes> NetworkService<T> {
             network_globals.clone(),
             network_send.clone(),
             executor.clone(),
+            config
+                .beacon_processor_admission_cost_threshold_ms
+                .map(Duration::from_millis),
             network_log.clone(),
         )?;
 
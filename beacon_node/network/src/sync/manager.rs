@@ -37,7 +37,7 @@ use super::network_context::SyncNetworkContext;
 use super::peer_sync_info::{remote_sync_type, PeerSyncType};
 use super::range_sync::{ChainId, RangeSync, RangeSyncType, EPOCHS_PER_BATCH};
 use super::RequestId;
-use crate::beacon_processor::{ProcessId, WorkEvent as BeaconWorkEvent};
+use crate::beacon_processor::{try_send_work_event, ProcessId, WorkEvent as BeaconWorkEvent};
 use crate::service::NetworkMessage;
 use crate::status::ToStatusMessage;
 use beacon_chain::{BeaconChain, BeaconChainTypes, BlockError};
@@ -98,6 +98,14 @@ pub enum SyncMessage<T: EthSpec> {
     /// manager to attempt to find the block matching the unknown hash.
     UnknownBlockHash(PeerId, Hash256),
 
+    /// A peer has sent us a valid gossip block, so its head is at least as advanced as that block.
+    /// This lets sync learn of newer peer heads between STATUS exchanges.
+    PeerHeadUpdated {
+        peer_id: PeerId,
+        slot: Slot,
+        root: Hash256,
+    },
+
     /// A peer has disconnected.
     Disconnect(PeerId),
 
@@ -284,6 +292,31 @@ impl<T: BeaconChainTypes> SyncManager<T> {
         self.update_sync_state();
     }
 
+    /// A peer has gossiped a block at `slot`, which may be newer than the head it reported in its
+    /// last STATUS.
+    ///
+    /// If so, the peer's known head is updated and it is re-evaluated as though it had sent a new
+    /// STATUS, which starts a range sync if the peer is now sufficiently ahead of us. Peers we
+    /// have not yet STATUS'd are ignored, as their finalized checkpoint is unknown.
+    fn peer_head_updated(&mut self, peer_id: PeerId, slot: Slot, root: Hash256) {
+        let known_info = self
+            .network_globals
+            .peers
+            .read()
+            .peer_info(&peer_id)
+            .and_then(|info| info.sync_status.info().cloned());
+
+        if let Some(mut remote) = known_info {
+            if slot > remote.head_slot {
+                trace!(self.log, "Peer head updated from gossip"; "peer_id" => %peer_id,
+                    "old_head_slot" => remote.head_slot, "new_head_slot" => slot);
+                remote.head_slot = slot;
+                remote.head_root = root;
+                self.add_peer(peer_id, remote);
+            }
+        }
+    }
+
     /// The response to a `BlocksByRoot` request.
     /// The current implementation takes one block at a time. As blocks are streamed, any
     /// subsequent blocks will simply be ignored.
@@ -393,7 +426,7 @@ impl<T: BeaconChainTypes> SyncManager<T> {
         block: SignedBeaconBlock<T::EthSpec>,
     ) -> Option<Result<Hash256, BlockError<T::EthSpec>>> {
         let (event, rx) = BeaconWorkEvent::rpc_beacon_block(Box::new(block));
-        match self.beacon_processor_send.try_send(event) {
+        match try_send_work_event(&self.beacon_processor_send, event) {
             Ok(_) => {}
             Err(e) => {
                 error!(
@@ -771,10 +804,10 @@ impl<T: BeaconChainTypes> SyncManager<T> {
                     );
                     let blocks = parent_request.downloaded_blocks;
 
-                    match self
-                        .beacon_processor_send
-                        .try_send(BeaconWorkEvent::chain_segment(process_id, blocks))
-                    {
+                    match try_send_work_event(
+                        &self.beacon_processor_send,
+                        BeaconWorkEvent::chain_segment(process_id, blocks),
+                    ) {
                         Ok(_) => {}
                         Err(e) => {
                             error!(
@@ -904,6 +937,13 @@ impl<T: BeaconChainTypes> SyncManager<T> {
                     SyncMessage::UnknownBlockHash(peer_id, block_hash) => {
                         self.search_for_block(peer_id, block_hash);
                     }
+                    SyncMessage::PeerHeadUpdated {
+                        peer_id,
+                        slot,
+                        root,
+                    } => {
+                        self.peer_head_updated(peer_id, slot, root);
+                    }
                     SyncMessage::Disconnect(peer_id) => {
                         self.peer_disconnect(&peer_id);
                     }
@@ -1,6 +1,5 @@
 use super::batch::{BatchInfo, BatchState};
-use crate::beacon_processor::ProcessId;
-use crate::beacon_processor::WorkEvent as BeaconWorkEvent;
+use crate::beacon_processor::{try_send_work_event, ProcessId, WorkEvent as BeaconWorkEvent};
 use crate::sync::{network_context::SyncNetworkContext, BatchProcessResult, RequestId};
 use beacon_chain::BeaconChainTypes;
 use eth2_libp2p::{PeerAction, PeerId};
@@ -303,10 +302,10 @@ impl<T: BeaconChainTypes> SyncingChain<T> {
         let process_id = ProcessId::RangeBatchId(self.id, batch_id);
         self.current_processing_batch = Some(batch_id);
 
-        if let Err(e) = self
-            .beacon_processor_send
-            .try_send(BeaconWorkEvent::chain_segment(process_id, blocks))
-        {
+        if let Err(e) = try_send_work_event(
+            &self.beacon_processor_send,
+            BeaconWorkEvent::chain_segment(process_id, blocks),
+        ) {
             crit!(self.log, "Failed to send chain segment to processor."; "msg" => "process_batch",
                 "error" => %e, "batch" => self.processing_target);
             // This is unlikely to happen but it would stall syncing since the batch now has no
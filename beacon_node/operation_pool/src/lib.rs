@@ -23,8 +23,8 @@ use std::marker::PhantomData;
 use std::ptr;
 use types::{
     typenum::Unsigned, Attestation, AttesterSlashing, BeaconState, BeaconStateError, ChainSpec,
-    Epoch, EthSpec, Fork, ForkVersion, Hash256, ProposerSlashing, RelativeEpoch,
-    SignedVoluntaryExit, Validator,
+    CommitteeIndex, Epoch, EthSpec, Fork, ForkVersion, Hash256, ProposerSlashing, RelativeEpoch,
+    SignedVoluntaryExit, Slot, Validator,
 };
 #[derive(Default, Debug)]
 pub struct OperationPool<T: EthSpec + Default> {
@@ -97,6 +97,17 @@ impl<T: EthSpec> OperationPool<T> {
         self.attestations.read().values().map(Vec::len).sum()
     }
 
+    /// Number of attestations in the pool for each `(slot, committee_index)`.
+    pub fn num_attestations_by_committee(&self) -> HashMap<(Slot, CommitteeIndex), usize> {
+        let mut counts = HashMap::new();
+        for attestation in self.attestations.read().values().flatten() {
+            *counts
+                .entry((attestation.data.slot, attestation.data.index))
+                .or_default() += 1;
+        }
+        counts
+    }
+
     /// Return all valid attestations for the given epoch, for use in max cover.
     fn get_valid_attestations_for_epoch<'a>(
         &'a self,
@@ -83,6 +83,119 @@ pub fn cli_app<'a, 'b>() -> App<'a, 'b> {
                 .default_value("50")
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name("network-max-concurrent-rpc-requests-per-peer")
+                .long("network-max-concurrent-rpc-requests-per-peer")
+                .value_name("COUNT")
+                .help("The maximum number of RPC requests a single peer may have in flight at \
+                       once. Any further requests are rejected until one completes.")
+                .default_value("4")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("rpc-range-request-limit")
+                .long("rpc-range-request-limit")
+                .value_name("BLOCKS")
+                .help("The number of blocks a single peer may request via BlocksByRange every \
+                       10 seconds. Requests beyond this rate are rejected.")
+                .default_value("1024")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("rpc-range-burst")
+                .long("rpc-range-burst")
+                .value_name("BLOCKS")
+                .help("The maximum number of blocks a single peer may request via BlocksByRange \
+                       in a single burst before being rate limited. Requests for more blocks than \
+                       this are always rejected, so it must be at least 64.")
+                .default_value("1024")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("network-ping-interval")
+                .long("network-ping-interval")
+                .value_name("SECONDS")
+                .help("The number of seconds between PINGs sent to each outbound peer. Inbound \
+                       peers are PING'd 5 seconds later than this. Lower values detect dead \
+                       connections sooner at the cost of extra traffic. Must be at least 1.")
+                .default_value("30")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("network-yamux-max-stream-buffer-size")
+                .long("network-yamux-max-stream-buffer-size")
+                .value_name("BYTES")
+                .help("The maximum number of bytes buffered for each yamux stream. Lower values \
+                       reduce memory usage when many RPC streams are open at once.")
+                .default_value("16777216")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("network-yamux-window-size")
+                .long("network-yamux-window-size")
+                .value_name("BYTES")
+                .help("The yamux flow-control receive window for each stream. Must be at least \
+                       262144 bytes.")
+                .default_value("262144")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("network-gossip-heartbeat-interval")
+                .long("network-gossip-heartbeat-interval")
+                .value_name("MILLISECONDS")
+                .help("The interval between gossipsub heartbeats, which maintain the mesh of \
+                       peers for each topic by grafting and pruning peers.")
+                .default_value("700")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("network-gossip-mesh-n-low")
+                .long("network-gossip-mesh-n-low")
+                .value_name("PEERS")
+                .help("The number of peers in a gossipsub topic mesh below which more peers \
+                       are grafted during a heartbeat.")
+                .default_value("6")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("network-gossip-mesh-n")
+                .long("network-gossip-mesh-n")
+                .value_name("PEERS")
+                .help("The target number of peers in each gossipsub topic mesh.")
+                .default_value("8")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("network-gossip-mesh-n-high")
+                .long("network-gossip-mesh-n-high")
+                .value_name("PEERS")
+                .help("The number of peers in a gossipsub topic mesh above which peers are \
+                       pruned during a heartbeat.")
+                .default_value("12")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("network-gossip-flood-publish")
+                .long("network-gossip-flood-publish")
+                .value_name("BOOLEAN")
+                .help("If true, the messages published by this node (e.g., blocks and \
+                       attestations) are sent to all peers subscribed to the topic, rather than \
+                       just the topic mesh. This reduces propagation latency at the cost of \
+                       bandwidth. Applies to all topics.")
+                .possible_values(&["true", "false"])
+                .default_value("true")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("beacon-processor-admission-cost-threshold-ms")
+                .long("beacon-processor-admission-cost-threshold-ms")
+                .value_name("MILLISECONDS")
+                .help("When the queues of work received from the network are congested, only \
+                       accept new work which is expected to take less than this many \
+                       milliseconds to process. Blocks requested by sync are always \
+                       accepted. By default, no work is rejected on this basis.")
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name("boot-nodes")
                 .long("boot-nodes")
@@ -198,6 +311,15 @@ pub fn cli_app<'a, 'b>() -> App<'a, 'b> {
                     address of this server (e.g., http://localhost:5052).")
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name("http-max-response-body-size")
+                .long("http-max-response-body-size")
+                .value_name("BYTES")
+                .help("The maximum size of a response body returned by the RESTful HTTP API \
+                    server. Larger responses are replaced with a 413 error.")
+                .default_value("2147483648")
+                .takes_value(true),
+        )
         /* Prometheus metrics HTTP server related arguments */
         .arg(
             Arg::with_name("metrics")
@@ -231,6 +353,15 @@ pub fn cli_app<'a, 'b>() -> App<'a, 'b> {
                     address of this server (e.g., http://localhost:5054).")
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name("http-metrics-auth-token")
+                .long("http-metrics-auth-token")
+                .value_name("TOKEN")
+                .help("Require requests to the metrics server to provide this token in an \
+                    `Authorization: Bearer <TOKEN>` header. If no value is supplied, the \
+                    metrics server does not require authorization.")
+                .takes_value(true),
+        )
 
         /*
          * Monitoring metrics
@@ -297,6 +428,19 @@ pub fn cli_app<'a, 'b>() -> App<'a, 'b> {
                        Defaults to http://127.0.0.1:8545.")
                 .takes_value(true)
         )
+        .arg(
+            Arg::with_name("eth1-endpoint-priority")
+                .long("eth1-endpoint-priority")
+                .value_name("HTTP-ENDPOINT,PRIORITY")
+                .help("Sets the priority of an eth1 endpoint, adding it to the --eth1-endpoints if \
+                       it is not already present. Endpoints with a lower priority are tried \
+                       first, endpoints with the same priority are tried in the order they were \
+                       given. Endpoints without a priority have priority 0. May be supplied \
+                       multiple times. Also enables the --eth1 flag.")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+        )
         .arg(
             Arg::with_name("eth1-purge-cache")
                 .long("eth1-purge-cache")
@@ -304,6 +448,14 @@ pub fn cli_app<'a, 'b>() -> App<'a, 'b> {
                 .help("Purges the eth1 block and deposit caches")
                 .takes_value(false)
         )
+        .arg(
+            Arg::with_name("disable-deposit-processing")
+                .long("disable-deposit-processing")
+                .help("Never contact the eth1 node or process new deposits. Blocks produced by \
+                       this node will vote for the existing Eth1Data and include no deposits. \
+                       Only suitable for networks where all deposits have been processed.")
+                .takes_value(false)
+        )
         .arg(
             Arg::with_name("eth1-blocks-per-log-query")
                 .long("eth1-blocks-per-log-query")
@@ -376,6 +528,27 @@ pub fn cli_app<'a, 'b>() -> App<'a, 'b> {
                 .value_name("NUM_SLOTS")
                 .takes_value(true)
         )
+        .arg(
+            Arg::with_name("future-slot-tolerance")
+                .long("future-slot-tolerance")
+                .help(
+                    "The number of slots a peer's head may be ahead of our clock before the peer \
+                    is considered to be on a different clock or genesis time. Larger values \
+                    accommodate clock skew on networks with very short slots. At most 4."
+                )
+                .value_name("NUM_SLOTS")
+                .default_value("1")
+                .takes_value(true)
+        )
+        .arg(
+            Arg::with_name("verify-tree-consistency")
+                .long("verify-tree-consistency")
+                .help(
+                    "Check that every block known to fork choice and its state are present in \
+                    the database during start-up. Any missing data is logged."
+                )
+                .takes_value(false)
+        )
         /*
          * Slasher.
          */
@@ -450,6 +623,17 @@ pub fn cli_app<'a, 'b>() -> App<'a, 'b> {
                 .requires("slasher")
                 .takes_value(true)
         )
+        .arg(
+            Arg::with_name("slasher-validators-per-batch")
+                .long("slasher-validators-per-batch")
+                .help(
+                    "Maximum number of validators whose attestations are checked for slashings \
+                     in each batch update. Lower values spread the work across more updates."
+                )
+                .value_name("NUM_VALIDATORS")
+                .requires("slasher")
+                .takes_value(true)
+        )
         .arg(
             Arg::with_name("slasher-broadcast")
                 .long("slasher-broadcast")
@@ -1,8 +1,11 @@
+use beacon_chain::chain_config::MAX_FUTURE_SLOT_TOLERANCE;
 use clap::ArgMatches;
 use clap_utils::{flags::DISABLE_MALLOC_TUNING_FLAG, BAD_TESTNET_DIR_MESSAGE};
 use client::{ClientConfig, ClientGenesis};
 use directory::{DEFAULT_BEACON_NODE_DIR, DEFAULT_NETWORK_DIR, DEFAULT_ROOT_DIR};
-use eth2_libp2p::{multiaddr::Protocol, Enr, Multiaddr, NetworkConfig, PeerIdSerialized};
+use eth2_libp2p::{
+    multiaddr::Protocol, Enr, Multiaddr, NetworkConfig, PeerIdSerialized, MIN_YAMUX_WINDOW_SIZE,
+};
 use eth2_network_config::{Eth2NetworkConfig, DEFAULT_HARDCODED_NETWORK};
 use sensitive_url::SensitiveUrl;
 use slog::{info, warn, Logger};
@@ -13,6 +16,7 @@ use std::net::{IpAddr, Ipv4Addr, ToSocketAddrs};
 use std::net::{TcpListener, UdpSocket};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::Duration;
 use types::{ChainSpec, Checkpoint, Epoch, EthSpec, Hash256, PublicKeyBytes, GRAFFITI_BYTES_LEN};
 
 /// Gets the fully-initialized global client.
@@ -107,6 +111,12 @@ pub fn get_config<E: EthSpec>(
         client_config.http_api.allow_origin = Some(allow_origin.to_string());
     }
 
+    if let Some(max_size) = cli_args.value_of("http-max-response-body-size") {
+        client_config.http_api.max_response_body_size = max_size
+            .parse::<u64>()
+            .map_err(|_| "http-max-response-body-size is not a valid u64.")?;
+    }
+
     /*
      * Prometheus metrics HTTP server
      */
@@ -136,6 +146,10 @@ pub fn get_config<E: EthSpec>(
         client_config.http_metrics.allow_origin = Some(allow_origin.to_string());
     }
 
+    if let Some(auth_token) = cli_args.value_of("http-metrics-auth-token") {
+        client_config.http_metrics.auth_token = Some(auth_token.to_string());
+    }
+
     /*
      * Explorer metrics
      */
@@ -198,6 +212,33 @@ pub fn get_config<E: EthSpec>(
             .map_err(|e| format!("eth1-endpoints contains an invalid URL {:?}", e))?;
     }
 
+    if let Some(values) = cli_args.values_of("eth1-endpoint-priority") {
+        // Endpoints which are only given a priority replace the default endpoint.
+        if !cli_args.is_present("eth1-endpoint") && !cli_args.is_present("eth1-endpoints") {
+            client_config.eth1.endpoints.clear();
+        }
+        client_config.sync_eth1_chain = true;
+
+        for value in values {
+            let (endpoint, priority) = parse_eth1_endpoint_priority(value)?;
+            let eth1 = &mut client_config.eth1;
+
+            let index = if let Some(index) = eth1
+                .endpoints
+                .iter()
+                .position(|existing| existing.full == endpoint.full)
+            {
+                index
+            } else {
+                eth1.endpoints.push(endpoint);
+                eth1.endpoints.len() - 1
+            };
+
+            eth1.endpoint_priorities.resize(eth1.endpoints.len(), 0);
+            eth1.endpoint_priorities[index] = priority;
+        }
+    }
+
     if let Some(val) = cli_args.value_of("eth1-blocks-per-log-query") {
         client_config.eth1.blocks_per_log_query = val
             .parse()
@@ -208,6 +249,13 @@ pub fn get_config<E: EthSpec>(
         client_config.eth1.purge_cache = true;
     }
 
+    // Block production still requires an eth1 backend, but it will never contact the eth1 node
+    // whilst deposit processing is disabled.
+    if cli_args.is_present("disable-deposit-processing") {
+        client_config.sync_eth1_chain = true;
+        client_config.eth1.disable_deposit_processing = true;
+    }
+
     if let Some(freezer_dir) = cli_args.value_of("freezer-dir") {
         client_config.freezer_db_path = Some(PathBuf::from(freezer_dir));
     }
@@ -363,6 +411,23 @@ pub fn get_config<E: EthSpec>(
         };
     }
 
+    if let Some(tolerance_str) = cli_args.value_of("future-slot-tolerance") {
+        let tolerance = tolerance_str
+            .parse::<u64>()
+            .map_err(|_| format!("Invalid future-slot-tolerance: {}", tolerance_str))?;
+        if tolerance > MAX_FUTURE_SLOT_TOLERANCE {
+            return Err(format!(
+                "The future-slot-tolerance must be at most {}",
+                MAX_FUTURE_SLOT_TOLERANCE
+            ));
+        }
+        client_config.chain.future_slot_tolerance = tolerance;
+    }
+
+    if cli_args.is_present("verify-tree-consistency") {
+        client_config.chain.verify_tree_consistency = true;
+    }
+
     if cli_args.is_present("slasher") {
         let slasher_dir = if let Some(slasher_dir) = cli_args.value_of("slasher-dir") {
             PathBuf::from(slasher_dir)
@@ -399,6 +464,12 @@ pub fn get_config<E: EthSpec>(
             slasher_config.validator_chunk_size = validator_chunk_size;
         }
 
+        if let Some(validators_per_batch) =
+            clap_utils::parse_optional(cli_args, "slasher-validators-per-batch")?
+        {
+            slasher_config.validators_per_batch = validators_per_batch;
+        }
+
         slasher_config.broadcast = cli_args.is_present("slasher-broadcast");
 
         client_config.slasher = Some(slasher_config);
@@ -440,6 +511,31 @@ pub fn get_config<E: EthSpec>(
     Ok(client_config)
 }
 
+/// Parses a `--eth1-endpoint-priority` value of the form `<url>,<priority>`.
+///
+/// A comma is used rather than a colon so that the priority cannot be confused with the port of
+/// the URL.
+fn parse_eth1_endpoint_priority(value: &str) -> Result<(SensitiveUrl, u64), String> {
+    let split = value.rfind(',').ok_or_else(|| {
+        format!(
+            "eth1-endpoint-priority {} is not of the form URL,PRIORITY",
+            value
+        )
+    })?;
+    let (url, priority) = (&value[..split], &value[split + 1..]);
+
+    let endpoint = SensitiveUrl::parse(url)
+        .map_err(|e| format!("eth1-endpoint-priority contains an invalid URL {:?}", e))?;
+    let priority = priority.parse().map_err(|_| {
+        format!(
+            "eth1-endpoint-priority has an invalid priority {}",
+            priority
+        )
+    })?;
+
+    Ok((endpoint, priority))
+}
+
 /// Sets the network config from the command line arguments
 pub fn set_network_config(
     config: &mut NetworkConfig,
@@ -476,6 +572,102 @@ pub fn set_network_config(
             .map_err(|_| format!("Invalid number of target peers: {}", target_peers_str))?;
     }
 
+    if let Some(max_requests_str) =
+        cli_args.value_of("network-max-concurrent-rpc-requests-per-peer")
+    {
+        config.max_concurrent_rpc_requests_per_peer =
+            max_requests_str.parse::<usize>().map_err(|_| {
+                format!(
+                    "Invalid maximum number of concurrent RPC requests: {}",
+                    max_requests_str
+                )
+            })?;
+    }
+
+    if let Some(limit_str) = cli_args.value_of("rpc-range-request-limit") {
+        config.rpc_range_request_limit = limit_str
+            .parse::<u64>()
+            .map_err(|_| format!("Invalid RPC range request limit: {}", limit_str))?;
+    }
+
+    if let Some(burst_str) = cli_args.value_of("rpc-range-burst") {
+        config.rpc_range_burst = burst_str
+            .parse::<u64>()
+            .map_err(|_| format!("Invalid RPC range burst: {}", burst_str))?;
+    }
+
+    // Check that the rate limiter will accept the `BlocksByRange` quota.
+    eth2_libp2p::rpc::range_replenish_period(
+        config.rpc_range_request_limit,
+        config.rpc_range_burst,
+    )?;
+
+    if let Some(ping_interval_str) = cli_args.value_of("network-ping-interval") {
+        let ping_interval = ping_interval_str
+            .parse::<u64>()
+            .map_err(|_| format!("Invalid ping interval: {}", ping_interval_str))?;
+        if ping_interval == 0 {
+            return Err("The ping interval must be at least 1 second".to_string());
+        }
+        config.ping_interval = ping_interval;
+    }
+
+    if let Some(buffer_size_str) = cli_args.value_of("network-yamux-max-stream-buffer-size") {
+        config.yamux_max_stream_buffer_size = buffer_size_str
+            .parse::<usize>()
+            .map_err(|_| format!("Invalid yamux stream buffer size: {}", buffer_size_str))?;
+    }
+
+    if let Some(window_size_str) = cli_args.value_of("network-yamux-window-size") {
+        let window_size = window_size_str
+            .parse::<u32>()
+            .map_err(|_| format!("Invalid yamux window size: {}", window_size_str))?;
+        if window_size < MIN_YAMUX_WINDOW_SIZE {
+            return Err(format!(
+                "The yamux window size must be at least {} bytes",
+                MIN_YAMUX_WINDOW_SIZE
+            ));
+        }
+        config.yamux_window_size = window_size;
+    }
+
+    // These flags are not defined by the boot node, which shares this function, so each may be
+    // absent. Only rebuild the gossipsub config when at least one is given.
+    let heartbeat_interval_ms: Option<u64> =
+        clap_utils::parse_optional(cli_args, "network-gossip-heartbeat-interval")?;
+    let mesh_n_low: Option<usize> =
+        clap_utils::parse_optional(cli_args, "network-gossip-mesh-n-low")?;
+    let mesh_n: Option<usize> = clap_utils::parse_optional(cli_args, "network-gossip-mesh-n")?;
+    let mesh_n_high: Option<usize> =
+        clap_utils::parse_optional(cli_args, "network-gossip-mesh-n-high")?;
+    if heartbeat_interval_ms.is_some()
+        || mesh_n_low.is_some()
+        || mesh_n.is_some()
+        || mesh_n_high.is_some()
+    {
+        let gs_config = &config.gs_config;
+        let heartbeat_interval = heartbeat_interval_ms
+            .map(Duration::from_millis)
+            .unwrap_or_else(|| gs_config.heartbeat_interval());
+        let mesh_n_low = mesh_n_low.unwrap_or_else(|| gs_config.mesh_n_low());
+        let mesh_n = mesh_n.unwrap_or_else(|| gs_config.mesh_n());
+        let mesh_n_high = mesh_n_high.unwrap_or_else(|| gs_config.mesh_n_high());
+        config.set_gossipsub_mesh_params(heartbeat_interval, mesh_n_low, mesh_n, mesh_n_high)?;
+    }
+    if let Some(flood_publish) =
+        clap_utils::parse_optional(cli_args, "network-gossip-flood-publish")?
+    {
+        config.set_gossipsub_flood_publish(flood_publish)?;
+    }
+
+    if let Some(threshold_str) = cli_args.value_of("beacon-processor-admission-cost-threshold-ms") {
+        config.beacon_processor_admission_cost_threshold_ms = Some(
+            threshold_str
+                .parse::<u64>()
+                .map_err(|_| format!("Invalid admission cost threshold: {}", threshold_str))?,
+        );
+    }
+
     if let Some(port_str) = cli_args.value_of("port") {
         let port = port_str
             .parse::<u16>()
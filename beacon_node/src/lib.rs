@@ -94,6 +94,14 @@ impl<E: EthSpec> ProductionBeaconNode<E> {
             .beacon_chain_builder(client_genesis, client_config.clone())
             .await?;
         let builder = if client_config.sync_eth1_chain && !client_config.dummy_eth1_backend {
+            if client_config.eth1.disable_deposit_processing {
+                warn!(
+                    log,
+                    "Deposit processing disabled";
+                    "msg" => "new deposits will not be included in blocks",
+                    "info" => "blocks cannot be produced whilst deposits are pending"
+                );
+            }
             info!(
                 log,
                 "Block production enabled";
@@ -6,7 +6,7 @@ use leveldb::database::batch::{Batch, Writebatch};
 use leveldb::database::kv::KV;
 use leveldb::database::Database;
 use leveldb::error::Error as LevelDBError;
-use leveldb::iterator::{Iterable, KeyIterator};
+use leveldb::iterator::{Iterable, KeyIterator, LevelDBIterator};
 use leveldb::options::{Options, ReadOptions, WriteOptions};
 use parking_lot::{Mutex, MutexGuard};
 use std::marker::PhantomData;
@@ -174,6 +174,37 @@ impl<E: EthSpec> KeyValueStore<E> for LevelDB<E> {
         }
         Ok(())
     }
+
+    fn column_size(&self, column: DBColumn, include_values: bool) -> Result<ColumnSize, Error> {
+        let start_key = BytesKey::from_vec(column.as_bytes().to_vec());
+
+        if !include_values {
+            let keys_iter = self.keys_iter();
+            keys_iter.seek(&start_key);
+
+            return Ok(ColumnSize {
+                key_count: keys_iter
+                    .take_while(|key| key.matches_column(column))
+                    .count() as u64,
+                size_bytes: None,
+            });
+        }
+
+        let iter = self.db.iter(self.read_options());
+        iter.seek(&start_key);
+
+        let mut key_count = 0;
+        let mut size_bytes = 0;
+        for (key, value) in iter.take_while(|(key, _)| key.matches_column(column)) {
+            key_count += 1;
+            size_bytes += (key.key.len() + value.len()) as u64;
+        }
+
+        Ok(ColumnSize {
+            key_count,
+            size_bytes: Some(size_bytes),
+        })
+    }
 }
 
 impl<E: EthSpec> ItemStore<E> for LevelDB<E> {}
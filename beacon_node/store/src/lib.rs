@@ -69,6 +69,21 @@ pub trait KeyValueStore<E: EthSpec>: Sync + Send + Sized + 'static {
 
     /// Compact the database, freeing space used by deleted items.
     fn compact(&self) -> Result<(), Error>;
+
+    /// Return the number of keys in `column`.
+    ///
+    /// Only the keys are read unless `include_values` is `true`, in which case every value is
+    /// also read to compute the total size of the keys and values.
+    fn column_size(&self, column: DBColumn, include_values: bool) -> Result<ColumnSize, Error>;
+}
+
+/// The number of keys in a column and their total size, see `KeyValueStore::column_size`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ColumnSize {
+    pub key_count: u64,
+    /// The sum of the lengths of all keys (including the column prefix) and values, before any
+    /// compression by the database. `None` if the values were not read.
+    pub size_bytes: Option<u64>,
 }
 
 pub fn get_key_for_col(column: &str, key: &[u8]) -> Vec<u8> {
@@ -192,6 +207,26 @@ impl Into<&'static str> for DBColumn {
 }
 
 impl DBColumn {
+    /// Every column, in declaration order.
+    pub const ALL: &'static [DBColumn] = &[
+        DBColumn::BeaconMeta,
+        DBColumn::BeaconBlock,
+        DBColumn::BeaconState,
+        DBColumn::BeaconChain,
+        DBColumn::OpPool,
+        DBColumn::Eth1Cache,
+        DBColumn::ForkChoice,
+        DBColumn::PubkeyCache,
+        DBColumn::BeaconRestorePoint,
+        DBColumn::BeaconStateSummary,
+        DBColumn::BeaconStateTemporary,
+        DBColumn::BeaconBlockRoots,
+        DBColumn::BeaconStateRoots,
+        DBColumn::BeaconHistoricalRoots,
+        DBColumn::BeaconRandaoMixes,
+        DBColumn::DhtEnrs,
+    ];
+
     pub fn as_str(self) -> &'static str {
         self.into()
     }
@@ -265,6 +300,38 @@ mod tests {
         assert_eq!(store.exists::<StorableThing>(&key).unwrap(), false);
 
         assert_eq!(store.get::<StorableThing>(&key).unwrap(), None);
+
+        // Column sizes count only the keys in the column.
+        let keys = [Hash256::random(), Hash256::random()];
+        for key in &keys {
+            store.put(key, &item).unwrap();
+        }
+        store
+            .put_bytes(DBColumn::OpPool.into(), key.as_bytes(), &[0; 8])
+            .unwrap();
+
+        let item_size = DBColumn::BeaconBlock.as_bytes().len() + 32 + item.as_store_bytes().len();
+        assert_eq!(
+            store.column_size(DBColumn::BeaconBlock, true).unwrap(),
+            ColumnSize {
+                key_count: 2,
+                size_bytes: Some(2 * item_size as u64),
+            }
+        );
+        assert_eq!(
+            store.column_size(DBColumn::BeaconBlock, false).unwrap(),
+            ColumnSize {
+                key_count: 2,
+                size_bytes: None,
+            }
+        );
+        assert_eq!(
+            store.column_size(DBColumn::BeaconState, true).unwrap(),
+            ColumnSize {
+                key_count: 0,
+                size_bytes: Some(0),
+            }
+        );
     }
 
     #[test]
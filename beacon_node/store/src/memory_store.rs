@@ -1,4 +1,4 @@
-use super::{Error, ItemStore, KeyValueStore, KeyValueStoreOp};
+use super::{ColumnSize, DBColumn, Error, ItemStore, KeyValueStore, KeyValueStoreOp};
 use parking_lot::{Mutex, MutexGuard, RwLock};
 use std::collections::HashMap;
 use std::marker::PhantomData;
@@ -88,6 +88,26 @@ impl<E: EthSpec> KeyValueStore<E> for MemoryStore<E> {
     fn compact(&self) -> Result<(), Error> {
         Ok(())
     }
+
+    fn column_size(&self, column: DBColumn, include_values: bool) -> Result<ColumnSize, Error> {
+        let (key_count, size_bytes) = self
+            .db
+            .read()
+            .iter()
+            .filter(|(key, _)| key.starts_with(column.as_bytes()))
+            .fold((0, 0), |(key_count, size_bytes), (key, value)| {
+                (key_count + 1, size_bytes + (key.len() + value.len()) as u64)
+            });
+
+        Ok(ColumnSize {
+            key_count,
+            size_bytes: if include_values {
+                Some(size_bytes)
+            } else {
+                None
+            },
+        })
+    }
 }
 
 impl<E: EthSpec> ItemStore<E> for MemoryStore<E> {}
@@ -899,6 +899,27 @@ impl BeaconNodeHttpClient {
             .transpose()
     }
 
+    /// `GET debug/beacon/states/{block_root}?by_block_root=true`
+    ///
+    /// Returns `Ok(None)` on a 404 error.
+    pub async fn get_debug_beacon_states_by_block_root<T: EthSpec>(
+        &self,
+        block_root: Hash256,
+    ) -> Result<Option<GenericResponse<BeaconState<T>>>, Error> {
+        let mut path = self.eth_path()?;
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("debug")
+            .push("beacon")
+            .push("states")
+            .push(&format!("{:?}", block_root));
+
+        path.query_pairs_mut().append_pair("by_block_root", "true");
+
+        self.get_opt(path).await
+    }
+
     /// `GET debug/beacon/heads`
     pub async fn get_debug_beacon_heads(
         &self,
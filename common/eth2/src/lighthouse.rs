@@ -2,7 +2,10 @@
 
 use crate::{
     ok_or_error,
-    types::{BeaconState, Epoch, EthSpec, GenericResponse, ValidatorId},
+    types::{
+        AttestationData, BeaconState, BlockId, Checkpoint, CommitteeIndex, Epoch, EthSpec,
+        GenericResponse, ProposerSlashing, RootData, SignedBeaconBlock, Slot, ValidatorId,
+    },
     BeaconNodeHttpClient, DepositData, Error, Eth1Data, Hash256, StateId, StatusCode,
 };
 use proto_array::core::ProtoArray;
@@ -10,8 +13,12 @@ use reqwest::IntoUrl;
 use serde::{Deserialize, Serialize};
 use ssz::Decode;
 use ssz_derive::{Decode, Encode};
+use std::collections::HashMap;
 
-pub use eth2_libp2p::{types::SyncState, PeerInfo};
+pub use eth2_libp2p::{
+    types::{GossipMessageCounters, SyncState},
+    PeerInfo,
+};
 
 /// Information returned by `peers` and `connected_peers`.
 // TODO: this should be deserializable..
@@ -276,6 +283,101 @@ impl Health {
     }
 }
 
+/// The number of distinct justified checkpoints in an epoch, as seen by fork choice.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct JustifiedCheckpoints {
+    pub epoch: Epoch,
+    pub count: usize,
+}
+
+/// A voluntary exit included in a canonical block.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OnChainExit {
+    pub validator_index: u64,
+    /// The epoch from which the exit is valid.
+    pub epoch: Epoch,
+}
+
+/// The sync state of the node and how many of its peers agree with its head.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SyncingStatus {
+    pub sync_state: SyncState,
+    /// The number of connected peers whose latest STATUS reports the same head as the node.
+    pub peers_at_head: usize,
+    pub total_connected_peers: usize,
+}
+
+/// The performance of a monitored validator across a range of epochs.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ValidatorPerformanceSummary {
+    /// The number of epochs in the range which are retained by the validator monitor, excluding
+    /// epochs prior to the activation of the validator.
+    pub epochs: u64,
+    /// The number of times a block from the validator was observed on gossip or the HTTP API.
+    ///
+    /// This is not the number of canonical blocks proposed: a block seen on both gossip and the
+    /// HTTP API is counted twice, and blocks which were later orphaned are included.
+    pub blocks_observed: usize,
+    /// The fraction of `epochs` in which an attestation from the validator was included in a block.
+    pub attestation_inclusion_rate: f64,
+    /// The mean number of slots by which included attestations exceeded the minimum inclusion
+    /// delay, or `None` if no attestations were included.
+    pub average_inclusion_distance: Option<f64>,
+}
+
+/// The number of attestations held in the attestation pools for a committee.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CommitteeAttestationCount {
+    pub slot: Slot,
+    pub index: u64,
+    pub count: usize,
+}
+
+/// The number of keys in a column of the hot database and their total size.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ColumnStats {
+    pub column_name: String,
+    pub key_count: u64,
+    /// The total size of the keys and values before compression by the database, if requested.
+    pub approximate_size_bytes: Option<u64>,
+}
+
+/// The root of the canonical block at some slot.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SlotBlockRoot {
+    pub slot: Slot,
+    pub root: Hash256,
+}
+
+/// The stage at which an attestation from a validator was lost, if it was lost at all.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum MissedAttestationDiagnosis {
+    /// The validator was not assigned to attest at the slot.
+    NotInCommittee,
+    /// No attestation from the validator was seen on gossip, nor included in a block.
+    NotSeen,
+    /// An attestation from the validator was included in a canonical block.
+    IncludedInBlock {
+        block_root: Hash256,
+        inclusion_slot: Slot,
+    },
+    /// An attestation from the validator was seen on gossip, but was not included in any of the
+    /// `blocks_checked` canonical blocks of the inclusion window.
+    MissedInclusion { blocks_checked: usize },
+}
+
+/// The outcome of running the state transition for a block without importing it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SimulatedBlockImport {
+    /// The root of the post-state, or `None` if the block is invalid.
+    pub state_root: Option<Hash256>,
+    /// The time taken to run the state transition, in milliseconds.
+    pub processing_time_ms: u64,
+    /// The reason the block is invalid, if any.
+    pub validation_error: Option<String>,
+}
+
 /// Indicates how up-to-date the Eth1 caches are.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Eth1SyncStatusData {
@@ -300,6 +402,18 @@ pub struct DepositLog {
     pub signature_is_valid: bool,
 }
 
+/// A deposit from the eth1 deposit cache, with a merkle proof against the `deposit_root` of the
+/// first `deposit_count` deposits.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct DepositContractData {
+    pub deposit_data: DepositData,
+    pub proof: Vec<Hash256>,
+    /// The block number of the log that included this `DepositData`.
+    pub eth1_block: u64,
+    pub deposit_count: u64,
+    pub deposit_root: Hash256,
+}
+
 /// A block of the eth1 chain.
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Encode, Decode)]
 pub struct Eth1Block {
@@ -355,7 +469,7 @@ impl BeaconNodeHttpClient {
     }
 
     /// `GET lighthouse/syncing`
-    pub async fn get_lighthouse_syncing(&self) -> Result<GenericResponse<SyncState>, Error> {
+    pub async fn get_lighthouse_syncing(&self) -> Result<GenericResponse<SyncingStatus>, Error> {
         let mut path = self.server.full.clone();
 
         path.path_segments_mut()
@@ -366,6 +480,21 @@ impl BeaconNodeHttpClient {
         self.get(path).await
     }
 
+    /// `GET lighthouse/network/gossip_stats`
+    pub async fn get_lighthouse_network_gossip_stats(
+        &self,
+    ) -> Result<GenericResponse<HashMap<String, GossipMessageCounters>>, Error> {
+        let mut path = self.server.full.clone();
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("lighthouse")
+            .push("network")
+            .push("gossip_stats");
+
+        self.get(path).await
+    }
+
     /*
      * Note:
      *
@@ -386,6 +515,256 @@ impl BeaconNodeHttpClient {
         self.get(path).await
     }
 
+    /// `GET lighthouse/fork_choice/justified_checkpoints?epoch`
+    pub async fn get_lighthouse_fork_choice_justified_checkpoints(
+        &self,
+        epoch: Epoch,
+    ) -> Result<GenericResponse<JustifiedCheckpoints>, Error> {
+        let mut path = self.server.full.clone();
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("lighthouse")
+            .push("fork_choice")
+            .push("justified_checkpoints");
+
+        path.query_pairs_mut()
+            .append_pair("epoch", &epoch.to_string());
+
+        self.get(path).await
+    }
+
+    /// `GET lighthouse/sync/checkpoint_peers?epoch,root`
+    pub async fn get_lighthouse_sync_checkpoint_peers(
+        &self,
+        checkpoint: Checkpoint,
+    ) -> Result<GenericResponse<Vec<String>>, Error> {
+        let mut path = self.server.full.clone();
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("lighthouse")
+            .push("sync")
+            .push("checkpoint_peers");
+
+        path.query_pairs_mut()
+            .append_pair("epoch", &checkpoint.epoch.to_string())
+            .append_pair("root", &format!("{:?}", checkpoint.root));
+
+        self.get(path).await
+    }
+
+    /// `GET lighthouse/beacon/finality_checkpoints_history?count`
+    pub async fn get_lighthouse_beacon_finality_checkpoints_history(
+        &self,
+        count: Option<usize>,
+    ) -> Result<GenericResponse<Vec<Checkpoint>>, Error> {
+        let mut path = self.server.full.clone();
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("lighthouse")
+            .push("beacon")
+            .push("finality_checkpoints_history");
+
+        if let Some(count) = count {
+            path.query_pairs_mut()
+                .append_pair("count", &count.to_string());
+        }
+
+        self.get(path).await
+    }
+
+    /// `GET lighthouse/beacon/checkpoint_state_root?epoch`
+    pub async fn get_lighthouse_beacon_checkpoint_state_root(
+        &self,
+        epoch: Epoch,
+    ) -> Result<Option<GenericResponse<RootData>>, Error> {
+        let mut path = self.server.full.clone();
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("lighthouse")
+            .push("beacon")
+            .push("checkpoint_state_root");
+
+        path.query_pairs_mut()
+            .append_pair("epoch", &epoch.to_string());
+
+        self.get_opt(path).await
+    }
+
+    /// `GET lighthouse/beacon/blocks/{block_id}/attesting_validators`
+    pub async fn get_lighthouse_beacon_block_attesting_validators(
+        &self,
+        block_id: BlockId,
+    ) -> Result<Option<GenericResponse<Vec<u64>>>, Error> {
+        let mut path = self.server.full.clone();
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("lighthouse")
+            .push("beacon")
+            .push("blocks")
+            .push(&block_id.to_string())
+            .push("attesting_validators");
+
+        self.get_opt(path).await
+    }
+
+    /// `GET lighthouse/beacon/exits_by_epoch/{epoch}`
+    pub async fn get_lighthouse_beacon_exits_by_epoch(
+        &self,
+        epoch: Epoch,
+    ) -> Result<GenericResponse<Vec<OnChainExit>>, Error> {
+        let mut path = self.server.full.clone();
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("lighthouse")
+            .push("beacon")
+            .push("exits_by_epoch")
+            .push(&epoch.to_string());
+
+        self.get(path).await
+    }
+
+    /// `GET lighthouse/beacon/proposer_slashings_by_epoch/{epoch}`
+    pub async fn get_lighthouse_beacon_proposer_slashings_by_epoch(
+        &self,
+        epoch: Epoch,
+    ) -> Result<GenericResponse<Vec<ProposerSlashing>>, Error> {
+        let mut path = self.server.full.clone();
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("lighthouse")
+            .push("beacon")
+            .push("proposer_slashings_by_epoch")
+            .push(&epoch.to_string());
+
+        self.get(path).await
+    }
+
+    /// `GET lighthouse/beacon/block_roots_range?start,end`
+    pub async fn get_lighthouse_beacon_block_roots_range(
+        &self,
+        start: Slot,
+        end: Slot,
+    ) -> Result<GenericResponse<Vec<SlotBlockRoot>>, Error> {
+        let mut path = self.server.full.clone();
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("lighthouse")
+            .push("beacon")
+            .push("block_roots_range");
+
+        path.query_pairs_mut()
+            .append_pair("start", &start.to_string())
+            .append_pair("end", &end.to_string());
+
+        self.get(path).await
+    }
+
+    /// `GET lighthouse/validator/{index}/performance?start_epoch,end_epoch`
+    pub async fn get_lighthouse_validator_performance(
+        &self,
+        validator_index: u64,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+    ) -> Result<Option<GenericResponse<ValidatorPerformanceSummary>>, Error> {
+        let mut path = self.server.full.clone();
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("lighthouse")
+            .push("validator")
+            .push(&validator_index.to_string())
+            .push("performance");
+
+        path.query_pairs_mut()
+            .append_pair("start_epoch", &start_epoch.to_string())
+            .append_pair("end_epoch", &end_epoch.to_string());
+
+        self.get_opt(path).await
+    }
+
+    /// `GET lighthouse/validator/{index}/missed_attestation_diagnosis?slot`
+    pub async fn get_lighthouse_validator_missed_attestation_diagnosis(
+        &self,
+        validator_index: u64,
+        slot: Slot,
+    ) -> Result<GenericResponse<MissedAttestationDiagnosis>, Error> {
+        let mut path = self.server.full.clone();
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("lighthouse")
+            .push("validator")
+            .push(&validator_index.to_string())
+            .push("missed_attestation_diagnosis");
+
+        path.query_pairs_mut()
+            .append_pair("slot", &slot.to_string());
+
+        self.get(path).await
+    }
+
+    /// `GET lighthouse/validator/attestation_data_batch?slot`
+    pub async fn get_lighthouse_validator_attestation_data_batch(
+        &self,
+        slot: Slot,
+    ) -> Result<GenericResponse<HashMap<CommitteeIndex, AttestationData>>, Error> {
+        let mut path = self.server.full.clone();
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("lighthouse")
+            .push("validator")
+            .push("attestation_data_batch");
+
+        path.query_pairs_mut()
+            .append_pair("slot", &slot.to_string());
+
+        self.get(path).await
+    }
+
+    /// `GET lighthouse/beacon/attestation_pool_by_committee`
+    pub async fn get_lighthouse_beacon_attestation_pool_by_committee(
+        &self,
+    ) -> Result<GenericResponse<Vec<CommitteeAttestationCount>>, Error> {
+        let mut path = self.server.full.clone();
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("lighthouse")
+            .push("beacon")
+            .push("attestation_pool_by_committee");
+
+        self.get(path).await
+    }
+
+    /// `GET lighthouse/admin/db_stats?include_sizes`
+    pub async fn get_lighthouse_admin_db_stats(
+        &self,
+        include_sizes: bool,
+    ) -> Result<GenericResponse<Vec<ColumnStats>>, Error> {
+        let mut path = self.server.full.clone();
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("lighthouse")
+            .push("admin")
+            .push("db_stats");
+
+        if include_sizes {
+            path.query_pairs_mut().append_pair("include_sizes", "true");
+        }
+
+        self.get(path).await
+    }
+
     /// `GET lighthouse/validator_inclusion/{epoch}/global`
     pub async fn get_lighthouse_validator_inclusion_global(
         &self,
@@ -466,6 +845,25 @@ impl BeaconNodeHttpClient {
         self.get(path).await
     }
 
+    /// `GET lighthouse/eth1/deposit/{index}`
+    ///
+    /// Returns `Ok(None)` on a 404 error.
+    pub async fn get_lighthouse_eth1_deposit(
+        &self,
+        index: u64,
+    ) -> Result<Option<GenericResponse<DepositContractData>>, Error> {
+        let mut path = self.server.full.clone();
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("lighthouse")
+            .push("eth1")
+            .push("deposit")
+            .push(&index.to_string());
+
+        self.get_opt(path).await
+    }
+
     /// `GET lighthouse/beacon/states/{state_id}/ssz`
     pub async fn get_lighthouse_beacon_states_ssz<E: EthSpec>(
         &self,
@@ -498,4 +896,37 @@ impl BeaconNodeHttpClient {
 
         self.get_opt::<(), _>(path).await.map(|opt| opt.is_some())
     }
+
+    /// `POST lighthouse/debug/simulate_block_import`
+    pub async fn post_lighthouse_debug_simulate_block_import<T: EthSpec>(
+        &self,
+        block: &SignedBeaconBlock<T>,
+    ) -> Result<GenericResponse<SimulatedBlockImport>, Error> {
+        let mut path = self.server.full.clone();
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("lighthouse")
+            .push("debug")
+            .push("simulate_block_import");
+
+        self.post_with_response(path, block).await
+    }
+
+    /// `POST lighthouse/admin/rebroadcast_block/{block_root}`
+    pub async fn post_lighthouse_admin_rebroadcast_block(
+        &self,
+        block_root: Hash256,
+    ) -> Result<(), Error> {
+        let mut path = self.server.full.clone();
+
+        path.path_segments_mut()
+            .map_err(|()| Error::InvalidUrl(self.server.clone()))?
+            .push("lighthouse")
+            .push("admin")
+            .push("rebroadcast_block")
+            .push(&format!("{:?}", block_root));
+
+        self.post(path, &()).await
+    }
 }
@@ -524,6 +524,59 @@ pub struct ValidatorAttestationDataQuery {
     pub committee_index: CommitteeIndex,
 }
 
+#[derive(Clone, Serialize, Deserialize)]
+pub struct DebugBeaconStateQuery {
+    pub by_block_root: Option<bool>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct JustifiedCheckpointsQuery {
+    pub epoch: Epoch,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CheckpointStateRootQuery {
+    pub epoch: Epoch,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FinalityCheckpointsHistoryQuery {
+    pub count: Option<usize>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BlockRootsRangeQuery {
+    pub start: Slot,
+    pub end: Slot,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ValidatorPerformanceQuery {
+    pub start_epoch: Epoch,
+    pub end_epoch: Epoch,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct MissedAttestationDiagnosisQuery {
+    pub slot: Slot,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct DbStatsQuery {
+    pub include_sizes: Option<bool>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct AttestationDataBatchQuery {
+    pub slot: Slot,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CheckpointPeersQuery {
+    pub epoch: Epoch,
+    pub root: Hash256,
+}
+
 #[derive(Clone, Serialize, Deserialize)]
 pub struct ValidatorAggregateAttestationQuery {
     pub attestation_data_root: Hash256,
@@ -689,6 +742,20 @@ pub struct SseHead {
     pub epoch_transition: bool,
 }
 
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
+pub struct SseChainSplit {
+    pub epoch: Epoch,
+    pub justified_roots: Vec<Hash256>,
+}
+
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
+pub struct SseForkChoiceEquivocation {
+    pub epoch: Epoch,
+    pub root_1: Hash256,
+    pub root_2: Hash256,
+    pub divergence_slot: Slot,
+}
+
 #[derive(PartialEq, Debug, Serialize, Clone)]
 #[serde(bound = "T: EthSpec", untagged)]
 pub enum EventKind<T: EthSpec> {
@@ -697,6 +764,8 @@ pub enum EventKind<T: EthSpec> {
     FinalizedCheckpoint(SseFinalizedCheckpoint),
     Head(SseHead),
     VoluntaryExit(SignedVoluntaryExit),
+    ChainSplitDetected(SseChainSplit),
+    ForkChoiceEquivocation(SseForkChoiceEquivocation),
 }
 
 impl<T: EthSpec> EventKind<T> {
@@ -707,6 +776,8 @@ impl<T: EthSpec> EventKind<T> {
             EventKind::Attestation(_) => "attestation",
             EventKind::VoluntaryExit(_) => "voluntary_exit",
             EventKind::FinalizedCheckpoint(_) => "finalized_checkpoint",
+            EventKind::ChainSplitDetected(_) => "chain_split_detected",
+            EventKind::ForkChoiceEquivocation(_) => "fork_choice_equivocation",
         }
     }
 
@@ -748,6 +819,19 @@ impl<T: EthSpec> EventKind<T> {
                     ServerError::InvalidServerSentEvent(format!("Voluntary Exit: {:?}", e))
                 })?,
             )),
+            "chain_split_detected" => Ok(EventKind::ChainSplitDetected(
+                serde_json::from_str(data).map_err(|e| {
+                    ServerError::InvalidServerSentEvent(format!("Chain Split Detected: {:?}", e))
+                })?,
+            )),
+            "fork_choice_equivocation" => Ok(EventKind::ForkChoiceEquivocation(
+                serde_json::from_str(data).map_err(|e| {
+                    ServerError::InvalidServerSentEvent(format!(
+                        "Fork Choice Equivocation: {:?}",
+                        e
+                    ))
+                })?,
+            )),
             _ => Err(ServerError::InvalidServerSentEvent(
                 "Could not parse event tag".to_string(),
             )),
@@ -768,6 +852,8 @@ pub enum EventTopic {
     Attestation,
     VoluntaryExit,
     FinalizedCheckpoint,
+    ChainSplitDetected,
+    ForkChoiceEquivocation,
 }
 
 impl FromStr for EventTopic {
@@ -780,6 +866,8 @@ impl FromStr for EventTopic {
             "attestation" => Ok(EventTopic::Attestation),
             "voluntary_exit" => Ok(EventTopic::VoluntaryExit),
             "finalized_checkpoint" => Ok(EventTopic::FinalizedCheckpoint),
+            "chain_split_detected" => Ok(EventTopic::ChainSplitDetected),
+            "fork_choice_equivocation" => Ok(EventTopic::ForkChoiceEquivocation),
             _ => Err("event topic cannot be parsed.".to_string()),
         }
     }
@@ -793,6 +881,8 @@ impl fmt::Display for EventTopic {
             EventTopic::Attestation => write!(f, "attestation"),
             EventTopic::VoluntaryExit => write!(f, "voluntary_exit"),
             EventTopic::FinalizedCheckpoint => write!(f, "finalized_checkpoint"),
+            EventTopic::ChainSplitDetected => write!(f, "chain_split_detected"),
+            EventTopic::ForkChoiceEquivocation => write!(f, "fork_choice_equivocation"),
         }
     }
 }
@@ -127,6 +127,15 @@ pub fn invalid_auth(msg: String) -> warp::reject::Rejection {
     warp::reject::custom(InvalidAuthorization(msg))
 }
 
+#[derive(Debug)]
+pub struct PayloadTooLarge(pub String);
+
+impl Reject for PayloadTooLarge {}
+
+pub fn payload_too_large(msg: String) -> warp::reject::Rejection {
+    warp::reject::custom(PayloadTooLarge(msg))
+}
+
 #[derive(Debug)]
 pub struct IndexedBadRequestErrors {
     pub message: String,
@@ -204,6 +213,9 @@ pub async fn handle_rejection(err: warp::Rejection) -> Result<impl warp::Reply,
     } else if let Some(e) = err.find::<crate::reject::InvalidAuthorization>() {
         code = StatusCode::FORBIDDEN;
         message = format!("FORBIDDEN: Invalid auth token: {}", e.0);
+    } else if let Some(e) = err.find::<crate::reject::PayloadTooLarge>() {
+        code = StatusCode::PAYLOAD_TOO_LARGE;
+        message = format!("PAYLOAD_TOO_LARGE: {}", e.0);
     } else if let Some(e) = err.find::<warp::reject::MissingHeader>() {
         code = StatusCode::BAD_REQUEST;
         message = format!("BAD_REQUEST: missing {} header", e.name());
@@ -5,13 +5,17 @@ extern crate env_logger;
 use criterion::Criterion;
 use criterion::{black_box, criterion_group, criterion_main, Benchmark};
 use ssz::Encode;
+use state_processing::common::DepositDataTree;
 use state_processing::{test_utils::BlockBuilder, BlockSignatureStrategy, VerifySignatures};
+use tree_hash::TreeHash;
 use types::{
-    BeaconState, ChainSpec, EthSpec, MainnetEthSpec, MinimalEthSpec, SignedBeaconBlock, Slot,
+    BeaconState, ChainSpec, Deposit, DepositData, Eth1Data, EthSpec, Hash256, MainnetEthSpec,
+    MinimalEthSpec, PublicKeyBytes, SignatureBytes, SignedBeaconBlock, Slot, DEPOSIT_TREE_DEPTH,
 };
 
 pub const VALIDATORS_LOW: usize = 32_768;
 pub const VALIDATORS_HIGH: usize = 300_032;
+pub const DEPOSIT_COUNT: usize = 1_000;
 
 fn all_benches(c: &mut Criterion) {
     env_logger::init();
@@ -23,6 +27,8 @@ fn all_benches(c: &mut Criterion) {
     worst_bench::<MinimalEthSpec>(c, "minimal", VALIDATORS_LOW);
     worst_bench::<MainnetEthSpec>(c, "mainnet", VALIDATORS_LOW);
     worst_bench::<MainnetEthSpec>(c, "mainnet", VALIDATORS_HIGH);
+
+    deposit_proofs_bench::<MainnetEthSpec>(c, "mainnet", DEPOSIT_COUNT);
 }
 
 /// Run a bench with a average complexity block.
@@ -75,6 +81,95 @@ fn get_worst_block<T: EthSpec>(
     builder.build(&spec)
 }
 
+/// Return a state and `deposit_count` deposits with valid merkle proofs against its deposit root.
+fn get_deposits<T: EthSpec>(
+    deposit_count: usize,
+    spec: &ChainSpec,
+) -> (BeaconState<T>, Vec<Deposit>) {
+    let datas = (0..deposit_count)
+        .map(|i| DepositData {
+            pubkey: PublicKeyBytes::empty(),
+            withdrawal_credentials: Hash256::from_low_u64_be(i as u64),
+            amount: spec.max_effective_balance,
+            signature: SignatureBytes::empty(),
+        })
+        .collect::<Vec<_>>();
+    let leaves = datas
+        .iter()
+        .map(|data| data.tree_hash_root())
+        .collect::<Vec<_>>();
+    let tree = DepositDataTree::create(&leaves, leaves.len(), DEPOSIT_TREE_DEPTH);
+
+    let deposits = datas
+        .into_iter()
+        .enumerate()
+        .map(|(i, data)| Deposit {
+            proof: tree.generate_proof(i).1.into(),
+            data,
+        })
+        .collect();
+    let eth1_data = Eth1Data {
+        deposit_root: tree.root(),
+        deposit_count: deposit_count as u64,
+        block_hash: Hash256::zero(),
+    };
+
+    (BeaconState::new(0, eth1_data, spec), deposits)
+}
+
+/// Compare verifying deposit merkle proofs one at a time against verifying them as a batch.
+fn deposit_proofs_bench<T: EthSpec>(c: &mut Criterion, spec_desc: &str, deposit_count: usize) {
+    let spec = &T::default_spec();
+
+    let (state, deposits) = get_deposits::<T>(deposit_count, spec);
+
+    let title = &format!("{}/{}_deposits", spec_desc, deposit_count);
+
+    let local_state = state.clone();
+    let local_deposits = deposits.clone();
+    let local_spec = spec.clone();
+    c.bench(
+        &title,
+        Benchmark::new("verify_deposit_merkle_proof/sequential", move |b| {
+            b.iter(|| {
+                black_box(
+                    local_deposits
+                        .iter()
+                        .enumerate()
+                        .map(|(i, deposit)| {
+                            state_processing::per_block_processing::verify_deposit_merkle_proof(
+                                &local_state,
+                                deposit,
+                                i as u64,
+                                &local_spec,
+                            )
+                            .is_ok()
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            })
+        })
+        .sample_size(10),
+    );
+
+    let local_spec = spec.clone();
+    c.bench(
+        &title,
+        Benchmark::new("verify_deposit_merkle_proofs_batch", move |b| {
+            b.iter(|| {
+                black_box(
+                    state_processing::per_block_processing::verify_deposit_merkle_proofs_batch(
+                        &state,
+                        &deposits,
+                        &local_spec,
+                    ),
+                )
+            })
+        })
+        .sample_size(10),
+    );
+}
+
 #[allow(clippy::unit_arg)]
 fn bench_block<T: EthSpec>(
     c: &mut Criterion,
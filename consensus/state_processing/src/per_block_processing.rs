@@ -16,7 +16,8 @@ pub use verify_attestation::{
     verify_attestation_for_block_inclusion, verify_attestation_for_state,
 };
 pub use verify_deposit::{
-    get_existing_validator_index, verify_deposit_merkle_proof, verify_deposit_signature,
+    get_existing_validator_index, verify_deposit_merkle_proof, verify_deposit_merkle_proofs_batch,
+    verify_deposit_signature,
 };
 pub use verify_exit::{verify_exit, verify_exit_time_independent_only};
 
@@ -2,6 +2,7 @@
 
 use super::block_processing_builder::BlockProcessingBuilder;
 use super::errors::*;
+use super::{verify_deposit_merkle_proof, verify_deposit_merkle_proofs_batch};
 use crate::{per_block_processing, BlockSignatureStrategy};
 use types::test_utils::{
     AttestationTestTask, AttesterSlashingTestTask, DepositTestTask, ProposerSlashingTestTask,
@@ -249,6 +250,37 @@ fn invalid_deposit_bad_merkle_proof() {
     );
 }
 
+#[test]
+fn deposit_merkle_proofs_batch_matches_sequential() {
+    let spec = MainnetEthSpec::default_spec();
+    let builder = get_builder(&spec, EPOCH_OFFSET, VALIDATOR_COUNT);
+    let test_task = DepositTestTask::Valid;
+    let num_deposits = <E as EthSpec>::MaxDeposits::to_u64();
+
+    let (block, state) = builder.build_with_n_deposits(num_deposits, test_task, None, None, &spec);
+    let mut deposits = block.message.body.deposits.to_vec();
+    assert_eq!(deposits.len() as u64, num_deposits);
+
+    // Corrupt the proof of a single deposit
+    let bad_index = 3;
+    deposits[bad_index].proof[0] = Hash256::repeat_byte(42);
+
+    let sequential = deposits
+        .iter()
+        .enumerate()
+        .map(|(i, deposit)| {
+            verify_deposit_merkle_proof(&state, deposit, state.eth1_deposit_index + i as u64, &spec)
+                .is_ok()
+        })
+        .collect::<Vec<_>>();
+    let batch = verify_deposit_merkle_proofs_batch(&state, &deposits, &spec);
+
+    assert_eq!(batch, sequential);
+    for (i, valid) in batch.into_iter().enumerate() {
+        assert_eq!(valid, i != bad_index, "deposit {}", i);
+    }
+}
+
 #[test]
 fn invalid_deposit_wrong_pubkey() {
     let spec = MainnetEthSpec::default_spec();
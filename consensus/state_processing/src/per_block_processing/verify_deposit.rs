@@ -1,6 +1,7 @@
 use super::errors::{BlockOperationError, DepositInvalid};
 use crate::per_block_processing::signature_sets::deposit_pubkey_signature_message;
 use merkle_proof::verify_merkle_proof;
+use rayon::prelude::*;
 use safe_arith::SafeArith;
 use tree_hash::TreeHash;
 use types::*;
@@ -67,3 +68,27 @@ pub fn verify_deposit_merkle_proof<T: EthSpec>(
 
     Ok(())
 }
+
+/// Verify the merkle proofs of many `deposits` in parallel.
+///
+/// The deposits are assumed to be contiguous, with the first having the index
+/// `state.eth1_deposit_index`. Returns one entry per deposit, which is `true` if
+/// its proof is valid.
+pub fn verify_deposit_merkle_proofs_batch<T: EthSpec>(
+    state: &BeaconState<T>,
+    deposits: &[Deposit],
+    spec: &ChainSpec,
+) -> Vec<bool> {
+    deposits
+        .par_iter()
+        .enumerate()
+        .map(|(i, deposit)| {
+            state
+                .eth1_deposit_index
+                .safe_add(i as u64)
+                .map_or(false, |deposit_index| {
+                    verify_deposit_merkle_proof(state, deposit, deposit_index, spec).is_ok()
+                })
+        })
+        .collect()
+}
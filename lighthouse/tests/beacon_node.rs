@@ -10,6 +10,7 @@ use std::path::PathBuf;
 use std::process::{Command, Output};
 use std::str::{from_utf8, FromStr};
 use std::string::ToString;
+use std::time::Duration;
 use tempfile::TempDir;
 use types::{Checkpoint, Epoch, Hash256};
 
@@ -176,6 +177,40 @@ fn max_skip_slots_flag() {
         .run()
         .with_config(|config| assert_eq!(config.chain.import_max_skip_slots, Some(10)));
 }
+#[test]
+fn future_slot_tolerance_flag() {
+    CommandLineTest::new()
+        .flag("future-slot-tolerance", Some("2"))
+        .run()
+        .with_config(|config| assert_eq!(config.chain.future_slot_tolerance, 2));
+}
+#[test]
+fn future_slot_tolerance_default() {
+    CommandLineTest::new()
+        .run()
+        .with_config(|config| assert_eq!(config.chain.future_slot_tolerance, 1));
+}
+#[test]
+#[should_panic]
+fn future_slot_tolerance_too_large() {
+    CommandLineTest::new()
+        .flag("future-slot-tolerance", Some("5"))
+        .run();
+}
+
+#[test]
+fn verify_tree_consistency_flag() {
+    CommandLineTest::new()
+        .flag("verify-tree-consistency", None)
+        .run()
+        .with_config(|config| assert!(config.chain.verify_tree_consistency));
+}
+#[test]
+fn verify_tree_consistency_default() {
+    CommandLineTest::new()
+        .run()
+        .with_config(|config| assert!(!config.chain.verify_tree_consistency));
+}
 
 #[test]
 fn freezer_dir_flag() {
@@ -261,6 +296,66 @@ fn eth1_endpoints_flag() {
         });
 }
 #[test]
+fn eth1_endpoint_priority_flag() {
+    CommandLineTest::new()
+        .flag(
+            "eth1-endpoints",
+            Some("http://localhost:9545,https://infura.io/secret"),
+        )
+        .flag("eth1-endpoint-priority", Some("http://localhost:9545,1"))
+        .flag("eth1-endpoint-priority", Some("http://localhost:8545,0"))
+        .run()
+        .with_config(|config| {
+            assert_eq!(config.eth1.endpoints.len(), 3);
+            assert_eq!(config.eth1.endpoint_priorities, vec![1, 0, 0]);
+            let endpoints = config
+                .eth1
+                .prioritized_endpoints()
+                .into_iter()
+                .map(|endpoint| endpoint.full.to_string())
+                .collect::<Vec<_>>();
+            assert_eq!(
+                endpoints,
+                vec![
+                    "https://infura.io/secret",
+                    "http://localhost:8545/",
+                    "http://localhost:9545/"
+                ]
+            );
+            assert!(config.sync_eth1_chain);
+        });
+}
+#[test]
+fn eth1_endpoint_priority_flag_without_endpoints() {
+    CommandLineTest::new()
+        .flag("eth1-endpoint-priority", Some("http://localhost:9545,2"))
+        .run()
+        .with_config(|config| {
+            assert_eq!(config.eth1.endpoints.len(), 1);
+            assert_eq!(
+                config.eth1.endpoints[0].full.to_string(),
+                "http://localhost:9545/"
+            );
+            assert_eq!(config.eth1.endpoint_priorities, vec![2]);
+            assert!(config.sync_eth1_chain);
+        });
+}
+#[test]
+#[should_panic]
+fn eth1_endpoint_priority_flag_invalid_priority() {
+    CommandLineTest::new()
+        .flag("eth1-endpoint-priority", Some("http://localhost:9545,high"))
+        .run();
+}
+#[test]
+#[should_panic]
+fn eth1_endpoint_priority_flag_without_priority() {
+    // The port must not be mistaken for a priority.
+    CommandLineTest::new()
+        .flag("eth1-endpoint-priority", Some("http://localhost:9545"))
+        .run();
+}
+#[test]
 fn eth1_blocks_per_log_query_flag() {
     CommandLineTest::new()
         .flag("eth1-blocks-per-log-query", Some("500"))
@@ -274,6 +369,16 @@ fn eth1_purge_cache_flag() {
         .run()
         .with_config(|config| assert!(config.eth1.purge_cache));
 }
+#[test]
+fn disable_deposit_processing_flag() {
+    CommandLineTest::new()
+        .flag("disable-deposit-processing", None)
+        .run()
+        .with_config(|config| {
+            assert!(config.sync_eth1_chain);
+            assert!(config.eth1.disable_deposit_processing);
+        });
+}
 
 // Tests for Network flags.
 #[test]
@@ -294,6 +399,195 @@ fn network_target_peers_flag() {
         });
 }
 #[test]
+fn network_max_concurrent_rpc_requests_per_peer_flag() {
+    CommandLineTest::new()
+        .flag("network-max-concurrent-rpc-requests-per-peer", Some("8"))
+        .run()
+        .with_config(|config| {
+            assert_eq!(config.network.max_concurrent_rpc_requests_per_peer, 8);
+        });
+}
+#[test]
+fn network_max_concurrent_rpc_requests_per_peer_default() {
+    CommandLineTest::new()
+        .run()
+        .with_config(|config| assert_eq!(config.network.max_concurrent_rpc_requests_per_peer, 4));
+}
+#[test]
+fn rpc_range_request_limit_flag() {
+    CommandLineTest::new()
+        .flag("rpc-range-request-limit", Some("512"))
+        .run()
+        .with_config(|config| assert_eq!(config.network.rpc_range_request_limit, 512));
+}
+#[test]
+fn rpc_range_request_limit_default() {
+    CommandLineTest::new()
+        .run()
+        .with_config(|config| assert_eq!(config.network.rpc_range_request_limit, 1024));
+}
+#[test]
+#[should_panic]
+fn rpc_range_request_limit_zero() {
+    CommandLineTest::new()
+        .flag("rpc-range-request-limit", Some("0"))
+        .run();
+}
+#[test]
+fn rpc_range_burst_flag() {
+    CommandLineTest::new()
+        .flag("rpc-range-burst", Some("256"))
+        .run()
+        .with_config(|config| assert_eq!(config.network.rpc_range_burst, 256));
+}
+#[test]
+fn rpc_range_burst_default() {
+    CommandLineTest::new()
+        .run()
+        .with_config(|config| assert_eq!(config.network.rpc_range_burst, 1024));
+}
+#[test]
+#[should_panic]
+fn rpc_range_burst_below_minimum() {
+    CommandLineTest::new()
+        .flag("rpc-range-burst", Some("63"))
+        .run();
+}
+#[test]
+#[should_panic]
+fn rpc_range_burst_too_large_for_limit() {
+    CommandLineTest::new()
+        .flag("rpc-range-request-limit", Some("1"))
+        .flag("rpc-range-burst", Some("18446744073709551615"))
+        .run();
+}
+#[test]
+fn network_ping_interval_flag() {
+    CommandLineTest::new()
+        .flag("network-ping-interval", Some("10"))
+        .run()
+        .with_config(|config| assert_eq!(config.network.ping_interval, 10));
+}
+#[test]
+fn network_ping_interval_default() {
+    CommandLineTest::new()
+        .run()
+        .with_config(|config| assert_eq!(config.network.ping_interval, 30));
+}
+#[test]
+#[should_panic]
+fn network_ping_interval_zero() {
+    CommandLineTest::new()
+        .flag("network-ping-interval", Some("0"))
+        .run();
+}
+#[test]
+fn network_yamux_max_stream_buffer_size_flag() {
+    CommandLineTest::new()
+        .flag("network-yamux-max-stream-buffer-size", Some("1048576"))
+        .run()
+        .with_config(|config| assert_eq!(config.network.yamux_max_stream_buffer_size, 1048576));
+}
+#[test]
+fn network_yamux_max_stream_buffer_size_default() {
+    CommandLineTest::new()
+        .run()
+        .with_config(|config| assert_eq!(config.network.yamux_max_stream_buffer_size, 16777216));
+}
+#[test]
+fn network_yamux_window_size_flag() {
+    CommandLineTest::new()
+        .flag("network-yamux-window-size", Some("1048576"))
+        .run()
+        .with_config(|config| assert_eq!(config.network.yamux_window_size, 1048576));
+}
+#[test]
+fn network_yamux_window_size_default() {
+    CommandLineTest::new()
+        .run()
+        .with_config(|config| assert_eq!(config.network.yamux_window_size, 262144));
+}
+#[test]
+#[should_panic]
+fn network_yamux_window_size_too_small() {
+    CommandLineTest::new()
+        .flag("network-yamux-window-size", Some("1024"))
+        .run();
+}
+#[test]
+fn network_gossip_heartbeat_interval_flag() {
+    CommandLineTest::new()
+        .flag("network-gossip-heartbeat-interval", Some("500"))
+        .run()
+        .with_config(|config| {
+            assert_eq!(
+                config.network.gs_config.heartbeat_interval(),
+                Duration::from_millis(500)
+            )
+        });
+}
+#[test]
+fn network_gossip_mesh_flags() {
+    CommandLineTest::new()
+        .flag("network-gossip-mesh-n-low", Some("4"))
+        .flag("network-gossip-mesh-n", Some("6"))
+        .flag("network-gossip-mesh-n-high", Some("10"))
+        .run()
+        .with_config(|config| {
+            assert_eq!(config.network.gs_config.mesh_n_low(), 4);
+            assert_eq!(config.network.gs_config.mesh_n(), 6);
+            assert_eq!(config.network.gs_config.mesh_n_high(), 10);
+        });
+}
+#[test]
+fn network_gossip_defaults() {
+    CommandLineTest::new().run().with_config(|config| {
+        assert_eq!(
+            config.network.gs_config.heartbeat_interval(),
+            Duration::from_millis(700)
+        );
+        assert_eq!(config.network.gs_config.mesh_n_low(), 6);
+        assert_eq!(config.network.gs_config.mesh_n(), 8);
+        assert_eq!(config.network.gs_config.mesh_n_high(), 12);
+        assert!(config.network.gs_config.flood_publish());
+    });
+}
+#[test]
+fn network_gossip_flood_publish_flag() {
+    CommandLineTest::new()
+        .flag("network-gossip-flood-publish", Some("false"))
+        .run()
+        .with_config(|config| assert!(!config.network.gs_config.flood_publish()));
+}
+#[test]
+#[should_panic]
+fn network_gossip_mesh_n_above_mesh_n_high() {
+    CommandLineTest::new()
+        .flag("network-gossip-mesh-n", Some("16"))
+        .run();
+}
+#[test]
+fn beacon_processor_admission_cost_threshold_flag() {
+    CommandLineTest::new()
+        .flag("beacon-processor-admission-cost-threshold-ms", Some("50"))
+        .run()
+        .with_config(|config| {
+            assert_eq!(
+                config.network.beacon_processor_admission_cost_threshold_ms,
+                Some(50)
+            )
+        });
+}
+#[test]
+fn beacon_processor_admission_cost_threshold_default() {
+    CommandLineTest::new().run().with_config(|config| {
+        assert_eq!(
+            config.network.beacon_processor_admission_cost_threshold_ms,
+            None
+        )
+    });
+}
+#[test]
 fn network_subscribe_all_subnets_flag() {
     CommandLineTest::new()
         .flag("subscribe-all-subnets", None)
@@ -560,6 +854,22 @@ fn http_allow_origin_all_flag() {
         .run()
         .with_config(|config| assert_eq!(config.http_api.allow_origin, Some("*".to_string())));
 }
+#[test]
+fn http_max_response_body_size_flag() {
+    CommandLineTest::new()
+        .flag("http-max-response-body-size", Some("1024"))
+        .run()
+        .with_config(|config| assert_eq!(config.http_api.max_response_body_size, 1024));
+}
+#[test]
+fn http_max_response_body_size_default() {
+    CommandLineTest::new().run().with_config(|config| {
+        assert_eq!(
+            config.http_api.max_response_body_size,
+            2 * 1024 * 1024 * 1024
+        )
+    });
+}
 
 // Tests for Metrics flags.
 #[test]
@@ -610,6 +920,23 @@ fn metrics_allow_origin_all_flag() {
         .run()
         .with_config(|config| assert_eq!(config.http_metrics.allow_origin, Some("*".to_string())));
 }
+#[test]
+fn http_metrics_auth_token_flag() {
+    CommandLineTest::new()
+        .flag("metrics", None)
+        .flag("http-metrics-auth-token", Some("secret"))
+        .run()
+        .with_config(|config| {
+            assert_eq!(config.http_metrics.auth_token, Some("secret".to_string()))
+        });
+}
+#[test]
+fn http_metrics_auth_token_default() {
+    CommandLineTest::new()
+        .flag("metrics", None)
+        .run()
+        .with_config(|config| assert_eq!(config.http_metrics.auth_token, None));
+}
 
 // Tests for Validator Monitor flags.
 #[test]
@@ -786,6 +1113,21 @@ fn slasher_validator_chunk_size_flag() {
         });
 }
 #[test]
+fn slasher_validators_per_batch_flag() {
+    CommandLineTest::new()
+        .flag("slasher", None)
+        .flag("slasher-max-db-size", Some("16"))
+        .flag("slasher-validators-per-batch", Some("1024"))
+        .run()
+        .with_config(|config| {
+            let slasher_config = config
+                .slasher
+                .as_ref()
+                .expect("Unable to parse Slasher config");
+            assert_eq!(slasher_config.validators_per_batch, 1024);
+        });
+}
+#[test]
 fn slasher_broadcast_flag() {
     CommandLineTest::new()
         .flag("slasher", None)
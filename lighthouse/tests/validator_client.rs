@@ -194,6 +194,29 @@ fn init_slashing_protections_flag() {
         .with_config(|config| assert!(config.init_slashing_protection));
 }
 
+#[test]
+fn beacon_node_score_threshold_default() {
+    CommandLineTest::new()
+        .run()
+        .with_config(|config| assert_eq!(config.beacon_node_score_threshold, None));
+}
+
+#[test]
+fn beacon_node_score_threshold_flag() {
+    CommandLineTest::new()
+        .flag("beacon-node-score-threshold", Some("0.75"))
+        .run()
+        .with_config(|config| assert_eq!(config.beacon_node_score_threshold, Some(0.75)));
+}
+
+#[test]
+#[should_panic]
+fn beacon_node_score_threshold_out_of_range() {
+    CommandLineTest::new()
+        .flag("beacon-node-score-threshold", Some("1.5"))
+        .run();
+}
+
 // Tests for Graffiti flags.
 #[test]
 fn graffiti_flag() {
@@ -14,11 +14,25 @@ pub struct AttestationQueue<E: EthSpec> {
 }
 
 /// Attestations grouped by validator index range.
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct GroupedAttestations<E: EthSpec> {
     pub subqueues: Vec<AttestationBatch<E>>,
 }
 
+impl<E: EthSpec> GroupedAttestations<E> {
+    /// Append the attestations in each subqueue of `other` to the same subqueue of `self`.
+    pub fn extend(&mut self, other: Self) {
+        if other.subqueues.len() > self.subqueues.len() {
+            self.subqueues
+                .resize_with(other.subqueues.len(), AttestationBatch::default);
+        }
+
+        for (subqueue, other_subqueue) in self.subqueues.iter_mut().zip(other.subqueues) {
+            subqueue.attestations.extend(other_subqueue.attestations);
+        }
+    }
+}
+
 /// A queue of attestations for a range of validator indices.
 #[derive(Debug, Default)]
 pub struct AttestationBatch<E: EthSpec> {
@@ -9,6 +9,7 @@ pub const DEFAULT_HISTORY_LENGTH: usize = 4096;
 pub const DEFAULT_UPDATE_PERIOD: u64 = 12;
 pub const DEFAULT_MAX_DB_SIZE: usize = 256 * 1024; // 256 GiB
 pub const DEFAULT_BROADCAST: bool = false;
+pub const DEFAULT_VALIDATORS_PER_BATCH: usize = 4096;
 
 /// Database size to use for tests.
 ///
@@ -30,6 +31,8 @@ pub struct Config {
     pub max_db_size_mbs: usize,
     /// Whether to broadcast slashings found to the network.
     pub broadcast: bool,
+    /// Maximum number of validators whose attestations are checked for slashings per batch.
+    pub validators_per_batch: usize,
 }
 
 impl Config {
@@ -42,6 +45,7 @@ impl Config {
             update_period: DEFAULT_UPDATE_PERIOD,
             max_db_size_mbs: DEFAULT_MAX_DB_SIZE,
             broadcast: DEFAULT_BROADCAST,
+            validators_per_batch: DEFAULT_VALIDATORS_PER_BATCH,
         }
     }
 
@@ -56,6 +60,7 @@ impl Config {
             || self.validator_chunk_size == 0
             || self.history_length == 0
             || self.max_db_size_mbs == 0
+            || self.validators_per_batch == 0
         {
             Err(Error::ConfigInvalidZeroParameter {
                 config: self.clone(),
@@ -76,6 +81,14 @@ impl Config {
             && self.history_length == other.history_length
     }
 
+    /// The number of validator chunks checked for slashings per batch.
+    ///
+    /// This is `validators_per_batch` rounded down to a whole number of validator chunks, but is
+    /// always at least one chunk.
+    pub fn validator_chunks_per_batch(&self) -> usize {
+        std::cmp::max(self.validators_per_batch / self.validator_chunk_size, 1)
+    }
+
     pub fn chunk_index(&self, epoch: Epoch) -> usize {
         (epoch.as_usize() % self.history_length) / self.chunk_size
     }
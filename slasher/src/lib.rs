@@ -15,7 +15,7 @@ pub mod test_utils;
 mod utils;
 
 pub use crate::slasher::Slasher;
-pub use attestation_queue::{AttestationBatch, AttestationQueue};
+pub use attestation_queue::{AttestationBatch, AttestationQueue, GroupedAttestations};
 pub use attester_record::AttesterRecord;
 pub use block_queue::BlockQueue;
 pub use config::Config;
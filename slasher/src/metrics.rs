@@ -10,6 +10,10 @@ lazy_static! {
         "slasher_process_batch_time",
         "Time taken to process a batch of blocks and attestations"
     );
+    pub static ref SLASHER_CHUNK_PROCESSING_TIME: Result<Histogram> = try_create_histogram(
+        "slasher_chunk_processing_duration_seconds",
+        "Time taken to check the attestations of a batch of validator chunks for slashings"
+    );
     pub static ref SLASHER_NUM_ATTESTATIONS_DROPPED: Result<IntGauge> = try_create_int_gauge(
         "slasher_num_attestations_dropped",
         "Number of attestations dropped per batch"
@@ -1,4 +1,7 @@
-use crate::{database::CURRENT_SCHEMA_VERSION, Config, Error, SlasherDB};
+use crate::{
+    config::DEFAULT_VALIDATORS_PER_BATCH, database::CURRENT_SCHEMA_VERSION, Config, Error,
+    SlasherDB,
+};
 use lmdb::RwTransaction;
 use serde_derive::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -15,11 +18,23 @@ pub struct ConfigV1 {
     max_db_size_mbs: usize,
 }
 
-type ConfigV2 = Config;
+/// Config from schema version 2, for migration to version 3+.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigV2 {
+    database_path: PathBuf,
+    chunk_size: usize,
+    validator_chunk_size: usize,
+    history_length: usize,
+    update_period: u64,
+    max_db_size_mbs: usize,
+    broadcast: bool,
+}
+
+type ConfigV3 = Config;
 
 impl Into<ConfigV2> for ConfigV1 {
     fn into(self) -> ConfigV2 {
-        Config {
+        ConfigV2 {
             database_path: self.database_path,
             chunk_size: self.chunk_size,
             validator_chunk_size: self.validator_chunk_size,
@@ -31,6 +46,21 @@ impl Into<ConfigV2> for ConfigV1 {
     }
 }
 
+impl Into<ConfigV3> for ConfigV2 {
+    fn into(self) -> ConfigV3 {
+        Config {
+            database_path: self.database_path,
+            chunk_size: self.chunk_size,
+            validator_chunk_size: self.validator_chunk_size,
+            history_length: self.history_length,
+            update_period: self.update_period,
+            max_db_size_mbs: self.max_db_size_mbs,
+            broadcast: self.broadcast,
+            validators_per_batch: DEFAULT_VALIDATORS_PER_BATCH,
+        }
+    }
+}
+
 impl<E: EthSpec> SlasherDB<E> {
     /// If the database exists, and has a schema, attempt to migrate it to the current version.
     pub fn migrate(&self, txn: &mut RwTransaction<'_>) -> Result<(), Error> {
@@ -39,23 +69,26 @@ impl<E: EthSpec> SlasherDB<E> {
                 // The migration from v1 to v2 is a bit messy because v1.0.5 silently
                 // changed the schema to v2, so a v1 schema could have either a v1 or v2
                 // config.
-                (1, 2) => {
-                    match self.load_config::<ConfigV1>(txn) {
-                        Ok(Some(config_v1)) => {
-                            // Upgrade to v2 config and store on disk.
-                            let config_v2 = config_v1.into();
-                            self.store_config(&config_v2, txn)?;
-                        }
+                (1, 3) => {
+                    let config_v2 = match self.load_config::<ConfigV1>(txn) {
+                        Ok(Some(config_v1)) => config_v1.into(),
                         Ok(None) => {
                             // Impossible to have schema version and no config.
                             return Err(Error::ConfigMissing);
                         }
                         Err(_) => {
                             // If loading v1 config failed, ensure loading v2 config succeeds.
-                            // No further action is needed.
-                            let _config_v2 = self.load_config::<ConfigV2>(txn)?;
+                            self.load_config::<ConfigV2>(txn)?
+                                .ok_or(Error::ConfigMissing)?
                         }
-                    }
+                    };
+                    self.migrate_config_v2(config_v2, txn)?;
+                }
+                (2, 3) => {
+                    let config_v2 = self
+                        .load_config::<ConfigV2>(txn)?
+                        .ok_or(Error::ConfigMissing)?;
+                    self.migrate_config_v2(config_v2, txn)?;
                 }
                 (x, y) if x == y => {}
                 (_, _) => {
@@ -71,4 +104,14 @@ impl<E: EthSpec> SlasherDB<E> {
         self.store_schema_version(txn)?;
         Ok(())
     }
+
+    /// Upgrade a v2 config to v3 and store it on disk.
+    fn migrate_config_v2(
+        &self,
+        config_v2: ConfigV2,
+        txn: &mut RwTransaction<'_>,
+    ) -> Result<(), Error> {
+        let config_v3: ConfigV3 = config_v2.into();
+        self.store_config(&config_v3, txn)
+    }
 }
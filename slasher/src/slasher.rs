@@ -1,11 +1,11 @@
 use crate::batch_stats::{AttestationStats, BatchStats, BlockStats};
 use crate::metrics::{
-    self, SLASHER_NUM_ATTESTATIONS_DEFERRED, SLASHER_NUM_ATTESTATIONS_DROPPED,
-    SLASHER_NUM_ATTESTATIONS_VALID, SLASHER_NUM_BLOCKS_PROCESSED,
+    self, SLASHER_CHUNK_PROCESSING_TIME, SLASHER_NUM_ATTESTATIONS_DEFERRED,
+    SLASHER_NUM_ATTESTATIONS_DROPPED, SLASHER_NUM_ATTESTATIONS_VALID, SLASHER_NUM_BLOCKS_PROCESSED,
 };
 use crate::{
     array, AttestationBatch, AttestationQueue, AttesterRecord, BlockQueue, Config, Error,
-    ProposerSlashingStatus, SlasherDB,
+    GroupedAttestations, ProposerSlashingStatus, SlasherDB,
 };
 use lmdb::{RwTransaction, Transaction};
 use parking_lot::Mutex;
@@ -20,6 +20,10 @@ use types::{
 pub struct Slasher<E: EthSpec> {
     db: SlasherDB<E>,
     attestation_queue: AttestationQueue<E>,
+    /// Stored attestations which are yet to be checked for slashings, by validator chunk.
+    unchecked_attestations: Mutex<GroupedAttestations<E>>,
+    /// The validator chunk from which the next batch starts checking `unchecked_attestations`.
+    next_subqueue_id: Mutex<usize>,
     block_queue: BlockQueue,
     attester_slashings: Mutex<HashSet<AttesterSlashing<E>>>,
     proposer_slashings: Mutex<HashSet<ProposerSlashing>>,
@@ -39,6 +43,8 @@ impl<E: EthSpec> Slasher<E> {
         Ok(Self {
             db,
             attestation_queue,
+            unchecked_attestations: Mutex::new(GroupedAttestations::default()),
+            next_subqueue_id: Mutex::new(0),
             block_queue,
             attester_slashings,
             proposer_slashings,
@@ -147,20 +153,74 @@ impl<E: EthSpec> Slasher<E> {
         metrics::set_gauge(&SLASHER_NUM_ATTESTATIONS_DEFERRED, num_deferred as i64);
         metrics::set_gauge(&SLASHER_NUM_ATTESTATIONS_DROPPED, num_dropped as i64);
 
-        for attestation in snapshot.attestations.iter() {
-            self.db.store_indexed_attestation(
-                txn,
-                attestation.1.indexed_attestation_hash,
-                &attestation.0,
-            )?;
+        // Group attestations into batches and process them. Attestations are only stored in the
+        // database once they are checked, see `process_unchecked_subqueues`.
+        let mut unchecked_attestations = self.unchecked_attestations.lock();
+        unchecked_attestations.extend(snapshot.group_by_validator_index(&self.config));
+        self.process_unchecked_subqueues(txn, &mut unchecked_attestations, current_epoch)?;
+
+        Ok(AttestationStats { num_processed })
+    }
+
+    /// Check the attestations of at most `Config::validator_chunks_per_batch` validator chunks
+    /// for slashings.
+    ///
+    /// Successive calls rotate through the validator chunks with unchecked attestations, so the
+    /// attestations of every validator are checked within
+    /// `ceil(num_validator_chunks / validator_chunks_per_batch)` calls.
+    ///
+    /// Each attestation is stored in the database as part of the batch which checks it. Unchecked
+    /// attestations are only held in memory, so like the attestation queue they are lost on
+    /// shutdown rather than being stored and never checked.
+    fn process_unchecked_subqueues(
+        &self,
+        txn: &mut RwTransaction<'_>,
+        unchecked_attestations: &mut GroupedAttestations<E>,
+        current_epoch: Epoch,
+    ) -> Result<(), Error> {
+        let num_subqueues = unchecked_attestations.subqueues.len();
+        if num_subqueues == 0 {
+            return Ok(());
         }
 
-        // Group attestations into batches and process them.
-        let grouped_attestations = snapshot.group_by_validator_index(&self.config);
-        for (subqueue_id, subqueue) in grouped_attestations.subqueues.into_iter().enumerate() {
-            self.process_batch(txn, subqueue_id, subqueue.attestations, current_epoch)?;
+        let _timer = metrics::start_timer(&SLASHER_CHUNK_PROCESSING_TIME);
+
+        let mut next_subqueue_id = self.next_subqueue_id.lock();
+        let start = *next_subqueue_id % num_subqueues;
+        let subqueue_ids = (start..start + num_subqueues)
+            .map(|id| id % num_subqueues)
+            .filter(|id| !unchecked_attestations.subqueues[*id].is_empty())
+            .take(self.config.validator_chunks_per_batch())
+            .collect::<Vec<_>>();
+
+        if let Some(last_subqueue_id) = subqueue_ids.last() {
+            *next_subqueue_id = (last_subqueue_id + 1) % num_subqueues;
         }
-        Ok(AttestationStats { num_processed })
+
+        let batches = subqueue_ids
+            .into_iter()
+            .map(|subqueue_id| {
+                let subqueue =
+                    std::mem::take(&mut unchecked_attestations.subqueues[subqueue_id].attestations);
+                (subqueue_id, subqueue)
+            })
+            .collect::<Vec<_>>();
+
+        // An attestation may appear in the batches of several validator chunks, only store it once.
+        let mut stored_attestations = HashSet::new();
+        for attestation in batches.iter().flat_map(|(_, batch)| batch) {
+            let indexed_attestation_hash = attestation.1.indexed_attestation_hash;
+            if stored_attestations.insert(indexed_attestation_hash) {
+                self.db
+                    .store_indexed_attestation(txn, indexed_attestation_hash, &attestation.0)?;
+            }
+        }
+
+        for (subqueue_id, batch) in batches {
+            self.process_batch(txn, subqueue_id, batch, current_epoch)?;
+        }
+
+        Ok(())
     }
 
     /// Process a batch of attestations for a range of validator indices.
@@ -145,6 +145,40 @@ fn surrounded_by_single_val_multi_chunk() {
     slasher_test_indiv(&attestations, &slashings, 4 * chunk_size);
 }
 
+// With a small number of validators per batch, every validator is checked within
+// `ceil(num_validators / validators_per_batch)` batches.
+#[test]
+fn double_votes_found_across_batches() {
+    let tempdir = tempdir().unwrap();
+    let mut config = Config::new(tempdir.path().into()).for_testing();
+    config.validator_chunk_size = 1;
+    config.validators_per_batch = 2;
+    let slasher = Slasher::open(config, logger()).unwrap();
+    let current_epoch = Epoch::new(1);
+
+    let validators = (0..6).collect::<HashSet<u64>>();
+    for &validator_index in &validators {
+        slasher.accept_attestation(indexed_att(&[validator_index], 0, 1, 0));
+        slasher.accept_attestation(indexed_att(&[validator_index], 0, 1, 1));
+    }
+
+    let mut slashed_validators = HashSet::new();
+    for _ in 0..3 {
+        slasher.process_queued(current_epoch).unwrap();
+        let slashings = slasher.get_attester_slashings();
+        assert_eq!(slashings.len(), 2);
+        slashed_validators.extend(slashed_validators_from_slashings(&slashings));
+    }
+    assert_eq!(slashed_validators, validators);
+
+    // All attestations have been checked.
+    slasher.process_queued(current_epoch).unwrap();
+    assert!(slasher.get_attester_slashings().is_empty());
+
+    // windows won't delete the temporary directory if you don't do this..
+    drop(slasher);
+}
+
 // Process each attestation individually, and confirm that the slashings produced are as expected.
 fn slasher_test_indiv(
     attestations: &[IndexedAttestation<E>],
@@ -5,6 +5,7 @@
 use crate::check_synced::check_synced;
 use crate::http_metrics::metrics::{inc_counter_vec, ENDPOINT_ERRORS, ENDPOINT_REQUESTS};
 use environment::RuntimeContext;
+use eth2::types::SyncingData;
 use eth2::BeaconNodeHttpClient;
 use futures::future;
 use slog::{debug, error, info, warn, Logger};
@@ -14,9 +15,9 @@ use std::fmt::Debug;
 use std::future::Future;
 use std::marker::PhantomData;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::{sync::RwLock, time::sleep};
-use types::{ChainSpec, EthSpec};
+use types::{ChainSpec, EthSpec, Slot};
 
 /// The number of seconds *prior* to slot start that we will try and update the state of fallback
 /// nodes.
@@ -27,6 +28,16 @@ use types::{ChainSpec, EthSpec};
 /// having the correct nodes up and running prior to the start of the slot.
 const SLOT_LOOKAHEAD: Duration = Duration::from_secs(1);
 
+/// The distance in slots between the local slot clock and the head of a beacon node at which the
+/// head distance component of the node's score reaches zero.
+const SCORE_HEAD_DISTANCE_LIMIT: u64 = 8;
+
+/// The fraction of a slot taken by a beacon node to respond at which the latency component of the
+/// node's score reaches zero.
+///
+/// Set to a third of a slot, since a node that takes this long cannot serve attestations on time.
+const SCORE_LATENCY_LIMIT_DIVISOR: u32 = 3;
+
 /// Starts a service that will routinely try and update the status of the provided `beacon_nodes`.
 ///
 /// See `SLOT_LOOKAHEAD` for information about when this should run.
@@ -42,6 +53,7 @@ pub fn start_fallback_updater_service<T: SlotClock + 'static, E: EthSpec>(
     let future = async move {
         loop {
             beacon_nodes.update_unready_candidates().await;
+            beacon_nodes.check_primary_score().await;
 
             let sleep_time = beacon_nodes
                 .slot_clock
@@ -119,6 +131,44 @@ pub enum CandidateError {
     Offline,
     Incompatible,
     NotSynced,
+    /// The health score of the node is below the `--beacon-node-score-threshold`.
+    LowScore,
+}
+
+/// Returns a score from `0.0` to `1.0` representing the health of a beacon node, where `1.0` is
+/// perfectly healthy.
+///
+/// The score is the mean of three components:
+///
+/// - Sync status: `1.0` if the node is not syncing, otherwise `0.0`.
+/// - Head distance: decreases linearly from `1.0` when the node's head is at `current_slot` to
+///   `0.0` when it is `SCORE_HEAD_DISTANCE_LIMIT` slots behind.
+/// - Latency: decreases linearly from `1.0` for an instant response to `0.0` for a response
+///   taking a `SCORE_LATENCY_LIMIT_DIVISOR`th of a slot.
+///
+/// If `current_slot` is unknown, the head distance is taken to be the node's `sync_distance`.
+pub fn beacon_node_score(
+    syncing: &SyncingData,
+    current_slot: Option<Slot>,
+    latency: Duration,
+    slot_duration: Duration,
+) -> f64 {
+    let sync_score = if syncing.is_syncing { 0.0 } else { 1.0 };
+
+    let head_distance = current_slot
+        .map(|slot| slot.saturating_sub(syncing.head_slot).as_u64())
+        .unwrap_or_else(|| syncing.sync_distance.as_u64())
+        .min(SCORE_HEAD_DISTANCE_LIMIT);
+    let head_score = 1.0 - head_distance as f64 / SCORE_HEAD_DISTANCE_LIMIT as f64;
+
+    let latency_limit = slot_duration / SCORE_LATENCY_LIMIT_DIVISOR;
+    let latency_score = if latency_limit.as_nanos() == 0 {
+        1.0
+    } else {
+        1.0 - latency.min(latency_limit).as_secs_f64() / latency_limit.as_secs_f64()
+    };
+
+    (sync_score + head_score + latency_score) / 3.0
 }
 
 /// Represents a `BeaconNodeHttpClient` inside a `BeaconNodeFallback` that may or may not be used
@@ -159,6 +209,7 @@ impl<E: EthSpec> CandidateBeaconNode<E> {
     pub async fn refresh_status<T: SlotClock>(
         &self,
         slot_clock: Option<&T>,
+        score_threshold: Option<f64>,
         spec: &ChainSpec,
         log: &Logger,
     ) -> Result<(), CandidateError> {
@@ -168,7 +219,10 @@ impl<E: EthSpec> CandidateBeaconNode<E> {
             *status = Err(e);
         } else if let Err(e) = self.is_compatible(spec, log).await {
             *status = Err(e);
-        } else if let Err(e) = self.is_synced(slot_clock, log).await {
+        } else if let Err(e) = self
+            .is_synced_and_healthy(slot_clock, score_threshold, spec, log)
+            .await
+        {
             *status = Err(e);
         } else {
             *status = Ok(())
@@ -257,18 +311,83 @@ impl<E: EthSpec> CandidateBeaconNode<E> {
         }
     }
 
-    /// Checks if the beacon node is synced.
-    async fn is_synced<T: SlotClock>(
+    /// Checks if the beacon node is synced and, if `score_threshold` is set, that its score is at
+    /// or above `score_threshold`.
+    ///
+    /// Both checks are made using the same syncing request.
+    async fn is_synced_and_healthy<T: SlotClock>(
         &self,
         slot_clock: Option<&T>,
+        score_threshold: Option<f64>,
+        spec: &ChainSpec,
         log: &Logger,
     ) -> Result<(), CandidateError> {
+        if slot_clock.is_none() && score_threshold.is_none() {
+            // Skip these checks if we don't supply a slot clock or score threshold.
+            return Ok(());
+        }
+
+        let (syncing, latency) = self.get_syncing().await.map_err(|e| {
+            warn!(
+                log,
+                "Unable connect to beacon node";
+                "error" => %e,
+                "endpoint" => %self.beacon_node,
+            );
+            CandidateError::Offline
+        })?;
+
         if let Some(slot_clock) = slot_clock {
-            check_synced(&self.beacon_node, slot_clock, Some(log)).await
-        } else {
-            // Skip this check if we don't supply a slot clock.
-            Ok(())
+            check_synced(&self.beacon_node, &syncing, slot_clock, Some(log))?;
+        }
+
+        if let Some(threshold) = score_threshold {
+            let score = beacon_node_score(
+                &syncing,
+                slot_clock.and_then(SlotClock::now),
+                latency,
+                Duration::from_secs(spec.seconds_per_slot),
+            );
+            if score < threshold {
+                debug!(
+                    log,
+                    "Beacon node score below threshold";
+                    "score" => score,
+                    "threshold" => threshold,
+                    "endpoint" => %self.beacon_node,
+                );
+                return Err(CandidateError::LowScore);
+            }
         }
+
+        Ok(())
+    }
+
+    /// Measures the score of the beacon node, see `beacon_node_score`.
+    async fn score<T: SlotClock>(
+        &self,
+        slot_clock: Option<&T>,
+        spec: &ChainSpec,
+    ) -> Result<f64, CandidateError> {
+        let (syncing, latency) = self
+            .get_syncing()
+            .await
+            .map_err(|_| CandidateError::Offline)?;
+
+        Ok(beacon_node_score(
+            &syncing,
+            slot_clock.and_then(SlotClock::now),
+            latency,
+            Duration::from_secs(spec.seconds_per_slot),
+        ))
+    }
+
+    /// Requests the sync status of the beacon node, returning it alongside the time taken for the
+    /// node to respond.
+    async fn get_syncing(&self) -> Result<(SyncingData, Duration), eth2::Error> {
+        let request_start = Instant::now();
+        let syncing = self.beacon_node.get_node_syncing().await?.data;
+        Ok((syncing, request_start.elapsed()))
     }
 }
 
@@ -278,6 +397,7 @@ impl<E: EthSpec> CandidateBeaconNode<E> {
 pub struct BeaconNodeFallback<T, E> {
     candidates: Vec<CandidateBeaconNode<E>>,
     slot_clock: Option<T>,
+    score_threshold: Option<f64>,
     spec: ChainSpec,
     log: Logger,
 }
@@ -287,6 +407,7 @@ impl<T: SlotClock, E: EthSpec> BeaconNodeFallback<T, E> {
         Self {
             candidates,
             slot_clock: None,
+            score_threshold: None,
             spec,
             log,
         }
@@ -301,6 +422,13 @@ impl<T: SlotClock, E: EthSpec> BeaconNodeFallback<T, E> {
         self.slot_clock = Some(slot_clock);
     }
 
+    /// Sets the minimum score a beacon node must have to be used ahead of the other candidates.
+    ///
+    /// See `beacon_node_score` for how the score is calculated.
+    pub fn set_score_threshold(&mut self, score_threshold: f64) {
+        self.score_threshold = Some(score_threshold);
+    }
+
     /// The count of candidates, regardless of their state.
     pub fn num_total(&self) -> usize {
         self.candidates.len()
@@ -359,6 +487,7 @@ impl<T: SlotClock, E: EthSpec> BeaconNodeFallback<T, E> {
                 // acceptable inefficiency.
                 futures.push(candidate.refresh_status(
                     self.slot_clock.as_ref(),
+                    self.score_threshold,
                     &self.spec,
                     &self.log,
                 ));
@@ -369,6 +498,45 @@ impl<T: SlotClock, E: EthSpec> BeaconNodeFallback<T, E> {
         let _ = future::join_all(futures).await;
     }
 
+    /// If a score threshold is set, measure the score of the primary beacon node and mark it as
+    /// degraded if it is below the threshold. This causes requests to be sent to the next ready
+    /// candidate until a subsequent `update_unready_candidates` finds the primary has recovered.
+    ///
+    /// Only the primary is scored here, since `update_unready_candidates` does not poll ready
+    /// nodes and the fallbacks are only used whilst the primary is unready.
+    pub async fn check_primary_score(&self) {
+        let threshold = if let Some(threshold) = self.score_threshold {
+            threshold
+        } else {
+            return;
+        };
+
+        let primary = if let Some(primary) = self.candidates.first() {
+            primary
+        } else {
+            return;
+        };
+
+        if primary.status(RequireSynced::Yes).await.is_err() {
+            return;
+        }
+
+        match primary.score(self.slot_clock.as_ref(), &self.spec).await {
+            Ok(score) if score < threshold => {
+                warn!(
+                    self.log,
+                    "Switching away from degraded beacon node";
+                    "score" => score,
+                    "threshold" => threshold,
+                    "endpoint" => %primary.beacon_node,
+                );
+                *primary.status.write().await = Err(CandidateError::LowScore);
+            }
+            Ok(_) => (),
+            Err(e) => *primary.status.write().await = Err(e),
+        }
+    }
+
     /// Run `func` against each candidate in `self`, returning immediately if a result is found.
     /// Otherwise, return all the errors encountered along the way.
     ///
@@ -387,6 +555,7 @@ impl<T: SlotClock, E: EthSpec> BeaconNodeFallback<T, E> {
         let mut errors = vec![];
         let mut to_retry = vec![];
         let mut retry_unsynced = vec![];
+        let mut retry_degraded = vec![];
 
         // Run `func` using a `candidate`, returning the value or capturing errors.
         //
@@ -424,6 +593,12 @@ impl<T: SlotClock, E: EthSpec> BeaconNodeFallback<T, E> {
                     retry_unsynced.push(candidate);
                     errors.push((candidate.beacon_node.to_string(), Error::Unavailable(e)));
                 }
+                Err(e @ CandidateError::LowScore) => {
+                    // This client has a low score, we will only try it if all the healthy clients
+                    // fail.
+                    retry_degraded.push(candidate);
+                    errors.push((candidate.beacon_node.to_string(), Error::Unavailable(e)));
+                }
                 Err(e) => {
                     // This client was not ready on the first pass, we might try it again later.
                     to_retry.push(candidate);
@@ -452,7 +627,12 @@ impl<T: SlotClock, E: EthSpec> BeaconNodeFallback<T, E> {
                 Ok(()) => Ok(()),
                 Err(_) => {
                     candidate
-                        .refresh_status(self.slot_clock.as_ref(), &self.spec, &self.log)
+                        .refresh_status(
+                            self.slot_clock.as_ref(),
+                            self.score_threshold,
+                            &self.spec,
+                            &self.log,
+                        )
                         .await
                 }
             };
@@ -460,13 +640,191 @@ impl<T: SlotClock, E: EthSpec> BeaconNodeFallback<T, E> {
             match new_status {
                 Ok(()) => try_func!(candidate),
                 Err(CandidateError::NotSynced) if require_synced == false => try_func!(candidate),
+                Err(CandidateError::LowScore) => retry_degraded.push(candidate),
                 Err(e) => {
                     errors.push((candidate.beacon_node.to_string(), Error::Unavailable(e)));
                 }
             }
         }
 
+        // Fourth pass: try the candidates with a low score, since a degraded node is better than
+        // none at all.
+        for candidate in retry_degraded {
+            try_func!(candidate);
+        }
+
         // There were no candidates already ready and we were unable to make any of them ready.
         Err(AllErrored(errors))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use eth2::types::GenericResponse;
+    use sensitive_url::SensitiveUrl;
+    use slog::{o, Discard};
+    use slot_clock::TestingSlotClock;
+    use types::MinimalEthSpec;
+    use warp::Filter;
+
+    const SLOT_DURATION: Duration = Duration::from_secs(6);
+
+    fn syncing_data(is_syncing: bool, head_slot: u64) -> SyncingData {
+        SyncingData {
+            is_syncing,
+            head_slot: Slot::new(head_slot),
+            sync_distance: Slot::new(0),
+        }
+    }
+
+    fn fallback(urls: &[&str]) -> BeaconNodeFallback<TestingSlotClock, MinimalEthSpec> {
+        let candidates = urls
+            .iter()
+            .map(|url| {
+                CandidateBeaconNode::new(BeaconNodeHttpClient::new(
+                    SensitiveUrl::parse(url).unwrap(),
+                ))
+            })
+            .collect();
+        let log = Logger::root(Discard, o!());
+        BeaconNodeFallback::new(candidates, MinimalEthSpec::default_spec(), log)
+    }
+
+    async fn set_statuses(
+        fallback: &BeaconNodeFallback<TestingSlotClock, MinimalEthSpec>,
+        statuses: &[Result<(), CandidateError>],
+    ) {
+        for (candidate, status) in fallback.candidates.iter().zip(statuses) {
+            *candidate.status.write().await = *status;
+        }
+    }
+
+    /// Serves `syncing` from the syncing endpoint of a local HTTP server, returning its URL.
+    fn serve_syncing(syncing: SyncingData) -> String {
+        let route = warp::path!("eth" / "v1" / "node" / "syncing")
+            .map(move || warp::reply::json(&GenericResponse::from(syncing.clone())));
+        let (addr, server) = warp::serve(route).bind_ephemeral(([127, 0, 0, 1], 0));
+        tokio::spawn(server);
+        format!("http://{}/", addr)
+    }
+
+    async fn first_success_endpoint(
+        fallback: &BeaconNodeFallback<TestingSlotClock, MinimalEthSpec>,
+    ) -> String {
+        fallback
+            .first_success(RequireSynced::Yes, |node| {
+                let endpoint = node.to_string();
+                async move { Ok::<_, ()>(endpoint) }
+            })
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn healthy_node_has_full_score() {
+        let score = beacon_node_score(
+            &syncing_data(false, 10),
+            Some(Slot::new(10)),
+            Duration::from_secs(0),
+            SLOT_DURATION,
+        );
+        assert!((score - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn degraded_node_has_reduced_score() {
+        let healthy = beacon_node_score(
+            &syncing_data(false, 10),
+            Some(Slot::new(10)),
+            Duration::from_millis(100),
+            SLOT_DURATION,
+        );
+
+        // Half of `SCORE_HEAD_DISTANCE_LIMIT` behind the head.
+        let behind = beacon_node_score(
+            &syncing_data(false, 6),
+            Some(Slot::new(10)),
+            Duration::from_millis(100),
+            SLOT_DURATION,
+        );
+
+        // Responding later than a third of a slot.
+        let slow = beacon_node_score(
+            &syncing_data(false, 10),
+            Some(Slot::new(10)),
+            SLOT_DURATION,
+            SLOT_DURATION,
+        );
+
+        let syncing = beacon_node_score(
+            &syncing_data(true, 10),
+            Some(Slot::new(10)),
+            Duration::from_millis(100),
+            SLOT_DURATION,
+        );
+
+        assert!(behind < healthy);
+        assert!(slow < healthy);
+        assert!(syncing < healthy);
+        assert!((slow - 2.0 / 3.0).abs() < f64::EPSILON);
+
+        let dead = beacon_node_score(
+            &syncing_data(true, 0),
+            Some(Slot::new(10)),
+            SLOT_DURATION,
+            SLOT_DURATION,
+        );
+        assert!(dead.abs() < f64::EPSILON);
+    }
+
+    #[tokio::test]
+    async fn degraded_primary_switches_to_backup() {
+        // The primary is syncing and `SCORE_HEAD_DISTANCE_LIMIT` slots behind the head.
+        let primary = serve_syncing(syncing_data(true, 0));
+        let backup = "http://backup.invalid:5052/";
+        let mut fallback = fallback(&[&primary, backup]);
+
+        let slot_clock = TestingSlotClock::new(Slot::new(0), Duration::from_secs(0), SLOT_DURATION);
+        slot_clock.set_slot(SCORE_HEAD_DISTANCE_LIMIT);
+        fallback.set_slot_clock(slot_clock);
+
+        set_statuses(&fallback, &[Ok(()), Ok(())]).await;
+        assert_eq!(first_success_endpoint(&fallback).await, primary);
+
+        // Without a threshold the primary is never scored.
+        fallback.check_primary_score().await;
+        assert_eq!(first_success_endpoint(&fallback).await, primary);
+
+        // Only the latency component of the primary's score can be non-zero, so it is below
+        // `1 / 3`.
+        fallback.set_score_threshold(0.5);
+        fallback.check_primary_score().await;
+        assert!(matches!(
+            fallback.candidates[0].status(RequireSynced::Yes).await,
+            Err(CandidateError::LowScore)
+        ));
+        assert_eq!(first_success_endpoint(&fallback).await, backup);
+        assert_eq!(fallback.num_synced().await, 1);
+    }
+
+    #[tokio::test]
+    async fn degraded_primary_used_without_backup() {
+        let primary = "http://primary.invalid:5052/";
+        let backup = "http://127.0.0.1:1/";
+        let fallback = fallback(&[primary, backup]);
+
+        set_statuses(
+            &fallback,
+            &[
+                Err(CandidateError::LowScore),
+                Err(CandidateError::Incompatible),
+            ],
+        )
+        .await;
+
+        // The backup is re-checked before falling back to the degraded primary. It is unreachable
+        // so it is marked offline.
+        assert_eq!(first_success_endpoint(&fallback).await, primary);
+    }
+}
@@ -94,6 +94,18 @@ pub fn cli_app<'a, 'b>() -> App<'a, 'b> {
                       node is not synced.",
                 ),
         )
+        .arg(
+            Arg::with_name("beacon-node-score-threshold")
+                .long("beacon-node-score-threshold")
+                .value_name("SCORE")
+                .help(
+                    "A value from 0.0 to 1.0. If the health score of the primary beacon node \
+                    (derived from its sync status, head distance and response latency) drops \
+                    below this value, switch to the next beacon node in --beacon-nodes until it \
+                    recovers. Disabled by default.",
+                )
+                .takes_value(true),
+        )
         // This overwrites the graffiti configured in the beacon node.
         .arg(
             Arg::with_name("graffiti")
@@ -31,6 +31,9 @@ pub struct Config {
     /// If true, the validator client will still poll for duties and produce blocks even if the
     /// beacon node is not synced at startup.
     pub allow_unsynced_beacon_node: bool,
+    /// If set, the primary beacon node will not be used whilst its health score is below this
+    /// value.
+    pub beacon_node_score_threshold: Option<f64>,
     /// If true, don't scan the validators dir for new keystores.
     pub disable_auto_discover: bool,
     /// If true, re-register existing validators in definitions.yml for slashing protection.
@@ -66,6 +69,7 @@ impl Default for Config {
             secrets_dir,
             beacon_nodes,
             allow_unsynced_beacon_node: false,
+            beacon_node_score_threshold: None,
             disable_auto_discover: false,
             init_slashing_protection: false,
             graffiti: None,
@@ -154,6 +158,16 @@ impl Config {
         }
 
         config.allow_unsynced_beacon_node = cli_args.is_present("allow-unsynced");
+
+        if let Some(threshold) = parse_optional::<f64>(cli_args, "beacon-node-score-threshold")? {
+            if !(0.0..=1.0).contains(&threshold) {
+                return Err(format!(
+                    "--beacon-node-score-threshold must be between 0.0 and 1.0, got {}",
+                    threshold
+                ));
+            }
+            config.beacon_node_score_threshold = Some(threshold);
+        }
         config.disable_auto_discover = cli_args.is_present("disable-auto-discover");
         config.init_slashing_protection = cli_args.is_present("init-slashing-protection");
 
@@ -253,6 +253,9 @@ impl<T: EthSpec> ProductionValidatorClient<T> {
         set_gauge(&http_metrics::metrics::ETH2_FALLBACK_CONNECTED, 0);
         let mut beacon_nodes: BeaconNodeFallback<_, T> =
             BeaconNodeFallback::new(candidates, context.eth2_config.spec.clone(), log.clone());
+        if let Some(threshold) = config.beacon_node_score_threshold {
+            beacon_nodes.set_score_threshold(threshold);
+        }
 
         // Perform some potentially long-running initialization tasks.
         let (genesis_time, genesis_validators_root, fork) = tokio::select! {